@@ -0,0 +1,444 @@
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::hash_map::RandomState,
+    fmt::Display,
+    hash::Hash,
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use gw2lib_model::{Endpoint, Language};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::cache::{
+    in_memory::{hash, snapshot_key_of, stored_name, Stored},
+    key_name, Cache, InMemoryCache, NoopCache,
+};
+
+/// identifies an entry of a [`DynCache`]
+///
+/// Carries both the hashed key used by in memory caches and the textual key
+/// used by caches storing strings, so either kind can be put behind a
+/// [`DynamicCache`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    pub type_id: TypeId,
+    /// [`std::any::type_name`] of the cached type
+    pub type_name: &'static str,
+    pub hash: u64,
+    pub authenticated: bool,
+    pub name: String,
+}
+
+/// a value handed to [`DynCache::insert`]
+///
+/// Holds the value itself, plus a way to serialize it for caches that don't
+/// keep values in memory.
+#[derive(Clone)]
+pub struct CacheValue {
+    value: Arc<dyn Any + Send + Sync>,
+    serialize: fn(&(dyn Any + Send + Sync)) -> Option<String>,
+}
+
+impl CacheValue {
+    pub(crate) fn new<T: Serialize + Send + Sync + 'static>(value: T) -> Self {
+        fn serialize<T: Serialize + 'static>(value: &(dyn Any + Send + Sync)) -> Option<String> {
+            value
+                .downcast_ref::<T>()
+                .and_then(|x| serde_json::to_string(x).ok())
+        }
+        Self {
+            value: Arc::new(value),
+            serialize: serialize::<T>,
+        }
+    }
+
+    /// the value, to be stored as is
+    pub fn shared(&self) -> Arc<dyn Any + Send + Sync> {
+        self.value.clone()
+    }
+
+    /// the value, serialized to json
+    pub fn to_json(&self) -> Option<String> {
+        (self.serialize)(&*self.value)
+    }
+}
+
+/// a value returned by [`DynCache::get`]
+pub enum Cached {
+    /// the value as it was inserted, see [`CacheValue::shared`]
+    Shared(Arc<dyn Any + Send + Sync>),
+    /// the value serialized to json, see [`CacheValue::to_json`]
+    Json(String),
+}
+
+/// an object safe version of [`Cache`]
+///
+/// [`Cache`] is generic over the cached types and can therefore not be used
+/// as a trait object. Implement this instead to select a cache at runtime,
+/// and put it behind a [`DynamicCache`].
+///
+/// Implemented for all caches shipped with this crate.
+#[async_trait]
+pub trait DynCache: Send + Sync {
+    async fn insert(&self, key: CacheKey, value: CacheValue, expiring: NaiveDateTime);
+
+    /// returns the value and when it expires, if known
+    async fn get(&self, key: &CacheKey) -> Option<(Cached, Option<NaiveDateTime>)>;
+
+    async fn cleanup(&self);
+
+    /// see [`Cache::purge_expired`]
+    async fn purge_expired(&self, _now: NaiveDateTime) -> usize {
+        0
+    }
+
+    /// see [`Cache::remove`]
+    async fn remove(&self, _key: &CacheKey) {}
+
+    /// see [`Cache::clear_type`]
+    async fn clear_type(&self, _type_id: TypeId) {}
+
+    /// see [`Cache::expiring`]
+    ///
+    /// Returned values not matching `type_id` are skipped.
+    async fn expiring(
+        &self,
+        _type_id: TypeId,
+        _authenticated: bool,
+        _before: NaiveDateTime,
+    ) -> Vec<Arc<dyn Any + Send + Sync>> {
+        Vec::new()
+    }
+
+    async fn wipe_static(&self);
+
+    async fn wipe_authenticated(&self);
+}
+
+/// a [`Cache`] backed by a [`DynCache`] trait object
+///
+/// Used by [`DynClient`](crate::DynClient) to pick the cache at runtime.
+/// ### Remarks
+/// Compared to using a cache directly, every operation goes through dynamic
+/// dispatch and builds the textual key of the entry, and every insert boxes
+/// the value. Caches storing json additionally pay for (de)serialization, as
+/// they always do.
+#[derive(Clone)]
+pub struct DynamicCache {
+    inner: Arc<dyn DynCache>,
+    hasher: RandomState,
+}
+
+impl DynamicCache {
+    pub fn new(cache: impl DynCache + 'static) -> Self {
+        Self::from_arc(Arc::new(cache))
+    }
+
+    pub fn from_arc(cache: Arc<dyn DynCache>) -> Self {
+        Self {
+            inner: cache,
+            hasher: RandomState::new(),
+        }
+    }
+
+    fn key<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> CacheKey
+    where
+        T: 'static,
+        I: Display + Hash + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + 'static,
+    {
+        let (type_id, hash) = hash::<_, T, I, A>(&self.hasher, id, E::LOCALE.then_some(lang), auth);
+        CacheKey {
+            type_id,
+            type_name: type_name::<T>(),
+            hash,
+            authenticated: E::AUTHENTICATED,
+            name: key_name::<E, I, A>(id, lang, auth),
+        }
+    }
+
+    async fn get_shared<T, I, E, A>(
+        &self,
+        id: &I,
+        lang: Language,
+        auth: &Option<A>,
+    ) -> Option<(Arc<T>, Option<NaiveDateTime>)>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        let key = self.key::<T, I, E, A>(id, lang, auth);
+        let (cached, expiring) = self.inner.get(&key).await?;
+        let value = match cached {
+            Cached::Shared(value) => value.downcast::<T>().ok()?,
+            Cached::Json(json) => Arc::new(serde_json::from_str(&json).ok()?),
+        };
+        Some((value, expiring))
+    }
+}
+
+#[async_trait]
+impl Cache for DynamicCache {
+    async fn insert<T, I, E, A>(
+        &self,
+        id: &I,
+        endpoint: &T,
+        expiring: NaiveDateTime,
+        lang: Language,
+        auth: &Option<A>,
+    ) where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        let key = self.key::<T, I, E, A>(id, lang, auth);
+        let value = CacheValue::new(endpoint.clone());
+        self.inner.insert(key, value, expiring).await;
+    }
+
+    async fn get<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> Option<T>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        self.get_shared::<T, I, E, A>(id, lang, auth)
+            .await
+            .map(|(x, _)| T::clone(&x))
+    }
+
+    async fn get_arc<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> Option<Arc<T>>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        self.get_shared::<T, I, E, A>(id, lang, auth)
+            .await
+            .map(|(x, _)| x)
+    }
+
+    async fn get_with_expiry<T, I, E, A>(
+        &self,
+        id: &I,
+        lang: Language,
+        auth: &Option<A>,
+    ) -> Option<(T, NaiveDateTime)>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        let (value, expiring) = self.get_shared::<T, I, E, A>(id, lang, auth).await?;
+        Some((T::clone(&value), expiring?))
+    }
+
+    async fn expiring<T, E>(&self, before: NaiveDateTime) -> Vec<Arc<T>>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        E: Endpoint,
+    {
+        self.inner
+            .expiring(TypeId::of::<T>(), E::AUTHENTICATED, before)
+            .await
+            .into_iter()
+            .filter_map(|x| x.downcast::<T>().ok())
+            .collect()
+    }
+
+    async fn cleanup(&self) {
+        self.inner.cleanup().await
+    }
+
+    async fn purge_expired(&self, now: NaiveDateTime) -> usize {
+        self.inner.purge_expired(now).await
+    }
+
+    async fn remove<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>)
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        let key = self.key::<T, I, E, A>(id, lang, auth);
+        self.inner.remove(&key).await
+    }
+
+    async fn clear_type<T: 'static>(&self) {
+        self.inner.clear_type(TypeId::of::<T>()).await
+    }
+
+    async fn wipe_static(&self) {
+        self.inner.wipe_static().await
+    }
+
+    async fn wipe_authenticated(&self) {
+        self.inner.wipe_authenticated().await
+    }
+}
+
+#[async_trait]
+impl DynCache for InMemoryCache {
+    async fn insert(&self, key: CacheKey, value: CacheValue, expiring: NaiveDateTime) {
+        self.map(key.authenticated).insert(
+            (key.type_id, key.hash),
+            Stored {
+                expiring,
+                value,
+                type_name: key.type_name,
+                snapshot_key: snapshot_key_of(&key.name),
+            },
+        );
+    }
+
+    async fn get(&self, key: &CacheKey) -> Option<(Cached, Option<NaiveDateTime>)> {
+        let map = self.map(key.authenticated);
+        let Some((expiring, value)) = map
+            .get(&(key.type_id, key.hash))
+            .map(|x| (x.expiring, x.value.shared()))
+        else {
+            let name = stored_name(key.type_name, snapshot_key_of(&key.name));
+            let (json, expiring) = self.imported(&name)?;
+            return Some((Cached::Json(json), Some(expiring)));
+        };
+        if Utc::now().naive_utc() < expiring {
+            Some((Cached::Shared(value), Some(expiring)))
+        } else {
+            map.remove(&(key.type_id, key.hash));
+            None
+        }
+    }
+
+    async fn cleanup(&self) {
+        Cache::cleanup(self).await
+    }
+
+    async fn purge_expired(&self, now: NaiveDateTime) -> usize {
+        Cache::purge_expired(self, now).await
+    }
+
+    async fn remove(&self, key: &CacheKey) {
+        let snapshot_key = snapshot_key_of(&key.name);
+        self.remove_entry(
+            key.authenticated,
+            (key.type_id, key.hash),
+            key.type_name,
+            snapshot_key,
+        );
+    }
+
+    async fn clear_type(&self, type_id: TypeId) {
+        self.clear_type_id(type_id);
+    }
+
+    async fn expiring(
+        &self,
+        type_id: TypeId,
+        authenticated: bool,
+        before: NaiveDateTime,
+    ) -> Vec<Arc<dyn Any + Send + Sync>> {
+        self.map(authenticated)
+            .iter()
+            .filter(|entry| entry.key().0 == type_id && entry.expiring < before)
+            .map(|entry| entry.value.shared())
+            .collect()
+    }
+
+    async fn wipe_static(&self) {
+        Cache::wipe_static(self).await
+    }
+
+    async fn wipe_authenticated(&self) {
+        Cache::wipe_authenticated(self).await
+    }
+}
+
+#[async_trait]
+impl DynCache for NoopCache {
+    async fn insert(&self, _key: CacheKey, _value: CacheValue, _expiring: NaiveDateTime) {}
+
+    async fn get(&self, _key: &CacheKey) -> Option<(Cached, Option<NaiveDateTime>)> {
+        None
+    }
+
+    async fn cleanup(&self) {}
+
+    async fn wipe_static(&self) {}
+
+    async fn wipe_authenticated(&self) {}
+}
+
+#[cfg(all(test, feature = "items"))]
+mod tests {
+    use chrono::Duration;
+    use gw2lib_model::{authenticated::account::Account, items::Item};
+
+    use super::*;
+    use crate::mock::JsonCache;
+
+    #[test]
+    fn in_memory_behind_trait_object() {
+        futures::executor::block_on(async {
+            let cache = DynamicCache::new(InMemoryCache::default());
+            let expires = Utc::now().naive_utc() + Duration::minutes(5);
+            cache
+                .insert::<u32, u32, Item, String>(&1, &10, expires, Language::En, &None)
+                .await;
+
+            let entry = cache.get_with_expiry::<u32, u32, Item, String>(&1, Language::En, &None);
+            assert_eq!(entry.await, Some((10, expires)));
+            let other_type = cache.get::<String, u32, Item, String>(&1, Language::En, &None);
+            assert_eq!(other_type.await, None);
+
+            let later = expires + Duration::minutes(1);
+            let expiring = cache.expiring::<u32, Item>(later).await;
+            assert_eq!(expiring.iter().map(|x| **x).collect::<Vec<_>>(), [10]);
+
+            cache.clear_type::<u32>().await;
+            let cleared = cache.get::<u32, u32, Item, String>(&1, Language::En, &None);
+            assert_eq!(cleared.await, None);
+        });
+    }
+
+    #[test]
+    fn json_behind_trait_object() {
+        futures::executor::block_on(async {
+            let json = Arc::new(JsonCache::default());
+            let cache = DynamicCache::from_arc(json.clone());
+            let expires = Utc::now().naive_utc() + Duration::minutes(5);
+            let auth = Some("key".to_string());
+            cache
+                .insert::<Vec<u32>, str, Account, String>(
+                    "",
+                    &vec![1, 2],
+                    expires,
+                    Language::En,
+                    &auth,
+                )
+                .await;
+
+            assert_eq!(
+                json.entries(),
+                [(
+                    "gw2lib_auth_v2/account_key_".to_string(),
+                    "[1,2]".to_string()
+                )]
+            );
+            let cached = cache.get::<Vec<u32>, str, Account, String>("", Language::En, &auth);
+            assert_eq!(cached.await, Some(vec![1, 2]));
+            let expiry =
+                cache.get_with_expiry::<Vec<u32>, str, Account, String>("", Language::En, &auth);
+            assert_eq!(expiry.await, None);
+        });
+    }
+}
@@ -1,19 +1,72 @@
 use std::{
-    any::{Any, TypeId},
-    collections::hash_map::RandomState,
+    any::{type_name, TypeId},
+    collections::hash_map::{DefaultHasher, RandomState},
+    fmt::{self, Display},
     hash::{BuildHasher, Hash, Hasher},
+    sync::Arc,
 };
 
 use async_trait::async_trait;
-use chrono::{NaiveDateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use dashmap::{mapref::entry::Entry, DashMap};
 use gw2lib_model::{Endpoint, Language};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use crate::cache::Cache;
+use crate::cache::{write_key_name, Cache, CacheValue, SyncCache};
 
+type Entries = DashMap<(TypeId, u64), Stored>;
+
+pub(crate) struct Stored {
+    pub(crate) expiring: NaiveDateTime,
+    pub(crate) value: CacheValue,
+    /// [`std::any::type_name`] of the value
+    pub(crate) type_name: &'static str,
+    /// names the entry in snapshots, see [`snapshot_key`]
+    pub(crate) snapshot_key: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    entries: Vec<SnapshotEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotEntry {
+    name: String,
+    authenticated: bool,
+    /// unix timestamp in milliseconds
+    expiring: i64,
+    json: String,
+}
+
+/// the default cache, keeping all entries in memory
+///
+/// Entries are spread across independently locked shards, selected by the
+/// hash of the cache key. Concurrent lookups for different ids therefore
+/// rarely contend on the same lock. Use [`InMemoryCache::with_shards`] to
+/// tune the number of shards for highly concurrent workloads.
 pub struct InMemoryCache {
-    statics: DashMap<(TypeId, u64), (NaiveDateTime, Box<dyn Any + Send + Sync>)>,
-    authenticated: DashMap<(TypeId, u64), (NaiveDateTime, Box<dyn Any + Send + Sync>)>,
+    statics: Entries,
+    authenticated: Entries,
+    /// entries of [`InMemoryCache::import`] that weren't requested yet, by
+    /// name
+    imported: DashMap<Box<str>, (bool, NaiveDateTime, String)>,
+}
+
+impl InMemoryCache {
+    /// creates a cache with `shards` internal shards per map
+    ///
+    /// `shards` is rounded up to the next power of two, with a minimum of 2.
+    /// The default uses four shards per available cpu core.
+    pub fn with_shards(shards: usize) -> Self {
+        let shards = shards.max(2).next_power_of_two();
+        let hasher = RandomState::new();
+        Self {
+            statics: DashMap::with_hasher_and_shard_amount(hasher.clone(), shards),
+            authenticated: DashMap::with_hasher_and_shard_amount(hasher, shards),
+            imported: DashMap::new(),
+        }
+    }
 }
 
 impl Default for InMemoryCache {
@@ -22,75 +75,327 @@ impl Default for InMemoryCache {
         Self {
             statics: DashMap::with_hasher(hasher.clone()),
             authenticated: DashMap::with_hasher(hasher),
+            imported: DashMap::new(),
         }
     }
 }
 
-#[async_trait]
-impl Cache for InMemoryCache {
-    async fn insert<T, I, E, A>(
-        &self,
-        id: &I,
-        endpoint: &T,
-        expiring: NaiveDateTime,
-        lang: Language,
-        auth: &Option<A>,
-    ) where
-        T: Clone + Send + Sync + 'static,
-        I: Hash + Sync + 'static + ?Sized,
-        E: Endpoint,
-        A: Hash + Sync + 'static,
-    {
-        let hash = hash::<_, T, I, A>(self.statics.hasher(), id, E::LOCALE.then_some(lang), auth);
-        let map = if E::AUTHENTICATED {
+impl InMemoryCache {
+    pub(crate) fn map(&self, authenticated: bool) -> &Entries {
+        if authenticated {
             &self.authenticated
         } else {
             &self.statics
-        };
-        map.insert(hash, (expiring, Box::new(endpoint.clone())));
+        }
     }
 
-    async fn get<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> Option<T>
+    pub(crate) fn clear_type_id(&self, type_id: TypeId) {
+        self.statics.retain(|(id, _), _| *id != type_id);
+        self.authenticated.retain(|(id, _), _| *id != type_id);
+    }
+
+    pub(crate) fn remove_entry(
+        &self,
+        authenticated: bool,
+        key: (TypeId, u64),
+        type_name: &str,
+        snapshot_key: u64,
+    ) {
+        self.map(authenticated).remove(&key);
+        if !self.imported.is_empty() {
+            self.imported.remove(&*stored_name(type_name, snapshot_key));
+        }
+    }
+
+    /// an imported entry that wasn't requested yet, as json
+    pub(crate) fn imported(&self, name: &str) -> Option<(String, NaiveDateTime)> {
+        let (_, expiring, json) = &*self.imported.get(name)?;
+        (Utc::now().naive_utc() < *expiring).then(|| (json.clone(), *expiring))
+    }
+
+    /// serializes all entries that didn't expire yet
+    ///
+    /// Values are stored as json, under a name made of the cached type and a
+    /// hash of the textual cache key, so api keys don't end up in the
+    /// snapshot. Restore the snapshot with
+    /// [`InMemoryCache::import`], e.g. to start a new deployment with a warm
+    /// cache.
+    pub fn export(&self) -> Vec<u8> {
+        let now = Utc::now().naive_utc();
+        let mut entries = Vec::new();
+        for (map, authenticated) in [(&self.statics, false), (&self.authenticated, true)] {
+            for entry in map.iter().filter(|x| now < x.expiring) {
+                if let Some(json) = entry.value.to_json() {
+                    entries.push(SnapshotEntry {
+                        name: stored_name(entry.type_name, entry.snapshot_key),
+                        authenticated,
+                        expiring: entry.expiring.and_utc().timestamp_millis(),
+                        json,
+                    });
+                }
+            }
+        }
+        for entry in self.imported.iter().filter(|x| now < x.1) {
+            let (authenticated, expiring, json) = entry.value();
+            entries.push(SnapshotEntry {
+                name: entry.key().to_string(),
+                authenticated: *authenticated,
+                expiring: expiring.and_utc().timestamp_millis(),
+                json: json.clone(),
+            });
+        }
+        serde_json::to_vec(&Snapshot { entries }).expect("snapshot is always serializable")
+    }
+
+    /// restores a snapshot of [`InMemoryCache::export`]
+    ///
+    /// Entries that expired in the meantime are skipped. The others are
+    /// deserialized on their first lookup, entries that no longer match
+    /// their type are treated as missing. The name of a type is not
+    /// guaranteed to be stable across compiler versions, so a snapshot is
+    /// best restored by the same build. The cache may already be in use by
+    /// a client.
+    pub fn import(&self, bytes: &[u8]) -> serde_json::Result<()> {
+        let snapshot: Snapshot = serde_json::from_slice(bytes)?;
+        let now = Utc::now().naive_utc();
+        for entry in snapshot.entries {
+            let expiring = DateTime::from_timestamp_millis(entry.expiring).map(|x| x.naive_utc());
+            if let Some(expiring) = expiring.filter(|x| now < *x) {
+                self.imported.insert(
+                    entry.name.into_boxed_str(),
+                    (entry.authenticated, expiring, entry.json),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn get_shared<T, I, E, A>(
+        &self,
+        id: &I,
+        lang: Language,
+        auth: &Option<A>,
+    ) -> Option<(Arc<T>, NaiveDateTime)>
     where
-        T: Clone + Send + Sync + 'static,
-        I: Hash + Sync + 'static + ?Sized,
+        T: DeserializeOwned + Serialize + Send + Sync + 'static,
+        I: Display + Hash + ?Sized + 'static,
         E: Endpoint,
-        A: Hash + Sync + 'static,
+        A: Display + Hash + 'static,
     {
         let hash = hash::<_, T, I, A>(self.statics.hasher(), id, E::LOCALE.then_some(lang), auth);
-        let map = if E::AUTHENTICATED {
-            &self.authenticated
-        } else {
-            &self.statics
-        };
+        let map = self.map(E::AUTHENTICATED);
         let entry = map.entry(hash);
         match entry {
             Entry::Occupied(entry) => {
-                let (expiring, any) = entry.get();
+                let Stored {
+                    expiring, value, ..
+                } = entry.get();
                 let now = Utc::now().naive_utc();
                 if now < *expiring {
-                    any.downcast_ref().cloned()
+                    let value = value.shared().downcast::<T>().ok()?;
+                    Some((value, *expiring))
                 } else {
                     entry.remove();
                     None
                 }
             }
+            Entry::Vacant(entry) if !self.imported.is_empty() => {
+                let snapshot_key = snapshot_key::<E, I, A>(id, lang, auth);
+                let name = stored_name(type_name::<T>(), snapshot_key);
+                let (_, (_, expiring, json)) = self.imported.remove(&*name)?;
+                if Utc::now().naive_utc() >= expiring {
+                    return None;
+                }
+                let value = CacheValue::new::<T>(serde_json::from_str(&json).ok()?);
+                let shared = value.shared().downcast::<T>().ok()?;
+                entry.insert(Stored {
+                    expiring,
+                    value,
+                    type_name: type_name::<T>(),
+                    snapshot_key,
+                });
+                Some((shared, expiring))
+            }
             Entry::Vacant(_) => None,
         }
     }
+}
+
+impl SyncCache for InMemoryCache {
+    fn get_sync<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> Option<T>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        self.get_shared::<T, I, E, A>(id, lang, auth)
+            .map(|(x, _)| T::clone(&x))
+    }
+}
+
+#[async_trait]
+impl Cache for InMemoryCache {
+    async fn insert<T, I, E, A>(
+        &self,
+        id: &I,
+        endpoint: &T,
+        expiring: NaiveDateTime,
+        lang: Language,
+        auth: &Option<A>,
+    ) where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        let hash = hash::<_, T, I, A>(self.statics.hasher(), id, E::LOCALE.then_some(lang), auth);
+        let map = self.map(E::AUTHENTICATED);
+        map.insert(
+            hash,
+            Stored {
+                expiring,
+                value: CacheValue::new(endpoint.clone()),
+                type_name: type_name::<T>(),
+                snapshot_key: snapshot_key::<E, I, A>(id, lang, auth),
+            },
+        );
+    }
+
+    async fn get<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> Option<T>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        self.get_shared::<T, I, E, A>(id, lang, auth)
+            .map(|(x, _)| T::clone(&x))
+    }
+
+    async fn get_with_expiry<T, I, E, A>(
+        &self,
+        id: &I,
+        lang: Language,
+        auth: &Option<A>,
+    ) -> Option<(T, NaiveDateTime)>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        self.get_shared::<T, I, E, A>(id, lang, auth)
+            .map(|(x, expiring)| (T::clone(&x), expiring))
+    }
+
+    async fn get_arc<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> Option<Arc<T>>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        self.get_shared::<T, I, E, A>(id, lang, auth)
+            .map(|(x, _)| x)
+    }
+
+    async fn expiring<T, E>(&self, before: NaiveDateTime) -> Vec<Arc<T>>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        E: Endpoint,
+    {
+        let map = self.map(E::AUTHENTICATED);
+        let type_id = TypeId::of::<T>();
+        map.iter()
+            .filter(|entry| entry.key().0 == type_id && entry.expiring < before)
+            .filter_map(|entry| entry.value.shared().downcast::<T>().ok())
+            .collect()
+    }
 
     async fn cleanup(&self) {
-        let now = Utc::now().naive_utc();
-        self.statics.retain(|_, (time, _)| *time < now);
-        self.authenticated.retain(|_, (time, _)| *time < now);
+        self.purge_expired(Utc::now().naive_utc()).await;
+    }
+
+    async fn purge_expired(&self, now: NaiveDateTime) -> usize {
+        // counted while retaining, entries inserted concurrently would throw
+        // off a difference of lengths
+        let mut removed = 0;
+        let mut keep = |expiring: &NaiveDateTime| {
+            let keep = now < *expiring;
+            removed += usize::from(!keep);
+            keep
+        };
+        self.statics.retain(|_, entry| keep(&entry.expiring));
+        self.authenticated.retain(|_, entry| keep(&entry.expiring));
+        self.imported.retain(|_, (_, time, _)| keep(time));
+        removed
+    }
+
+    async fn remove<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>)
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        let hash = hash::<_, T, I, A>(self.statics.hasher(), id, E::LOCALE.then_some(lang), auth);
+        let snapshot_key = snapshot_key::<E, I, A>(id, lang, auth);
+        self.remove_entry(E::AUTHENTICATED, hash, type_name::<T>(), snapshot_key);
+    }
+
+    async fn clear_type<T: 'static>(&self) {
+        self.clear_type_id(TypeId::of::<T>());
+        let prefix = format!("{} ", type_name::<T>());
+        self.imported.retain(|name, _| !name.starts_with(&prefix));
     }
 
     async fn wipe_static(&self) {
         self.statics.clear();
+        self.imported
+            .retain(|_, (authenticated, ..)| *authenticated);
     }
 
     async fn wipe_authenticated(&self) {
         self.authenticated.clear();
+        self.imported
+            .retain(|_, (authenticated, ..)| !*authenticated);
+    }
+}
+
+/// the name of an entry in a snapshot, see [`InMemoryCache::export`]
+pub(crate) fn stored_name(type_name: &str, snapshot_key: u64) -> String {
+    format!("{type_name} {snapshot_key:016x}")
+}
+
+/// a hash of the textual key of an entry, stable across processes of the
+/// same build
+///
+/// Hashed without building the textual key, so inserts don't allocate for
+/// it.
+pub(crate) fn snapshot_key<E: Endpoint, I: Display + ?Sized, A: Display>(
+    id: &I,
+    lang: Language,
+    auth: &Option<A>,
+) -> u64 {
+    let mut hasher = HashWriter(DefaultHasher::new());
+    write_key_name::<E, I, A>(&mut hasher, id, lang, auth).unwrap();
+    hasher.0.finish()
+}
+
+/// [`snapshot_key`] of an already built textual key
+pub(crate) fn snapshot_key_of(key_name: &str) -> u64 {
+    let mut hasher = HashWriter(DefaultHasher::new());
+    fmt::Write::write_str(&mut hasher, key_name).unwrap();
+    hasher.0.finish()
+}
+
+struct HashWriter(DefaultHasher);
+
+impl fmt::Write for HashWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write(s.as_bytes());
+        Ok(())
     }
 }
 
@@ -112,3 +417,229 @@ pub(crate) fn hash<H: BuildHasher, T: 'static, I: 'static + Hash + ?Sized, A: 's
 
     (type_id, hash)
 }
+
+#[cfg(all(test, feature = "items"))]
+mod tests {
+    use chrono::Duration;
+    use gw2lib_model::{authenticated::account::Account, items::Item};
+
+    use super::*;
+
+    #[test]
+    fn purge_removes_only_expired() {
+        futures::executor::block_on(async {
+            let cache = InMemoryCache::default();
+            let now = Utc::now().naive_utc();
+            let auth = Some("key".to_string());
+            for (id, expires) in [(1u32, -10), (2, -1), (3, 1), (4, 10)] {
+                cache
+                    .insert::<u32, u32, Item, String>(
+                        &id,
+                        &id,
+                        now + Duration::minutes(expires),
+                        Language::En,
+                        &None,
+                    )
+                    .await;
+            }
+            cache
+                .insert::<u32, str, Account, String>(
+                    "",
+                    &5,
+                    now - Duration::minutes(1),
+                    Language::En,
+                    &auth,
+                )
+                .await;
+
+            assert_eq!(cache.purge_expired(now).await, 3);
+            assert_eq!(cache.purge_expired(now).await, 0);
+            for id in [3u32, 4] {
+                let cached = cache.get::<u32, u32, Item, String>(&id, Language::En, &None);
+                assert_eq!(cached.await, Some(id));
+            }
+            assert_eq!(cache.statics.len(), 2);
+            assert!(cache.authenticated.is_empty());
+        });
+    }
+
+    /// throughput of concurrent lookups of different ids, by shard count
+    ///
+    /// `cargo test --release --lib -- --ignored --nocapture concurrent_lookups`
+    #[test]
+    #[ignore]
+    fn concurrent_lookups() {
+        let threads = 8;
+        let expires = Utc::now().naive_utc() + Duration::minutes(5);
+        for shards in [2, 8, 64] {
+            let cache = InMemoryCache::with_shards(shards);
+            for id in 0..10_000u32 {
+                let insert =
+                    cache.insert::<u32, u32, Item, String>(&id, &id, expires, Language::En, &None);
+                futures::executor::block_on(insert);
+            }
+
+            let start = std::time::Instant::now();
+            std::thread::scope(|s| {
+                for thread in 0..threads {
+                    let cache = &cache;
+                    s.spawn(move || {
+                        for i in 0..200_000u32 {
+                            let id = (i * 7 + thread * 1_000) % 10_000;
+                            let found =
+                                cache.get_sync::<u32, u32, Item, String>(&id, Language::En, &None);
+                            assert_eq!(found, Some(id));
+                        }
+                    });
+                }
+            });
+            let lookups = f64::from(threads) * 200_000.0;
+            println!(
+                "{threads} threads, {shards} shards: {:.1}M lookups/s",
+                lookups / start.elapsed().as_secs_f64() / 1e6
+            );
+        }
+    }
+
+    #[test]
+    fn expiry_of_cached_value() {
+        futures::executor::block_on(async {
+            let cache = InMemoryCache::default();
+            let expires = Utc::now().naive_utc() + Duration::minutes(5);
+            cache
+                .insert::<u32, u32, Item, String>(&1, &10, expires, Language::En, &None)
+                .await;
+
+            let entry = cache.get_with_expiry::<u32, u32, Item, String>(&1, Language::En, &None);
+            assert_eq!(entry.await, Some((10, expires)));
+            let missing = cache.get_with_expiry::<u32, u32, Item, String>(&2, Language::En, &None);
+            assert_eq!(missing.await, None);
+        });
+    }
+
+    #[test]
+    fn export_import_round_trip() {
+        futures::executor::block_on(async {
+            let cache = InMemoryCache::default();
+            let now = Utc::now().naive_utc();
+            let expires = now + Duration::minutes(5);
+            let auth = Some("key".to_string());
+            cache
+                .insert::<String, u32, Item, String>(
+                    &1,
+                    &"one".to_string(),
+                    expires,
+                    Language::En,
+                    &None,
+                )
+                .await;
+            cache
+                .insert::<String, u32, Item, String>(
+                    &2,
+                    &"two".to_string(),
+                    now - Duration::minutes(1),
+                    Language::En,
+                    &None,
+                )
+                .await;
+            cache
+                .insert::<u32, str, Account, String>("", &5, expires, Language::En, &auth)
+                .await;
+
+            let restored = InMemoryCache::default();
+            restored.import(&cache.export()).unwrap();
+            assert_eq!(restored.imported.len(), 2);
+
+            // entries that weren't requested yet are part of the next export
+            let again = InMemoryCache::default();
+            again.import(&restored.export()).unwrap();
+
+            for cache in [restored, again] {
+                let (one, expiring) = cache
+                    .get_with_expiry::<String, u32, Item, String>(&1, Language::En, &None)
+                    .await
+                    .unwrap();
+                assert_eq!(one, "one");
+                assert_eq!(
+                    expiring.and_utc().timestamp_millis(),
+                    expires.and_utc().timestamp_millis()
+                );
+                let expired = cache.get::<String, u32, Item, String>(&2, Language::En, &None);
+                assert_eq!(expired.await, None);
+                let other_lang = cache.get::<String, u32, Item, String>(&1, Language::De, &None);
+                assert_eq!(other_lang.await, None);
+                let account = cache.get::<u32, str, Account, String>("", Language::En, &auth);
+                assert_eq!(account.await, Some(5));
+            }
+        });
+    }
+
+    #[test]
+    fn snapshot_leaves_out_api_keys() {
+        futures::executor::block_on(async {
+            let cache = InMemoryCache::default();
+            let expires = Utc::now().naive_utc() + Duration::minutes(5);
+            let auth = Some("SECRET-API-KEY".to_string());
+            cache
+                .insert::<u32, str, Account, String>("", &5, expires, Language::En, &auth)
+                .await;
+
+            let snapshot = String::from_utf8(cache.export()).unwrap();
+            assert!(!snapshot.contains("SECRET-API-KEY"), "{snapshot}");
+            let name = crate::cache::key_name::<Account, str, String>("", Language::En, &auth);
+            assert_eq!(
+                snapshot_key::<Account, str, String>("", Language::En, &auth),
+                snapshot_key_of(&name)
+            );
+        });
+    }
+
+    #[test]
+    fn import_skips_mismatched_types() {
+        futures::executor::block_on(async {
+            let cache = InMemoryCache::default();
+            let expires = Utc::now().naive_utc() + Duration::minutes(5);
+            cache
+                .insert::<String, u32, Item, String>(
+                    &1,
+                    &"one".to_string(),
+                    expires,
+                    Language::En,
+                    &None,
+                )
+                .await;
+
+            let restored = InMemoryCache::default();
+            restored.import(&cache.export()).unwrap();
+            let other_type = restored.get::<u32, u32, Item, String>(&1, Language::En, &None);
+            assert_eq!(other_type.await, None);
+            assert!(restored.import(b"not a snapshot").is_err());
+        });
+    }
+
+    #[test]
+    fn clear_type_keeps_other_types() {
+        futures::executor::block_on(async {
+            let cache = InMemoryCache::default();
+            let expires = Utc::now().naive_utc() + Duration::minutes(5);
+            cache
+                .insert::<u32, u32, Item, String>(&1, &1, expires, Language::En, &None)
+                .await;
+            cache
+                .insert::<String, u32, Item, String>(
+                    &1,
+                    &"one".to_string(),
+                    expires,
+                    Language::En,
+                    &None,
+                )
+                .await;
+
+            cache.clear_type::<u32>().await;
+            let cleared = cache.get::<u32, u32, Item, String>(&1, Language::En, &None);
+            assert_eq!(cleared.await, None);
+            let kept = cache.get::<String, u32, Item, String>(&1, Language::En, &None);
+            assert_eq!(kept.await.as_deref(), Some("one"));
+        });
+    }
+}
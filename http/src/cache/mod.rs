@@ -1,11 +1,18 @@
-use std::{fmt::Display, hash::Hash, ops::Deref};
+use std::{
+    fmt::{self, Display, Write},
+    hash::Hash,
+    ops::Deref,
+    sync::Arc,
+};
 
 use async_trait::async_trait;
 use chrono::NaiveDateTime;
 use gw2lib_model::{Endpoint, Language};
 use serde::{de::DeserializeOwned, Serialize};
 
+mod dynamic;
 pub(crate) mod in_memory;
+pub use dynamic::{CacheKey, CacheValue, Cached, DynCache, DynamicCache};
 pub use in_memory::InMemoryCache;
 pub use noop::NoopCache;
 mod noop;
@@ -41,8 +48,84 @@ pub trait Cache {
         E: Endpoint,
         A: Display + Hash + Sync + 'static;
 
+    /// like [`Cache::get`], but hands out a shared reference to the cached
+    /// value instead of a deep clone
+    ///
+    /// The default implementation wraps the result of [`Cache::get`].
+    /// Caches that keep values in memory should override this.
+    async fn get_arc<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> Option<Arc<T>>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        self.get::<T, I, E, A>(id, lang, auth).await.map(Arc::new)
+    }
+
+    /// returns all cached values of type `T` that expire before `before`
+    ///
+    /// Used by [`Requester::refresh_expiring`](crate::Requester::refresh_expiring).
+    /// The default implementation returns nothing. Caches able to enumerate
+    /// their entries should override this, it is expected to scan every
+    /// entry.
+    async fn expiring<T, E>(&self, _before: NaiveDateTime) -> Vec<Arc<T>>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        E: Endpoint,
+    {
+        Vec::new()
+    }
+
+    /// like [`Cache::get`], but also returns when the entry expires
+    ///
+    /// The default implementation returns `None`, for caches that don't
+    /// track expiry.
+    async fn get_with_expiry<T, I, E, A>(
+        &self,
+        _id: &I,
+        _lang: Language,
+        _auth: &Option<A>,
+    ) -> Option<(T, NaiveDateTime)>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        None
+    }
+
     async fn cleanup(&self);
 
+    /// removes all entries that expired before `now` and returns how many
+    /// were removed
+    ///
+    /// The default implementation removes nothing, for caches that expire
+    /// entries on their own.
+    async fn purge_expired(&self, _now: NaiveDateTime) -> usize {
+        0
+    }
+
+    /// removes the entry of `id`, if cached
+    ///
+    /// Used by [`Requester::invalidate`](crate::Requester::invalidate). The
+    /// default implementation removes nothing.
+    async fn remove<T, I, E, A>(&self, _id: &I, _lang: Language, _auth: &Option<A>)
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+    }
+
+    /// removes all entries stored as type `T`, in all languages and for all
+    /// authentications
+    ///
+    /// The default implementation removes nothing.
+    async fn clear_type<T: 'static>(&self) {}
+
     async fn wipe(&self) {
         self.wipe_static().await;
         self.wipe_authenticated().await;
@@ -53,6 +136,21 @@ pub trait Cache {
     async fn wipe_authenticated(&self);
 }
 
+/// a cache that can be read without awaiting
+///
+/// Implemented by caches keeping their entries in memory. Lets the blocking
+/// client read the cache without blocking on its runtime, which panics when
+/// called from within async code.
+pub trait SyncCache: Cache {
+    /// like [`Cache::get`], but synchronous
+    fn get_sync<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> Option<T>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static;
+}
+
 #[async_trait]
 pub(crate) trait CleanupCache {
     async fn cleanup(&self);
@@ -102,10 +200,63 @@ where
         self.deref().get::<T, I, E, A>(id, lang, auth).await
     }
 
+    async fn get_arc<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> Option<Arc<T>>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        self.deref().get_arc::<T, I, E, A>(id, lang, auth).await
+    }
+
+    async fn get_with_expiry<T, I, E, A>(
+        &self,
+        id: &I,
+        lang: Language,
+        auth: &Option<A>,
+    ) -> Option<(T, NaiveDateTime)>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        self.deref()
+            .get_with_expiry::<T, I, E, A>(id, lang, auth)
+            .await
+    }
+
+    async fn expiring<T, E>(&self, before: NaiveDateTime) -> Vec<Arc<T>>
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        E: Endpoint,
+    {
+        self.deref().expiring::<T, E>(before).await
+    }
+
     async fn cleanup(&self) {
         self.deref().cleanup().await
     }
 
+    async fn purge_expired(&self, now: NaiveDateTime) -> usize {
+        self.deref().purge_expired(now).await
+    }
+
+    async fn remove<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>)
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        self.deref().remove::<T, I, E, A>(id, lang, auth).await
+    }
+
+    async fn clear_type<T: 'static>(&self) {
+        self.deref().clear_type::<T>().await
+    }
+
     async fn wipe_static(&self) {
         self.deref().wipe_static().await
     }
@@ -114,3 +265,42 @@ where
         self.deref().wipe_authenticated().await
     }
 }
+
+/// builds the textual key of an entry, as used by caches storing strings
+pub(crate) fn key_name<E: Endpoint, I: Display + ?Sized, A: Display>(
+    id: &I,
+    lang: Language,
+    auth: &Option<A>,
+) -> String {
+    let mut key = String::with_capacity(128);
+    write_key_name::<E, I, A>(&mut key, id, lang, auth).unwrap();
+    key
+}
+
+/// writes the textual key of an entry, see [`key_name`]
+pub(crate) fn write_key_name<E: Endpoint, I: Display + ?Sized, A: Display>(
+    key: &mut impl Write,
+    id: &I,
+    lang: Language,
+    auth: &Option<A>,
+) -> fmt::Result {
+    key.write_str("gw2lib_")?;
+
+    if E::AUTHENTICATED {
+        key.write_str("auth_")?;
+    } else {
+        key.write_str("static_")?;
+    }
+
+    write!(key, "{}_", E::URL)?;
+
+    if E::LOCALE {
+        write!(key, "{}_", lang.as_str())?;
+    }
+
+    if E::AUTHENTICATED {
+        write!(key, "{}_", auth.as_ref().unwrap())?;
+    }
+
+    write!(key, "{}", id)
+}
@@ -4,8 +4,12 @@ use async_trait::async_trait;
 use chrono::NaiveDateTime;
 use gw2lib_model::{Endpoint, Language};
 
-use crate::cache::Cache;
+use crate::cache::{Cache, SyncCache};
 
+/// a cache that never stores anything
+///
+/// See [`Client::no_cache`](crate::Client::no_cache).
+#[derive(Clone, Copy, Debug, Default)]
 pub struct NoopCache;
 #[async_trait]
 impl Cache for NoopCache {
@@ -40,3 +44,15 @@ impl Cache for NoopCache {
 
     async fn wipe_authenticated(&self) {}
 }
+
+impl SyncCache for NoopCache {
+    fn get_sync<T, I, E, A>(&self, _id: &I, _lang: Language, _auth: &Option<A>) -> Option<T>
+    where
+        T: Clone + Send + Sync + 'static,
+        I: Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Sync + 'static,
+    {
+        None
+    }
+}
@@ -1,7 +1,4 @@
-use std::{
-    fmt::{Display, Write},
-    hash::Hash,
-};
+use std::{fmt::Display, hash::Hash};
 
 use async_trait::async_trait;
 use chrono::{NaiveDateTime, Utc};
@@ -12,7 +9,7 @@ use redis::{
 };
 use serde::{de::DeserializeOwned, Serialize};
 
-use crate::cache::Cache;
+use crate::cache::{key_name, Cache, CacheKey, CacheValue, Cached, DynCache};
 
 #[derive(Debug, Clone)]
 pub struct RedisCache {
@@ -29,7 +26,7 @@ impl Cache for RedisCache {
         lang: Language,
         auth: &Option<A>,
     ) where
-        T: DeserializeOwned + Serialize + Clone + Send + Sync + Sync + 'static,
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
         I: Display + Hash + Sync + 'static + ?Sized,
         E: Endpoint,
         A: Display + Hash + Sync + 'static,
@@ -39,7 +36,7 @@ impl Cache for RedisCache {
             None => return,
         };
         let ex = expiring - Utc::now().naive_utc();
-        let key = self.gen_key::<E, I, A>(id, lang, auth);
+        let key = key_name::<E, I, A>(id, lang, auth);
         if let Ok(value) = serde_json::to_string(endpoint) {
             conn.set_ex::<_, _, ()>(key, value, ex.num_seconds().try_into().unwrap_or_default())
                 .await
@@ -49,19 +46,33 @@ impl Cache for RedisCache {
 
     async fn get<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>) -> Option<T>
     where
-        T: DeserializeOwned + Serialize + Clone + Send + Sync + Sync + 'static,
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
         I: Display + Hash + Sync + 'static + ?Sized,
         E: Endpoint,
         A: Display + Hash + Sync + 'static,
     {
         let mut conn = self.connection().await?;
-        let key = self.gen_key::<E, I, A>(id, lang, auth);
+        let key = key_name::<E, I, A>(id, lang, auth);
         conn.get(key)
             .await
             .ok()
             .and_then(|x: String| serde_json::from_str(&x).ok())
     }
 
+    async fn remove<T, I, E, A>(&self, id: &I, lang: Language, auth: &Option<A>)
+    where
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+        I: Display + Hash + Sync + 'static + ?Sized,
+        E: Endpoint,
+        A: Display + Hash + Sync + 'static,
+    {
+        let Some(mut conn) = self.connection().await else {
+            return;
+        };
+        let key = key_name::<E, I, A>(id, lang, auth);
+        conn.del::<_, ()>(key).await.ok();
+    }
+
     async fn cleanup(&self) {}
 
     async fn wipe_static(&self) {
@@ -73,6 +84,49 @@ impl Cache for RedisCache {
     }
 }
 
+#[async_trait]
+impl DynCache for RedisCache {
+    async fn insert(&self, key: CacheKey, value: CacheValue, expiring: NaiveDateTime) {
+        let mut conn = match self.connection().await {
+            Some(conn) => conn,
+            None => return,
+        };
+        let ex = expiring - Utc::now().naive_utc();
+        if let Some(value) = value.to_json() {
+            conn.set_ex::<_, _, ()>(
+                key.name,
+                value,
+                ex.num_seconds().try_into().unwrap_or_default(),
+            )
+            .await
+            .ok();
+        }
+    }
+
+    async fn get(&self, key: &CacheKey) -> Option<(Cached, Option<NaiveDateTime>)> {
+        let mut conn = self.connection().await?;
+        let value: String = conn.get(&key.name).await.ok()?;
+        Some((Cached::Json(value), None))
+    }
+
+    async fn remove(&self, key: &CacheKey) {
+        let Some(mut conn) = self.connection().await else {
+            return;
+        };
+        conn.del::<_, ()>(&key.name).await.ok();
+    }
+
+    async fn cleanup(&self) {}
+
+    async fn wipe_static(&self) {
+        Cache::wipe_static(self).await
+    }
+
+    async fn wipe_authenticated(&self) {
+        Cache::wipe_authenticated(self).await
+    }
+}
+
 impl RedisCache {
     pub fn new(client: Client) -> Self {
         Self { client }
@@ -122,39 +176,4 @@ impl RedisCache {
 
         conn.del::<_, ()>(chunk).await
     }
-
-    fn gen_key<E: Endpoint, I: Display + ?Sized, A: Display>(
-        &self,
-        id: &I,
-        lang: Language,
-        auth: &Option<A>,
-    ) -> String {
-        let mut key = String::with_capacity(128);
-        let mut push = |s: &str| {
-            key.push_str(s);
-            key.push('_');
-        };
-
-        push("gw2lib");
-
-        if E::AUTHENTICATED {
-            push("auth");
-        } else {
-            push("static");
-        }
-
-        push(E::URL);
-
-        if E::LOCALE {
-            push(lang.as_str());
-        }
-
-        if E::AUTHENTICATED {
-            write!(key, "{}_", auth.as_ref().unwrap()).unwrap();
-        }
-
-        write!(key, "{}", id).unwrap();
-
-        key
-    }
 }
@@ -1,11 +1,23 @@
-use std::{fmt::Display, hash::Hash};
+use std::{fmt::Display, hash::Hash, sync::Arc};
 
-use chrono::Duration;
-use gw2lib_model::{BulkEndpoint, Endpoint, EndpointWithId, FixedEndpoint};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+#[cfg(feature = "achievements")]
+use gw2lib_model::daily_rewards::DailyProgress;
+#[cfg(feature = "items")]
+use gw2lib_model::items::{Item, ItemId, Rarity};
+#[cfg(feature = "misc")]
+use gw2lib_model::misc::worlds::{PopulationChange, World};
+#[cfg(all(feature = "items", feature = "commerce"))]
+use gw2lib_model::tradingpost::Prices;
+use gw2lib_model::{BulkEndpoint, Endpoint, EndpointWithId, FixedEndpoint, Language};
 use serde::{de::DeserializeOwned, Serialize};
+use tokio_util::sync::CancellationToken;
 
 use super::requester::Requester as Req;
-use crate::{block::block, CachedRequest, Client, EndpointResult};
+use crate::{
+    block::block, cache::SyncCache, CachedRequest, Client, DataSource, EndpointResult, PageInfo,
+    SchemaVersion,
+};
 
 pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>:
     Req<AUTHENTICATED, FORCE>
@@ -34,11 +46,53 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>:
     fn cached(
         &self,
         cache_duration: Duration,
-    ) -> CachedRequest<Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
     {
         Req::cached(self, cache_duration)
     }
 
+    /// like [`Requester::cached`], but takes a [`std::time::Duration`]
+    ///
+    /// Durations too long for [`chrono::Duration`] are capped.
+    fn cached_std(
+        &self,
+        cache_duration: std::time::Duration,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        Req::cached_std(self, cache_duration)
+    }
+
+    /// caches all requests returned from this function until `at`,
+    /// regardless of the cache duration sent by the api
+    fn cached_until(
+        &self,
+        at: DateTime<Utc>,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        Req::cached_until(self, at)
+    }
+
+    /// caches all requests returned from this function until the next daily
+    /// reset
+    ///
+    /// Meant for daily endpoints, like the daily completions of an account,
+    /// which change exactly at reset.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::{
+    ///     model::authenticated::account::daily_rewards::AccountWorldBosses, Client, Requester,
+    /// };
+    ///
+    /// let client = Client::default().api_key("<subtoken>");
+    /// let bosses: AccountWorldBosses = client.cached_until_reset().get().unwrap();
+    /// ```
+    fn cached_until_reset(
+        &self,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        Req::cached_until_reset(self)
+    }
+
     /// forces a fresh copy from the api
     /// ## Example
     /// ```
@@ -53,11 +107,97 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>:
     /// let build_id: Build = client.forced().get().unwrap();
     fn forced(
         &self,
-    ) -> CachedRequest<Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, true>
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, true>
     {
         Req::forced(self)
     }
 
+    /// doesn't write responses of the returned requester to the cache
+    ///
+    /// The cache is still read, combine with [`Requester::forced`] or use
+    /// [`Requester::forced_no_store`] to leave the cache out entirely.
+    fn no_store(
+        &self,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        Req::no_store(self)
+    }
+
+    /// forces a fresh copy from the api without writing it to the cache
+    ///
+    /// A transparent fetch that leaves the cache as it is, e.g. for a one-off
+    /// debug read. How requesters use the cache:
+    ///
+    /// | requester | reads the cache | writes the cache |
+    /// |---|---|---|
+    /// | `client` | yes | yes |
+    /// | [`client.forced()`](Requester::forced) | no | yes |
+    /// | [`client.no_store()`](Requester::no_store) | yes | no |
+    /// | `client.forced_no_store()` | no | no |
+    fn forced_no_store(
+        &self,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, true>
+    {
+        Req::forced_no_store(self)
+    }
+
+    /// aborts all requests made through the returned requester once `token`
+    /// is cancelled
+    ///
+    /// Cancelled requests return
+    /// [`EndpointError::Cancelled`](crate::EndpointError::Cancelled).
+    fn with_cancellation(
+        &self,
+        token: CancellationToken,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        Req::with_cancellation(self, token)
+    }
+
+    /// requests localized endpoints in `language` instead of the language of
+    /// the client
+    ///
+    /// Responses are cached per language, so this doesn't mix with cached
+    /// responses of other languages.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::{
+    ///     model::{items::Item, Language},
+    ///     Client, Requester,
+    /// };
+    ///
+    /// let client = Client::default();
+    /// let english: Item = client.single(19721).unwrap();
+    /// let german: Item = client.in_language(Language::De).single(19721).unwrap();
+    /// ```
+    fn in_language(
+        &self,
+        language: Language,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        Req::in_language(self, language)
+    }
+
+    /// requests all endpoints with the schema `version` instead of the
+    /// version of their model
+    ///
+    /// [`Client::strict_schema`] takes precedence. Responses are cached
+    /// regardless of the schema version they were requested with.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::{model::misc::build::Build, Client, Requester, SchemaVersion};
+    ///
+    /// let client = Client::default();
+    /// let build: Build = client.schema_version(SchemaVersion::Latest).get().unwrap();
+    /// ```
+    fn schema_version(
+        &self,
+        version: SchemaVersion,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        Req::schema_version(self, version)
+    }
+
     /// call the fixed endpoint
     fn get<T: DeserializeOwned + Serialize + Clone + Send + Sync + FixedEndpoint + 'static>(
         &self,
@@ -65,7 +205,19 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>:
         block(Req::get(self))
     }
 
+    /// like [`Requester::get`], but returns a shared reference to the
+    /// cached value instead of cloning it
+    fn get_arc<T: DeserializeOwned + Serialize + Clone + Send + Sync + FixedEndpoint + 'static>(
+        &self,
+    ) -> EndpointResult<Arc<T>> {
+        block(Req::get_arc(self))
+    }
+
     /// request a single item
+    ///
+    /// Unlike the async requester, `id` has to be the exact id type of `T`,
+    /// so integer literals like `client.single(19721)` infer their type.
+    /// String ids need an owned `String`: `client.single("box".to_string())`.
     fn single<
         T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
         I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
@@ -76,6 +228,120 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>:
         block(Req::single(self, id))
     }
 
+    /// like [`Requester::single`], but also returns where the item came from
+    ///
+    /// Useful to skip work for values that were already seen, e.g.
+    /// re-rendering only for [`DataSource::Network`].
+    fn single_with_source<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
+    >(
+        &self,
+        id: I,
+    ) -> EndpointResult<(T, DataSource)> {
+        block(Req::single_with_source(self, id))
+    }
+
+    /// like [`Requester::single`], but returns `None` if the id doesn't exist
+    ///
+    /// With [`Client::negative_cache`], ids that weren't found are
+    /// remembered and not requested again until it expires. Only a not
+    /// found response from the api results in `None`, network and all other
+    /// errors are still returned.
+    fn try_single<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
+    >(
+        &self,
+        id: I,
+    ) -> EndpointResult<Option<T>> {
+        block(Req::try_single(self, id))
+    }
+
+    /// like [`Requester::single`], but returns `T::default()` if the id
+    /// doesn't exist
+    ///
+    /// Only a not found response from the api is substituted, network and
+    /// all other errors are still returned.
+    fn single_or_default<
+        T: DeserializeOwned
+            + Serialize
+            + Clone
+            + Default
+            + Send
+            + Sync
+            + EndpointWithId<IdType = I>
+            + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
+    >(
+        &self,
+        id: I,
+    ) -> EndpointResult<T> {
+        block(Req::single_or_default(self, id))
+    }
+
+    /// like [`Requester::single`], but returns a shared reference to
+    /// the cached value instead of cloning it
+    fn single_arc<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
+    >(
+        &self,
+        id: I,
+    ) -> EndpointResult<Arc<T>> {
+        block(Req::single_arc(self, id))
+    }
+
+    /// returns a cached item together with the time it expires, without
+    /// making a request
+    ///
+    /// Returns `None` if the item isn't cached or the cache doesn't track
+    /// expiry. Ignores [`Requester::forced`].
+    fn cache_entry<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
+    >(
+        &self,
+        id: I,
+    ) -> Option<(T, NaiveDateTime)> {
+        block(Req::cache_entry(self, id))
+    }
+
+    /// checks whether an item exists without deserializing it
+    ///
+    /// Cached items count as existing. Otherwise only the status code of the
+    /// response is inspected.
+    fn exists<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
+    >(
+        &self,
+        id: I,
+    ) -> EndpointResult<bool> {
+        block(Req::exists::<T, I>(self, id))
+    }
+
+    /// checks which of the given ids exist
+    ///
+    /// The result has the same order as `ids`. Ids that aren't cached are
+    /// requested in chunks, but only their ids are deserialized.
+    fn exists_many<
+        T: DeserializeOwned
+            + Serialize
+            + EndpointWithId<IdType = I>
+            + BulkEndpoint
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        I: Display + DeserializeOwned + Hash + Clone + Eq + Send + Sync + 'static,
+    >(
+        &self,
+        ids: Vec<I>,
+    ) -> EndpointResult<Vec<bool>> {
+        block(Req::exists_many::<T, I>(self, ids))
+    }
+
     /// retrieves an item from cache
     /// ```
     /// use gw2lib::{model::items::Item, Client, Requester};
@@ -93,7 +359,151 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>:
         block(Req::try_get(self, id))
     }
 
+    /// like [`Requester::try_get`], but reads the cache directly instead of
+    /// blocking on the runtime
+    ///
+    /// Only available for caches that can be read synchronously, like the
+    /// default [`InMemoryCache`](crate::cache::InMemoryCache). Never blocks,
+    /// so it's safe to call on a UI thread or from within async code.
+    /// ```
+    /// use gw2lib::{model::items::Item, Client, Requester};
+    ///
+    /// let client = Client::default();
+    /// let from_cache: Option<Item> = client.try_get_sync(&19721);
+    /// ```
+    fn try_get_sync<
+        T: DeserializeOwned + Serialize + Clone + Endpoint + Send + Sync + 'static,
+        I: DeserializeOwned + Display + Hash + Clone + Sync + 'static,
+    >(
+        &self,
+        id: &I,
+    ) -> Option<T>
+    where
+        Self::Caching: SyncCache,
+    {
+        super::requester::try_get_sync::<T, I, Self, AUTHENTICATED, FORCE>(self, id)
+    }
+
+    /// like [`Requester::try_get_sync`] for multiple ids, returning `None`
+    /// for every id that isn't cached
+    fn try_get_many_sync<
+        T: DeserializeOwned + Serialize + Clone + Endpoint + Send + Sync + 'static,
+        I: DeserializeOwned + Display + Hash + Clone + Sync + 'static,
+    >(
+        &self,
+        ids: &[I],
+    ) -> Vec<Option<T>>
+    where
+        Self::Caching: SyncCache,
+    {
+        ids.iter().map(|id| self.try_get_sync(id)).collect()
+    }
+
+    /// removes an item from the cache, so the next request for it is sent to
+    /// the api
+    ///
+    /// Only the entry in the language of this requester is removed. Unlike
+    /// [`Requester::invalidate_type`], all other items stay cached. Also
+    /// forgets that the id wasn't found, see [`Client::negative_cache`].
+    /// ```
+    /// use gw2lib::{model::items::Item, Client, Requester};
+    ///
+    /// let client = Client::default();
+    /// client.invalidate::<Item, u32>(&19721);
+    /// ```
+    fn invalidate<
+        T: DeserializeOwned + Serialize + Clone + Endpoint + Send + Sync + 'static,
+        I: DeserializeOwned + Display + Hash + Clone + Sync + 'static,
+    >(
+        &self,
+        id: &I,
+    ) {
+        block(Req::invalidate::<T, I>(self, id))
+    }
+
+    /// requests an endpoint that isn't modeled yet and returns the raw json
+    ///
+    /// `path` is in the format `v2/account`. The request respects the rate
+    /// limiter, but is neither cached nor deduplicated. The language and, if
+    /// set, the api key are always sent. The schema version defaults to
+    /// `latest`, use [`Requester::raw_get_versioned`] to pin one.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::{Client, Requester};
+    ///
+    /// let client = Client::default();
+    /// let colors = client.raw_get("v2/colors", &[("ids", "1,2")]).unwrap();
+    /// ```
+    fn raw_get(&self, path: &str, queries: &[(&str, &str)]) -> EndpointResult<serde_json::Value> {
+        block(Req::raw_get(self, path, queries))
+    }
+
+    /// like [`Requester::raw_get`], but requests a specific schema version
+    fn raw_get_versioned(
+        &self,
+        path: &str,
+        queries: &[(&str, &str)],
+        version: &str,
+    ) -> EndpointResult<serde_json::Value> {
+        block(Req::raw_get_versioned(self, path, queries, version))
+    }
+
+    /// like [`Requester::raw_get`], but deserializes into a caller supplied
+    /// type
+    ///
+    /// `D` doesn't have to be an [`Endpoint`], so the response is neither
+    /// cached nor deduplicated with concurrent requests.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::{Client, Requester};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Build {
+    ///     id: u64,
+    /// }
+    ///
+    /// let client = Client::default();
+    /// let build: Build = client.raw_typed("v2/build", &[]).unwrap();
+    /// ```
+    fn raw_typed<D: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        queries: &[(&str, &str)],
+    ) -> EndpointResult<D> {
+        block(Req::raw_typed(self, path, queries))
+    }
+
+    /// like [`Requester::raw_typed`], but requests a specific schema version
+    fn raw_typed_versioned<D: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        queries: &[(&str, &str)],
+        version: &str,
+    ) -> EndpointResult<D> {
+        block(Req::raw_typed_versioned(self, path, queries, version))
+    }
+
+    /// sends `body` as json to an endpoint that isn't modeled yet and returns
+    /// the raw json response
+    ///
+    /// Behaves like [`Requester::raw_get`] otherwise.
+    fn raw_post(
+        &self,
+        path: &str,
+        queries: &[(&str, &str)],
+        body: &serde_json::Value,
+    ) -> EndpointResult<serde_json::Value> {
+        block(Req::raw_post(self, path, queries, body))
+    }
+
     /// request all available ids
+    ///
+    /// The order is defined by the api and differs between endpoints. Use
+    /// [`Requester::ids_sorted`] for a stable order.
+    ///
+    /// Requests the endpoint without `ids`, which only returns the ids. Works
+    /// for every endpoint with ids, regardless of [`BulkEndpoint::ALL`].
     fn ids<
         T: DeserializeOwned + Serialize + EndpointWithId<IdType = I> + Clone + Send + Sync + 'static,
         I: Display + DeserializeOwned + Serialize + Hash + Clone + Send + Sync + 'static,
@@ -103,6 +513,107 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>:
         block(Req::ids::<T, I>(self))
     }
 
+    /// request all available ids, sorted ascending
+    fn ids_sorted<
+        T: DeserializeOwned + Serialize + EndpointWithId<IdType = I> + Clone + Send + Sync + 'static,
+        I: Display + DeserializeOwned + Serialize + Hash + Clone + Ord + Send + Sync + 'static,
+    >(
+        &self,
+    ) -> EndpointResult<Vec<I>> {
+        block(Req::ids_sorted::<T, I>(self))
+    }
+
+    /// request all items with an id greater than `after`, in ascending order
+    /// of their ids
+    ///
+    /// Resumes an interrupted sync: persist the id of the last processed
+    /// item and pass it as `after` on restart. This relies on ids keeping
+    /// their order between runs, which holds for the numeric ids of the
+    /// api. See [`Requester::ids_sorted`].
+    fn all_from<
+        T: DeserializeOwned
+            + Serialize
+            + EndpointWithId<IdType = I>
+            + BulkEndpoint
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        I: Display + DeserializeOwned + Serialize + Hash + Clone + Ord + Send + Sync + 'static,
+    >(
+        &self,
+        after: I,
+    ) -> EndpointResult<Vec<T>> {
+        block(Req::all_from::<T, I>(self, after))
+    }
+
+    /// requests all items and returns those matching `pred`
+    ///
+    /// For endpoints that don't support `ids=all`, the items are requested
+    /// in batches and filtered batch by batch, so the full set is never
+    /// held at once. Items not matching `pred` are still cached.
+    fn all_filtered<
+        T: DeserializeOwned
+            + Serialize
+            + EndpointWithId<IdType = I>
+            + BulkEndpoint
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        I: Display + DeserializeOwned + Serialize + Hash + Clone + Send + Sync + Eq + 'static,
+    >(
+        &self,
+        pred: impl Fn(&T) -> bool + Send + Sync,
+    ) -> EndpointResult<Vec<T>> {
+        block(Req::all_filtered(self, pred))
+    }
+
+    /// requests all items of the given rarity
+    ///
+    /// See [`Requester::all_filtered`].
+    #[cfg(feature = "items")]
+    fn items_by_rarity(&self, rarity: Rarity) -> EndpointResult<Vec<Item>> {
+        block(Req::items_by_rarity(self, rarity))
+    }
+
+    /// re-fetches all cached entries of `T` that expire within `within`
+    ///
+    /// Keeps a long running cache warm: call this periodically and users
+    /// never wait on an expired entry. Returns the number of refreshed
+    /// entries.
+    ///
+    /// ### Remarks
+    /// This scans the whole cache through
+    /// [`Cache::expiring`](crate::Cache::expiring), which caches that can't
+    /// enumerate their entries don't support. The entries are requested with
+    /// the language and identifier of this client, even if they were cached
+    /// by a client with a different one.
+    fn refresh_expiring<
+        T: DeserializeOwned
+            + Serialize
+            + EndpointWithId<IdType = I>
+            + BulkEndpoint
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        I: Display + DeserializeOwned + Hash + Clone + Eq + Send + Sync + 'static,
+    >(
+        &self,
+        within: Duration,
+    ) -> EndpointResult<usize> {
+        block(Req::refresh_expiring::<T, I>(self, within))
+    }
+
+    /// drops all cached entries of `T`, e.g. all skills after a balance patch
+    ///
+    /// This includes the lists cached by [`Requester::all`]. Cached id lists
+    /// are kept, as they only depend on the id type.
+    fn invalidate_type<T: Endpoint + Send + Sync + 'static>(&self) {
+        block(Req::invalidate_type::<T>(self))
+    }
+
     /// request multiple ids at once
     fn many<
         T: DeserializeOwned
@@ -123,6 +634,8 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>:
 
     /// requests a page of items and returns the number of total items across
     /// all pages
+    ///
+    /// See [`Requester::page_with_info`] for the other paging headers.
     fn page<
         T: DeserializeOwned
             + EndpointWithId<IdType = I>
@@ -141,6 +654,28 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>:
         block(Req::page(self, page, page_size, result))
     }
 
+    /// like [`Requester::page`], but returns all paging headers of the
+    /// response
+    ///
+    /// Missing headers are derived from the request and the response body.
+    fn page_with_info<
+        T: DeserializeOwned
+            + EndpointWithId<IdType = I>
+            + BulkEndpoint
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        I: Display + DeserializeOwned + Hash + Clone + Sync + 'static,
+    >(
+        &self,
+        page: usize,
+        page_size: u8,
+        result: &mut Vec<T>,
+    ) -> EndpointResult<PageInfo> {
+        block(Req::page_with_info(self, page, page_size, result))
+    }
+
     /// requests all items using the most efficient method available
     /// ### Remarks
     /// for most endpoints this means using [`Self::get_all_by_requesting_ids`].
@@ -219,6 +754,67 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>:
     ) -> EndpointResult<Vec<T>> {
         block(Req::get_all_by_requesting_ids(self))
     }
+
+    /// requests an item and its trading post prices at once
+    ///
+    /// The prices are `None` for items that can't be traded.
+    #[cfg(all(feature = "items", feature = "commerce"))]
+    fn item_with_price(&self, id: ItemId) -> EndpointResult<(Item, Option<Prices>)> {
+        block(Req::item_with_price(self, id))
+    }
+
+    /// like [`Requester::item_with_price`] for multiple items
+    ///
+    /// The items are returned in the order of `ids`, ids that don't exist
+    /// are skipped.
+    #[cfg(all(feature = "items", feature = "commerce"))]
+    fn items_with_prices(&self, ids: Vec<ItemId>) -> EndpointResult<Vec<(Item, Option<Prices>)>> {
+        block(Req::items_with_prices(self, ids))
+    }
+
+    /// the item and all items linked to it through `upgrades_into` and
+    /// `upgrades_from`, like the attuned and infused versions of a ring
+    ///
+    /// Starts with the item itself, followed by the linked items in the
+    /// order they were found. Every item is requested once through
+    /// [`Requester::many`] and cached like any other item.
+    #[cfg(feature = "items")]
+    fn upgrade_chain(&self, id: ItemId) -> EndpointResult<Vec<Item>> {
+        block(Req::upgrade_chain(self, id))
+    }
+
+    /// the legendaries unlocked in the armory of the account, paired with
+    /// the unlocked count and sorted by item id
+    ///
+    /// The items are requested through [`Requester::many`] and cached like
+    /// any other item. Requires the `inventories` and `unlocks` scopes.
+    #[cfg(feature = "characters")]
+    fn resolved_legendary_armory(&self) -> EndpointResult<Vec<(Item, u32)>> {
+        block(Req::resolved_legendary_armory(self))
+    }
+
+    /// which world bosses, daily crafts and map chests the account already
+    /// completed since the last daily reset
+    ///
+    /// The lists of all rewards are cached for a day, the progress of the
+    /// account until the next daily reset. Requires the `progression` scope.
+    #[cfg(feature = "achievements")]
+    fn daily_progress(&self) -> EndpointResult<DailyProgress> {
+        block(Req::daily_progress(self))
+    }
+
+    /// fetches all worlds and returns those whose population changed
+    /// compared to `previous`, as `(id, old, new)`
+    ///
+    /// Always requests fresh data, see [`Requester::forced`]. Pass the
+    /// worlds of the previous call to poll for changes.
+    #[cfg(feature = "misc")]
+    fn world_population_changes(
+        &self,
+        previous: &[World],
+    ) -> EndpointResult<Vec<PopulationChange>> {
+        block(Req::world_population_changes(self, previous))
+    }
 }
 
 impl<T: Req<AUTHENTICATED, FORCE>, const AUTHENTICATED: bool, const FORCE: bool>
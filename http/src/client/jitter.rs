@@ -0,0 +1,106 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::BuildHasher,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use chrono::Duration;
+
+/// spreads cache expiries by a random fraction of their duration
+///
+/// Uses splitmix64, so a fixed seed yields the same sequence. The state is
+/// shared between clones of a client. Passed to [`Client::cache_jitter_with`]
+/// for reproducible expiries, e.g. in tests.
+///
+/// [`Client::cache_jitter_with`]: crate::Client::cache_jitter_with
+#[derive(Clone, Debug)]
+pub struct Jitter {
+    fraction: f64,
+    state: Arc<AtomicU64>,
+}
+
+impl Jitter {
+    /// a randomly seeded jitter, `fraction` is clamped to `0.0..=1.0`
+    pub fn new(fraction: f64) -> Self {
+        Self::with_seed(fraction, RandomState::new().hash_one(0))
+    }
+
+    /// a jitter that yields the same sequence for the same `seed`,
+    /// `fraction` is clamped to `0.0..=1.0`
+    pub fn with_seed(fraction: f64, seed: u64) -> Self {
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+        Self {
+            fraction,
+            state: Arc::new(AtomicU64::new(seed)),
+        }
+    }
+
+    /// `duration` scaled by a random factor in `1 ± fraction`
+    pub(crate) fn apply(&self, duration: Duration) -> Duration {
+        let offset = self.next_f64().mul_add(2.0, -1.0) * self.fraction;
+        let millis = duration.num_milliseconds() as f64 * (1.0 + offset);
+        Duration::milliseconds(millis as i64)
+    }
+
+    /// uniformly distributed in `0.0..1.0`
+    fn next_f64(&self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    fn next_u64(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed)
+            .wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_band() {
+        let jitter = Jitter::with_seed(0.1, 42);
+        let base = Duration::seconds(300);
+        let expiries: Vec<_> = (0..1000).map(|_| jitter.apply(base)).collect();
+        for expiry in &expiries {
+            assert!(*expiry >= Duration::seconds(270), "{expiry}");
+            assert!(*expiry <= Duration::seconds(330), "{expiry}");
+        }
+        let distinct: std::collections::HashSet<_> = expiries.iter().collect();
+        assert!(distinct.len() > 900);
+    }
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let a = Jitter::with_seed(0.5, 7);
+        let b = Jitter::with_seed(0.5, 7);
+        for _ in 0..10 {
+            let base = Duration::seconds(60);
+            assert_eq!(a.apply(base), b.apply(base));
+        }
+    }
+
+    #[test]
+    fn fraction_is_clamped() {
+        let jitter = Jitter::with_seed(5.0, 1);
+        for _ in 0..100 {
+            let expiry = jitter.apply(Duration::seconds(10));
+            assert!(expiry >= Duration::zero());
+            assert!(expiry <= Duration::seconds(20));
+        }
+        let none = Jitter::with_seed(0.0, 1);
+        assert_eq!(none.apply(Duration::seconds(10)), Duration::seconds(10));
+    }
+}
@@ -1,32 +1,47 @@
+mod jitter;
 mod requester;
 use core::default::Default;
 use std::{
     any::{Any, TypeId},
+    borrow::Cow,
+    fmt,
     sync::{Arc, Weak},
 };
 
 #[cfg(feature = "blocking")]
 pub use blocking::Requester;
+pub use jitter::Jitter;
+#[doc(hidden)]
+pub use requester::Requester as AsyncRequester;
 #[cfg(not(feature = "blocking"))]
 pub use requester::Requester;
 #[cfg(feature = "blocking")]
 mod blocking;
 
-use chrono::Duration;
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
 use dashmap::DashMap;
 use gw2lib_model::Language;
 use hyper::client::{connect::Connect, HttpConnector};
 use hyper_rustls::HttpsConnector;
 use static_init::dynamic;
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 
 use crate::{
-    cache::{CleanupCache, InMemoryCache},
+    cache::{CleanupCache, DynCache, DynamicCache, InMemoryCache},
+    metrics::Metrics,
+    rate_limit::DynRateLimiter,
     BucketRateLimiter, Cache, NoopCache, NoopRateLimiter, RateLimiter,
 };
 
 pub(crate) type Inflight = Arc<DashMap<(TypeId, u64), Box<dyn Any + Send + Sync>>>;
 
+/// the gw2 api client
+///
+/// Cloning is cheap: clones share the cache, the rate limiter, the
+/// deduplication of running requests and the connection pool. Settings like
+/// the language or the api key are copied, so changing them on a clone
+/// doesn't affect the original.
 #[must_use]
 pub struct Client<
     C: Cache + Send + Sync + 'static,
@@ -41,7 +56,55 @@ pub struct Client<
     identifier: Option<String>,
     cache: Arc<C>,
     inflight: Inflight,
-    rate_limiter: R,
+    rate_limiter: Arc<R>,
+    options: Options,
+}
+
+/// settings that don't change the type of the client
+#[derive(Clone, Debug)]
+pub(crate) struct Options {
+    /// maximum number of bytes of a response body kept in errors
+    pub(crate) error_body_limit: usize,
+    /// request the latest schema and error on unknown fields
+    pub(crate) strict_schema: bool,
+    /// send the schema version as `v=` instead of `X-Schema-Version`
+    pub(crate) schema_via_query: bool,
+    /// number of ids per bulk request and items per page
+    pub(crate) bulk_chunk_size: u8,
+    /// number of requests of a single call that run at the same time
+    pub(crate) max_concurrency: usize,
+    /// revalidate expired responses with `If-Modified-Since`
+    pub(crate) revalidate: bool,
+    /// receives request and cache statistics
+    pub(crate) metrics: Option<Arc<dyn Metrics>>,
+    /// how long ids that don't exist are remembered, zero disables it
+    pub(crate) negative_cache: Duration,
+    /// spreads the expiry of cached responses
+    pub(crate) cache_jitter: Option<Jitter>,
+    /// cache duration used instead of the `cache-control` header
+    pub(crate) default_cache_duration: Option<Duration>,
+    /// keep the fields of responses the models don't know
+    #[cfg(feature = "unknown-fields")]
+    pub(crate) capture_unknown_fields: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            error_body_limit: 4096,
+            strict_schema: false,
+            schema_via_query: true,
+            bulk_chunk_size: 200,
+            max_concurrency: 8,
+            revalidate: false,
+            metrics: None,
+            negative_cache: Duration::zero(),
+            cache_jitter: None,
+            default_cache_duration: None,
+            #[cfg(feature = "unknown-fields")]
+            capture_unknown_fields: false,
+        }
+    }
 }
 
 impl Client<NoopCache, NoopRateLimiter, HttpsConnector<HttpConnector>, false> {
@@ -62,7 +125,37 @@ impl Client<NoopCache, NoopRateLimiter, HttpsConnector<HttpConnector>, false> {
             identifier: None,
             cache: Arc::new(NoopCache {}),
             inflight: Default::default(),
-            rate_limiter,
+            rate_limiter: Arc::new(rate_limiter),
+            options: Default::default(),
+        }
+    }
+}
+
+impl Client<NoopCache, BucketRateLimiter, HttpsConnector<HttpConnector>, false> {
+    /// creates a new gw2 api client with the default rate limiter but without
+    /// a cache
+    ///
+    /// Unlike [`Requester::forced`], responses are never written anywhere.
+    /// Useful for short-lived programs where a cache is pure overhead.
+    /// ## Example
+    /// ```
+    /// use gw2lib::Client;
+    ///
+    /// let client = Client::no_cache().api_key("<api key>");
+    /// ```
+    pub fn no_cache() -> Self {
+        let client = create_client();
+        let rate_limiter = BucketRateLimiter::default();
+        Self {
+            host: "https://api.guildwars2.com".to_string(),
+            language: Language::En,
+            client,
+            api_key: None,
+            identifier: None,
+            cache: Arc::new(NoopCache),
+            inflight: Default::default(),
+            rate_limiter: Arc::new(rate_limiter),
+            options: Default::default(),
         }
     }
 }
@@ -81,7 +174,113 @@ impl Default for Client<InMemoryCache, BucketRateLimiter, HttpsConnector<HttpCon
             identifier: None,
             cache,
             inflight: Default::default(),
-            rate_limiter,
+            rate_limiter: Arc::new(rate_limiter),
+            options: Default::default(),
+        }
+    }
+}
+
+/// a client whose cache and rate limiter are picked at runtime
+///
+/// See [`Client::dyn_builder`].
+pub type DynClient<const AUTHENTICATED: bool = false> =
+    Client<DynamicCache, DynRateLimiter, HttpsConnector<HttpConnector>, AUTHENTICATED>;
+
+impl DynClient {
+    /// creates a new gw2 api client with type erased cache and rate limiter
+    ///
+    /// Starts out like [`Client::default`]. Use [`Client::dyn_cache`] and
+    /// [`Client::dyn_rate_limiter`] to replace them, without changing the
+    /// type of the client. This allows selecting them at runtime, e.g. from
+    /// a config file.
+    /// ### Remarks
+    /// Every cache and rate limiter call goes through dynamic dispatch, and
+    /// cache lookups build a textual key. See [`DynamicCache`] for details.
+    /// The overhead is small compared to a request, but prefer the statically
+    /// typed client when the cache is known at compile time.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::{
+    ///     cache::{InMemoryCache, NoopCache},
+    ///     Client,
+    /// };
+    ///
+    /// let use_cache = std::env::var("NO_CACHE").is_err();
+    /// let client = Client::dyn_builder();
+    /// let client = if use_cache {
+    ///     client.dyn_cache(InMemoryCache::default())
+    /// } else {
+    ///     client.dyn_cache(NoopCache)
+    /// };
+    /// ```
+    pub fn dyn_builder() -> Self {
+        let client = create_client();
+        let cache = Arc::new(DynamicCache::new(InMemoryCache::default()));
+        periodically_cleanup_cache(cache.clone());
+        Self {
+            host: "https://api.guildwars2.com".to_string(),
+            language: Language::En,
+            client,
+            api_key: None,
+            identifier: None,
+            cache,
+            inflight: Default::default(),
+            rate_limiter: Arc::new(Box::new(BucketRateLimiter::default())),
+            options: Default::default(),
+        }
+    }
+}
+
+impl<Conn: Connect + Clone + Send + Sync + 'static, const AUTHENTICATED: bool>
+    Client<DynamicCache, DynRateLimiter, Conn, AUTHENTICATED>
+{
+    /// replaces the cache, keeping the type of the client
+    pub fn dyn_cache(self, cache: impl DynCache + 'static) -> Self {
+        self.cache(Arc::new(DynamicCache::new(cache)))
+    }
+
+    /// replaces the rate limiter, keeping the type of the client
+    pub fn dyn_rate_limiter(self, rate_limiter: impl RateLimiter + Send + Sync + 'static) -> Self {
+        self.rate_limiter(Box::new(rate_limiter))
+    }
+}
+
+impl<Conn: Connect + Clone + Send + Sync + 'static>
+    Client<InMemoryCache, BucketRateLimiter, Conn, false>
+{
+    /// creates a new gw2 api client on top of an existing hyper client
+    ///
+    /// This allows full control over the connector, for example to use a
+    /// custom TLS configuration. Any connector works as long as it implements
+    /// [`Connect`] and is `Clone + Send + Sync + 'static`, and the client
+    /// sends [`hyper::Body`]. Caching and rate limiting are set up like
+    /// [`Client::default`].
+    ///
+    /// ### Warning
+    /// [`Client::host`] and [`Client::host_http`] replace the hyper client.
+    /// Set the [`host`](Client::host) field directly instead.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::Client;
+    ///
+    /// let hyper_client = hyper::Client::new();
+    /// let mut client = Client::from_hyper(hyper_client);
+    /// client.host = "http://localhost:8080".to_string();
+    /// ```
+    pub fn from_hyper(client: hyper::Client<Conn, hyper::Body>) -> Self {
+        let rate_limiter = BucketRateLimiter::default();
+        let cache = Arc::new(InMemoryCache::default());
+        periodically_cleanup_cache(cache.clone());
+        Self {
+            host: "https://api.guildwars2.com".to_string(),
+            language: Language::En,
+            client,
+            api_key: None,
+            identifier: None,
+            cache,
+            inflight: Default::default(),
+            rate_limiter: Arc::new(rate_limiter),
+            options: Default::default(),
         }
     }
 }
@@ -112,6 +311,7 @@ impl<
             cache: self.cache,
             inflight: self.inflight,
             rate_limiter: self.rate_limiter,
+            options: self.options,
         }
     }
 
@@ -129,6 +329,7 @@ impl<
             cache: self.cache,
             inflight: self.inflight,
             rate_limiter: self.rate_limiter,
+            options: self.options,
         }
     }
 
@@ -140,6 +341,151 @@ impl<
         }
     }
 
+    /// sets how many bytes of a response body are kept in
+    /// [`EndpointError::Deserialization`](crate::EndpointError::Deserialization)
+    ///
+    /// default is 4096
+    pub fn error_body_limit(mut self, limit: usize) -> Self {
+        self.options.error_body_limit = limit;
+        self
+    }
+
+    /// requests the `latest` schema instead of the version a model was written
+    /// against and fails with
+    /// [`EndpointError::UnknownFields`](crate::EndpointError::UnknownFields)
+    /// when a response has top level fields the model doesn't capture
+    ///
+    /// This surfaces model drift early. Every response is parsed twice, so
+    /// only enable this for testing. Responses of [`Requester::page`] and
+    /// the raw requests are not checked.
+    pub fn strict_schema(mut self, strict: bool) -> Self {
+        self.options.strict_schema = strict;
+        self
+    }
+
+    /// keeps the top level fields of responses that the models don't know in
+    /// their `extra` field
+    ///
+    /// This shows what the api sends that a model is missing, without failing
+    /// the request like [`Client::strict_schema`]. Captured fields count as
+    /// known to [`Client::strict_schema`]. Models parsed by the client leave
+    /// `extra` empty unless this is enabled.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::{model::items::Item, Client, Requester};
+    ///
+    /// # async fn example() -> gw2lib::EndpointResult<()> {
+    /// let client = Client::default().capture_unknown_fields(true);
+    /// let item: Item = client.single(19721_u32).await?;
+    /// for (field, value) in item.extra.iter() {
+    ///     println!("{field}: {value}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unknown-fields")]
+    pub fn capture_unknown_fields(mut self, capture: bool) -> Self {
+        self.options.capture_unknown_fields = capture;
+        self
+    }
+
+    /// sends the schema version as the `v=` query parameter instead of the
+    /// `X-Schema-Version` header
+    ///
+    /// default is `true`, which also works behind proxies that strip custom
+    /// headers
+    pub fn schema_via_query(mut self, via_query: bool) -> Self {
+        self.options.schema_via_query = via_query;
+        self
+    }
+
+    /// sets how many ids are requested at once by [`Requester::many`] and
+    /// how many items are requested per page when paging through all items
+    ///
+    /// `size` is clamped to `1..=200`, the api doesn't allow larger batches.
+    /// default is 200
+    pub fn bulk_chunk_size(mut self, size: usize) -> Self {
+        self.options.bulk_chunk_size = size.clamp(1, 200) as u8;
+        self
+    }
+
+    /// sets how many requests a single call may have in flight at once,
+    /// e.g. the chunks of ids of [`Requester::many`] or the pages requested
+    /// when paging through all items
+    ///
+    /// All requests still go through the rate limiter. default is 8, at
+    /// least 1
+    pub fn max_concurrency(mut self, max: usize) -> Self {
+        self.options.max_concurrency = max.max(1);
+        self
+    }
+
+    /// keeps responses with a `Last-Modified` header for a day after they
+    /// expired and revalidates them with `If-Modified-Since`
+    ///
+    /// A `304 Not Modified` answer renews the cached response without
+    /// transferring it again, which saves bandwidth when polling account
+    /// endpoints. Applies to [`Requester::get`] and [`Requester::single`],
+    /// forced requests always download the response. default is `false`
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.options.revalidate = revalidate;
+        self
+    }
+
+    /// sets the [`Metrics`] implementation that gets notified about requests
+    /// and cache lookups
+    ///
+    /// default is none, which skips the calls entirely
+    pub fn metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.options.metrics = Some(metrics);
+        self
+    }
+
+    /// remembers ids the api reported as not found for `duration`
+    ///
+    /// Until `duration` passed, [`Requester::single`] returns
+    /// [`ApiError::NotFound`](crate::ApiError::NotFound) for such an id
+    /// without another request, and [`Requester::try_single`] returns
+    /// `None`. Finding the id later, e.g. through [`Requester::forced`],
+    /// replaces the marker. default is zero, which disables it
+    pub fn negative_cache(mut self, duration: Duration) -> Self {
+        self.options.negative_cache = duration;
+        self
+    }
+
+    /// spreads the expiry of cached responses by up to `fraction` of their
+    /// cache duration in either direction
+    ///
+    /// Responses requested at the same time otherwise all expire at the same
+    /// time, and the next access of all of them hits the api at once.
+    /// `0.1` lets a response cached for 300s expire after 270s to 330s.
+    /// Expiries set through [`Requester::cached_until`] are exact. `fraction`
+    /// is clamped to `0.0..=1.0`, default is no jitter
+    pub fn cache_jitter(mut self, fraction: f64) -> Self {
+        self.options.cache_jitter = Some(Jitter::new(fraction));
+        self
+    }
+
+    /// like [`Client::cache_jitter`], with a jitter created through
+    /// [`Jitter::with_seed`] for reproducible expiries
+    pub fn cache_jitter_with(mut self, jitter: Jitter) -> Self {
+        self.options.cache_jitter = Some(jitter);
+        self
+    }
+
+    /// caches all responses for `duration`, ignoring the `cache-control`
+    /// header of the api
+    ///
+    /// The cache duration of a response is taken from, in order:
+    /// 1. the request, see [`Requester::cached`] and
+    ///    [`Requester::cached_until`]
+    /// 2. this setting
+    /// 3. the `cache-control` header, or 5 minutes without one
+    pub fn with_default_cache_duration(mut self, duration: Duration) -> Self {
+        self.options.default_cache_duration = Some(duration);
+        self
+    }
+
     /// sets a new api key
     pub fn api_key(self, key: impl Into<String>) -> Client<C, R, Conn, true> {
         let key = key.into();
@@ -152,6 +498,7 @@ impl<
             cache: self.cache,
             inflight: self.inflight,
             rate_limiter: self.rate_limiter,
+            options: self.options,
         }
     }
 
@@ -211,6 +558,7 @@ impl<
             cache,
             inflight: self.inflight,
             rate_limiter: self.rate_limiter,
+            options: self.options,
         }
     }
 
@@ -238,7 +586,8 @@ impl<
             identifier: self.identifier,
             cache: self.cache,
             inflight: self.inflight,
-            rate_limiter,
+            rate_limiter: Arc::new(rate_limiter),
+            options: self.options,
         }
     }
 }
@@ -263,13 +612,56 @@ impl<
     }
 }
 
+/// lets a client shared as `Arc<Client<..>>` make requests without
+/// dereferencing it first
+impl<T, const AUTHENTICATED: bool, const FORCE: bool> requester::Requester<AUTHENTICATED, FORCE>
+    for Arc<T>
+where
+    T: requester::Requester<AUTHENTICATED, FORCE> + Send,
+{
+    type Caching = T::Caching;
+    type Connector = T::Connector;
+    type RateLimiting = T::RateLimiting;
+
+    fn client(&self) -> &Client<Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED> {
+        T::client(self)
+    }
+
+    fn cache_duration(&self) -> Duration {
+        T::cache_duration(self)
+    }
+
+    fn request_language(&self) -> Language {
+        T::request_language(self)
+    }
+
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        T::cancellation(self)
+    }
+
+    fn cache_expiry(&self) -> Option<NaiveDateTime> {
+        T::cache_expiry(self)
+    }
+
+    fn request_schema(&self) -> Option<SchemaVersion> {
+        T::request_schema(self)
+    }
+
+    fn cache_store(&self) -> bool {
+        T::cache_store(self)
+    }
+}
+
 impl<
         C: Cache + Send + Sync + 'static,
-        R: RateLimiter + Clone + Send + Sync + 'static,
+        R: RateLimiter + Send + Sync + 'static,
         Conn: Connect + Clone + Send + Sync + 'static,
         const AUTHENTICATED: bool,
     > Clone for Client<C, R, Conn, AUTHENTICATED>
 {
+    /// returns a client sharing all state with this one
+    ///
+    /// See [`Client`] for what is shared.
     fn clone(&self) -> Self {
         Self {
             host: self.host.clone(),
@@ -280,10 +672,32 @@ impl<
             cache: self.cache.clone(),
             inflight: self.inflight.clone(),
             rate_limiter: self.rate_limiter.clone(),
+            options: self.options.clone(),
         }
     }
 }
 
+/// shows the settings of the client, with the api key and the identifier
+/// redacted
+impl<
+        C: Cache + Send + Sync + 'static,
+        R: RateLimiter + Send + Sync + 'static,
+        Conn: Connect + Clone + Send + Sync + 'static,
+        const AUTHENTICATED: bool,
+    > fmt::Debug for Client<C, R, Conn, AUTHENTICATED>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("host", &self.host)
+            .field("language", &self.language)
+            .field("authenticated", &AUTHENTICATED)
+            .field("api_key", &self.api_key.as_ref().map(|_| "***"))
+            .field("identifier", &self.identifier.as_ref().map(|_| "***"))
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
 #[must_use]
 pub struct CachedRequest<
     'client,
@@ -295,6 +709,97 @@ pub struct CachedRequest<
 > {
     client: &'client Client<C, R, Conn, AUTHENTICATED>,
     cache_duration: Duration,
+    expires_at: Option<NaiveDateTime>,
+    cancellation: Option<CancellationToken>,
+    language: Language,
+    schema: Option<SchemaVersion>,
+    store: bool,
+}
+
+impl<
+        C: Cache + Send + Sync + 'static,
+        R: RateLimiter + Send + Sync + 'static,
+        Conn: Connect + Clone + Send + Sync + 'static,
+        const AUTHENTICATED: bool,
+        const FORCE: bool,
+    > Clone for CachedRequest<'_, C, R, Conn, AUTHENTICATED, FORCE>
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            cache_duration: self.cache_duration,
+            expires_at: self.expires_at,
+            cancellation: self.cancellation.clone(),
+            language: self.language,
+            schema: self.schema,
+            store: self.store,
+        }
+    }
+}
+
+impl<
+        C: Cache + Send + Sync + 'static,
+        R: RateLimiter + Send + Sync + 'static,
+        Conn: Connect + Clone + Send + Sync + 'static,
+        const AUTHENTICATED: bool,
+        const FORCE: bool,
+    > fmt::Debug for CachedRequest<'_, C, R, Conn, AUTHENTICATED, FORCE>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedRequest")
+            .field("client", self.client)
+            .field("forced", &FORCE)
+            .field("cache_duration", &self.cache_duration)
+            .field("expires_at", &self.expires_at)
+            .field("cancellation", &self.cancellation)
+            .field("language", &self.language)
+            .field("schema", &self.schema)
+            .field("store", &self.store)
+            .finish()
+    }
+}
+
+/// the schema version to request, see [`Requester::schema_version`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SchemaVersion {
+    /// a version in the format `2019-05-16T00:00:00.000Z`
+    Pinned(&'static str),
+    /// always the newest schema
+    Latest,
+    /// the schema that was current at this point in time
+    Date(DateTime<Utc>),
+}
+
+/// where a result came from, see [`Requester::single_with_source`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DataSource {
+    /// the cache
+    Cache,
+    /// a request made for this call
+    Network,
+    /// an identical request that was already running
+    Inflight,
+}
+
+/// the paging headers of a response, see [`Requester::page_with_info`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PageInfo {
+    /// the number of items across all pages
+    pub total_items: usize,
+    pub total_pages: usize,
+    pub page_size: usize,
+    /// the number of items on this page
+    pub result_count: usize,
+}
+
+impl SchemaVersion {
+    pub(crate) fn value(&self) -> Cow<'static, str> {
+        match self {
+            Self::Pinned(version) => Cow::Borrowed(version),
+            Self::Latest => Cow::Borrowed("latest"),
+            Self::Date(date) => Cow::Owned(date.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()),
+        }
+    }
 }
 
 impl<
@@ -317,6 +822,26 @@ impl<
     fn cache_duration(&self) -> Duration {
         self.cache_duration
     }
+
+    fn request_language(&self) -> Language {
+        self.language
+    }
+
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    fn cache_expiry(&self) -> Option<NaiveDateTime> {
+        self.expires_at
+    }
+
+    fn request_schema(&self) -> Option<SchemaVersion> {
+        self.schema
+    }
+
+    fn cache_store(&self) -> bool {
+        self.store
+    }
 }
 
 fn create_client() -> hyper::Client<HttpsConnector<HttpConnector>, hyper::Body> {
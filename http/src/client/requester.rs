@@ -1,32 +1,67 @@
+#[cfg(feature = "characters")]
+use std::collections::BTreeMap;
+#[cfg(all(feature = "items", feature = "commerce"))]
+use std::collections::HashMap;
 use std::{
     any::TypeId,
+    borrow::Cow,
+    collections::{BTreeSet, HashSet},
     fmt::Display,
+    future::Future,
     hash::Hash,
+    marker::PhantomData,
     ops::Deref,
     str::FromStr,
     sync::{Arc, Weak},
+    time::Instant,
 };
 
 use async_trait::async_trait;
-use chrono::{Duration, NaiveDateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
 use dashmap::{mapref::entry::Entry, DashMap};
 use either::Either;
-use futures::{stream::FuturesUnordered, StreamExt};
+use futures::{stream::FuturesUnordered, StreamExt, TryStreamExt};
+#[cfg(feature = "characters")]
+use gw2lib_model::authenticated::account::legendary_armory::AccountLegendaryArmory;
+#[cfg(feature = "items")]
+use gw2lib_model::items::{Item, ItemId, Rarity};
+#[cfg(feature = "misc")]
+use gw2lib_model::misc::worlds::{population_changes, PopulationChange, World, WorldId};
+#[cfg(all(feature = "items", feature = "commerce"))]
+use gw2lib_model::tradingpost::Prices;
+#[cfg(feature = "achievements")]
+use gw2lib_model::{
+    authenticated::account::daily_rewards::{
+        AccountDailyCrafting, AccountMapChests, AccountWorldBosses,
+    },
+    daily_rewards::{DailyCrafting, DailyProgress, MapChest, WorldBoss},
+};
 use gw2lib_model::{
     BulkEndpoint, Endpoint, EndpointWithId, ErrorResponse, FixedEndpoint, Language, PagedEndpoint,
 };
-use hyper::{body::Buf, client::connect::Connect, Request, Response, Uri};
+use hyper::{
+    body::Bytes,
+    client::connect::Connect,
+    header::{
+        AsHeaderName, HeaderValue, CACHE_CONTROL, CONTENT_TYPE, IF_MODIFIED_SINCE, LAST_MODIFIED,
+        RETRY_AFTER,
+    },
+    Method, Request, Response, StatusCode, Uri,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use tokio::sync::{
     broadcast::{self, Receiver, Sender},
     Mutex,
 };
+use tokio_util::sync::CancellationToken;
 #[cfg(feature = "tracing")]
 use tracing::{instrument, Instrument};
 
+#[cfg(feature = "blocking")]
+use crate::cache::SyncCache;
 use crate::{
-    cache::in_memory::hash, ApiError, Cache, CachedRequest, Client, EndpointError, EndpointResult,
-    Inflight, RateLimiter,
+    cache::in_memory::hash, reset, ApiError, Cache, CachedRequest, Client, DataSource,
+    EndpointError, EndpointResult, Inflight, PageInfo, RateLimiter, SchemaVersion,
 };
 
 #[async_trait]
@@ -42,6 +77,31 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>: Sized + Sync
     #[doc(hidden)]
     fn cache_duration(&self) -> Duration;
 
+    #[doc(hidden)]
+    fn request_language(&self) -> Language {
+        self.client().language
+    }
+
+    #[doc(hidden)]
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        None
+    }
+
+    #[doc(hidden)]
+    fn cache_expiry(&self) -> Option<NaiveDateTime> {
+        None
+    }
+
+    #[doc(hidden)]
+    fn request_schema(&self) -> Option<SchemaVersion> {
+        None
+    }
+
+    #[doc(hidden)]
+    fn cache_store(&self) -> bool {
+        true
+    }
+
     /// overwrites the cache duration for all requests returned from this
     /// function ## Example
     /// ```
@@ -60,14 +120,72 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>: Sized + Sync
     fn cached(
         &self,
         cache_duration: Duration,
-    ) -> CachedRequest<Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
     {
         CachedRequest {
             client: self.client(),
             cache_duration,
+            expires_at: None,
+            cancellation: self.cancellation().cloned(),
+            language: self.request_language(),
+            schema: self.request_schema(),
+            store: self.cache_store(),
+        }
+    }
+
+    /// like [`Requester::cached`], but takes a [`std::time::Duration`]
+    ///
+    /// Durations too long for [`chrono::Duration`] are capped.
+    fn cached_std(
+        &self,
+        cache_duration: std::time::Duration,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        self.cached(Duration::from_std(cache_duration).unwrap_or(Duration::MAX))
+    }
+
+    /// caches all requests returned from this function until `at`,
+    /// regardless of the cache duration sent by the api
+    fn cached_until(
+        &self,
+        at: DateTime<Utc>,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        CachedRequest {
+            client: self.client(),
+            cache_duration: self.cache_duration(),
+            expires_at: Some(at.naive_utc()),
+            cancellation: self.cancellation().cloned(),
+            language: self.request_language(),
+            schema: self.request_schema(),
+            store: self.cache_store(),
         }
     }
 
+    /// caches all requests returned from this function until the next daily
+    /// reset
+    ///
+    /// Meant for daily endpoints, like the daily completions of an account,
+    /// which change exactly at reset.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::{
+    ///     model::authenticated::account::daily_rewards::AccountWorldBosses, Client, Requester,
+    /// };
+    ///
+    /// # async fn example() -> gw2lib::EndpointResult<()> {
+    /// let client = Client::default().api_key("<subtoken>");
+    /// let bosses: AccountWorldBosses = client.cached_until_reset().get().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn cached_until_reset(
+        &self,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        self.cached_until(reset::next_daily_reset(Utc::now()))
+    }
+
     /// forces a fresh copy from the api
     /// ## Example
     /// ```
@@ -82,11 +200,149 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>: Sized + Sync
     /// let build_id: Build = client.forced().get().unwrap();
     fn forced(
         &self,
-    ) -> CachedRequest<Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, true>
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, true>
+    {
+        CachedRequest {
+            client: self.client(),
+            cache_duration: Duration::zero(),
+            expires_at: None,
+            cancellation: self.cancellation().cloned(),
+            language: self.request_language(),
+            schema: self.request_schema(),
+            store: self.cache_store(),
+        }
+    }
+
+    /// doesn't write responses of the returned requester to the cache
+    ///
+    /// The cache is still read, combine with [`Requester::forced`] or use
+    /// [`Requester::forced_no_store`] to leave the cache out entirely.
+    fn no_store(
+        &self,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        CachedRequest {
+            client: self.client(),
+            cache_duration: self.cache_duration(),
+            expires_at: self.cache_expiry(),
+            cancellation: self.cancellation().cloned(),
+            language: self.request_language(),
+            schema: self.request_schema(),
+            store: false,
+        }
+    }
+
+    /// forces a fresh copy from the api without writing it to the cache
+    ///
+    /// A transparent fetch that leaves the cache as it is, e.g. for a one-off
+    /// debug read. How requesters use the cache:
+    ///
+    /// | requester | reads the cache | writes the cache |
+    /// |---|---|---|
+    /// | `client` | yes | yes |
+    /// | [`client.forced()`](Requester::forced) | no | yes |
+    /// | [`client.no_store()`](Requester::no_store) | yes | no |
+    /// | `client.forced_no_store()` | no | no |
+    fn forced_no_store(
+        &self,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, true>
     {
         CachedRequest {
             client: self.client(),
             cache_duration: Duration::zero(),
+            expires_at: None,
+            cancellation: self.cancellation().cloned(),
+            language: self.request_language(),
+            schema: self.request_schema(),
+            store: false,
+        }
+    }
+
+    /// aborts all requests made through the returned requester once `token`
+    /// is cancelled
+    ///
+    /// Cancelled requests return [`EndpointError::Cancelled`]. This includes
+    /// all outstanding chunks of [`Requester::many`] and the paging requests
+    /// of [`Requester::all`].
+    fn with_cancellation(
+        &self,
+        token: CancellationToken,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        CachedRequest {
+            client: self.client(),
+            cache_duration: self.cache_duration(),
+            expires_at: self.cache_expiry(),
+            cancellation: Some(token),
+            language: self.request_language(),
+            schema: self.request_schema(),
+            store: self.cache_store(),
+        }
+    }
+
+    /// requests localized endpoints in `language` instead of the language of
+    /// the client
+    ///
+    /// Responses are cached per language, so this doesn't mix with cached
+    /// responses of other languages.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::{
+    ///     model::{items::Item, Language},
+    ///     Client, Requester,
+    /// };
+    ///
+    /// # async fn example() -> gw2lib::EndpointResult<()> {
+    /// let client = Client::default();
+    /// let english: Item = client.single(19721_u32).await?;
+    /// let german: Item = client.in_language(Language::De).single(19721_u32).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn in_language(
+        &self,
+        language: Language,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        CachedRequest {
+            client: self.client(),
+            cache_duration: self.cache_duration(),
+            expires_at: self.cache_expiry(),
+            cancellation: self.cancellation().cloned(),
+            language,
+            schema: self.request_schema(),
+            store: self.cache_store(),
+        }
+    }
+
+    /// requests all endpoints with the schema `version` instead of the
+    /// version of their model
+    ///
+    /// [`Client::strict_schema`] takes precedence. Responses are cached
+    /// regardless of the schema version they were requested with.
+    /// ## Example
+    /// ```no_run
+    /// use gw2lib::{model::misc::build::Build, Client, Requester, SchemaVersion};
+    ///
+    /// # async fn example() -> gw2lib::EndpointResult<()> {
+    /// let client = Client::default();
+    /// let build: Build = client.schema_version(SchemaVersion::Latest).get().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn schema_version(
+        &self,
+        version: SchemaVersion,
+    ) -> CachedRequest<'_, Self::Caching, Self::RateLimiting, Self::Connector, AUTHENTICATED, FORCE>
+    {
+        CachedRequest {
+            client: self.client(),
+            cache_duration: self.cache_duration(),
+            expires_at: self.cache_expiry(),
+            cancellation: self.cancellation().cloned(),
+            language: self.request_language(),
+            schema: Some(version),
+            store: self.cache_store(),
         }
     }
 
@@ -100,8 +356,22 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>: Sized + Sync
         get_or_ids::<T, T, Self, AUTHENTICATED, FORCE>(self).await
     }
 
+    /// like [`Requester::get`], but returns a shared reference to the cached
+    /// value instead of cloning it
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn get_arc<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + FixedEndpoint + 'static,
+    >(
+        &self,
+    ) -> EndpointResult<Arc<T>> {
+        if let Some(c) = check_cache_arc::<T, str, T, Self, AUTHENTICATED, FORCE>(self, "").await {
+            return Ok(c);
+        }
+        self.get::<T>().await.map(Arc::new)
+    }
+
     /// request a single item
-    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(id, endpoint = %T::URL)))]
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
     async fn single<
         T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
         I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
@@ -109,12 +379,32 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>: Sized + Sync
         &self,
         id: impl Into<I> + Send,
     ) -> EndpointResult<T> {
+        self.single_with_source::<T, I>(id)
+            .await
+            .map(|(result, _)| result)
+    }
+
+    /// like [`Requester::single`], but also returns where the item came from
+    ///
+    /// Useful to skip work for values that were already seen, e.g.
+    /// re-rendering only for [`DataSource::Network`].
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(id, endpoint = %T::URL)))]
+    async fn single_with_source<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
+    >(
+        &self,
+        id: impl Into<I> + Send,
+    ) -> EndpointResult<(T, DataSource)> {
         let id = id.into();
         #[cfg(feature = "tracing")]
         tracing::Span::current().record("id", id.to_string());
-        let lang = self.client().language;
+        let lang = self.request_language();
         if let Some(c) = self.try_get(&id).await {
-            return Ok(c);
+            return Ok((c, DataSource::Cache));
+        }
+        if !FORCE && is_absent::<I, T, Self, AUTHENTICATED, FORCE>(self, &id).await {
+            return Err(EndpointError::ApiError(ApiError::NotFound));
         }
 
         let tx = loop {
@@ -126,67 +416,178 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>: Sized + Sync
             )
             .await;
             match either {
-                Some(Either::Left(mut rx)) => return rx.recv().await.map_err(Into::into),
+                Some(Either::Left(mut rx)) => {
+                    let result =
+                        cancellable(self, async { rx.recv().await.map_err(Into::into) }).await?;
+                    return Ok((result, DataSource::Inflight));
+                }
                 Some(Either::Right(tx)) => break tx,
                 None => {
                     if let Some(c) = self.try_get(&id).await {
-                        return Ok(c);
+                        return Ok((c, DataSource::Cache));
                     }
                 }
             }
         };
 
-        let request = build_request::<T, String, Self, AUTHENTICATED, FORCE>(
+        let request = build_request::<T, Self, AUTHENTICATED, FORCE>(
             self,
             T::format_url(T::format_id(&id).as_ref()),
-            None,
+            &[],
         )?;
 
-        let response = exec_req::<Self, AUTHENTICATED, FORCE>(self, request).await?;
-        let result =
-            cache_response::<I, T, T, Self, AUTHENTICATED, FORCE>(self, &id, response).await?;
+        let result = match fetch_and_cache::<I, T, T, Self, AUTHENTICATED, FORCE>(
+            self, &id, request,
+        )
+        .await
+        {
+            Err(EndpointError::ApiError(ApiError::NotFound)) => {
+                cache_absent::<I, T, Self, AUTHENTICATED, FORCE>(self, &id).await;
+                return Err(EndpointError::ApiError(ApiError::NotFound));
+            }
+            result => result?,
+        };
+        clear_absent::<I, T, Self, AUTHENTICATED, FORCE>(self, &id).await;
         // ignoring the error is fine here
         // the receiving side will check the cache if nothing got sent
         let _ = tx.lock().await.send(result.clone());
 
-        Ok(result)
+        Ok((result, DataSource::Network))
     }
 
-    /// retrieves an item from cache
-    /// ```
-    /// use gw2lib::{model::items::Item, Client, Requester};
+    /// like [`Requester::single`], but returns `T::default()` if the id
+    /// doesn't exist
     ///
-    /// let client = Client::default();
-    /// let from_cache: Option<Item> = client.try_get(&19721);
-    /// ```
-    #[cfg_attr(feature = "tracing", instrument(name = "get cached", skip_all, fields(id, endpoint = %T::URL)))]
-    async fn try_get<
-        T: DeserializeOwned + Serialize + Clone + Endpoint + Send + Sync + 'static,
-        I: DeserializeOwned + Display + Hash + Clone + Sync + 'static,
+    /// Only a not found response from the api is substituted, network and
+    /// all other errors are still returned.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn single_or_default<
+        T: DeserializeOwned
+            + Serialize
+            + Clone
+            + Default
+            + Send
+            + Sync
+            + EndpointWithId<IdType = I>
+            + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
     >(
         &self,
-        id: impl Into<&I> + Send,
-    ) -> Option<T> {
+        id: impl Into<I> + Send,
+    ) -> EndpointResult<T> {
+        match self.single::<T, I>(id).await {
+            Err(EndpointError::ApiError(ApiError::NotFound)) => Ok(T::default()),
+            result => result,
+        }
+    }
+
+    /// like [`Requester::single`], but returns `None` if the id doesn't exist
+    ///
+    /// With [`Client::negative_cache`], ids that weren't found are
+    /// remembered and not requested again until it expires. Only a not
+    /// found response from the api results in `None`, network and all other
+    /// errors are still returned.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn try_single<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
+    >(
+        &self,
+        id: impl Into<I> + Send,
+    ) -> EndpointResult<Option<T>> {
+        match self.single::<T, I>(id).await {
+            Ok(result) => Ok(Some(result)),
+            Err(EndpointError::ApiError(ApiError::NotFound)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// like [`Requester::single`], but returns a shared reference to the
+    /// cached value instead of cloning it
+    ///
+    /// Cache hits are handed out without copying, which matters for large
+    /// values like order books.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(id, endpoint = %T::URL)))]
+    async fn single_arc<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
+    >(
+        &self,
+        id: impl Into<I> + Send,
+    ) -> EndpointResult<Arc<T>> {
         let id = id.into();
         #[cfg(feature = "tracing")]
         tracing::Span::current().record("id", id.to_string());
-        check_cache::<T, I, T, Self, AUTHENTICATED, FORCE>(self, id).await
+        if let Some(c) = check_cache_arc::<T, I, T, Self, AUTHENTICATED, FORCE>(self, &id).await {
+            return Ok(c);
+        }
+        self.single::<T, I>(id).await.map(Arc::new)
     }
 
-    /// request all available ids
-    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
-    async fn ids<
-        T: DeserializeOwned + Serialize + EndpointWithId<IdType = I> + Clone + Send + Sync + 'static,
-        I: Display + DeserializeOwned + Serialize + Hash + Clone + Send + Sync + 'static,
+    /// returns a cached item together with the time it expires, without
+    /// making a request
+    ///
+    /// Returns `None` if the item isn't cached or the cache doesn't track
+    /// expiry. Ignores [`Requester::forced`].
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(id, endpoint = %T::URL)))]
+    async fn cache_entry<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
     >(
         &self,
-    ) -> EndpointResult<Vec<I>> {
-        get_or_ids::<T, Vec<I>, Self, AUTHENTICATED, FORCE>(self).await
+        id: impl Into<I> + Send,
+    ) -> Option<(T, NaiveDateTime)> {
+        let id = id.into();
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("id", id.to_string());
+        let client = self.client();
+        client
+            .cache
+            .get_with_expiry::<T, I, T, String>(&id, self.request_language(), &client.identifier)
+            .await
     }
 
-    /// request multiple ids at once
+    /// checks whether an item exists without deserializing it
+    ///
+    /// Cached items count as existing. Otherwise only the status code of the
+    /// response is inspected.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(id, endpoint = %T::URL)))]
+    async fn exists<
+        T: DeserializeOwned + Serialize + Clone + Send + Sync + EndpointWithId<IdType = I> + 'static,
+        I: Display + DeserializeOwned + Hash + Send + Sync + Clone + 'static,
+    >(
+        &self,
+        id: impl Into<I> + Send,
+    ) -> EndpointResult<bool> {
+        let id = id.into();
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("id", id.to_string());
+        if self.try_get::<T, I>(&id).await.is_some() {
+            return Ok(true);
+        }
+
+        let request = build_request::<T, Self, AUTHENTICATED, FORCE>(
+            self,
+            T::format_url(T::format_id(&id).as_ref()),
+            &[],
+        )?;
+        cancellable(self, async {
+            let response = exec_req::<Self, AUTHENTICATED, FORCE>(self, request).await?;
+            match response.status() {
+                status if status.is_success() => Ok(true),
+                StatusCode::NOT_FOUND => Ok(false),
+                _ => Err(api_error(self, response).await),
+            }
+        })
+        .await
+    }
+
+    /// checks which of the given ids exist
+    ///
+    /// The result has the same order as `ids`. Ids that aren't cached are
+    /// requested in chunks, but only their ids are deserialized.
     #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
-    async fn many<
+    async fn exists_many<
         T: DeserializeOwned
             + Serialize
             + EndpointWithId<IdType = I>
@@ -199,133 +600,223 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>: Sized + Sync
     >(
         &self,
         ids: Vec<impl Into<I> + Send>,
-    ) -> EndpointResult<Vec<T>> {
-        let mut result = Vec::with_capacity(ids.len());
-        let ids = if !FORCE {
-            let ids = extract_many_from_cache(self, ids, &mut result).await;
-            if ids.is_empty() {
-                return Ok(result);
+    ) -> EndpointResult<Vec<bool>> {
+        let ids: Vec<I> = ids.into_iter().map(Into::into).collect();
+        let mut found = HashSet::with_capacity(ids.len());
+        let mut remaining = Vec::with_capacity(ids.len());
+        for id in &ids {
+            if self.try_get::<T, I>(id).await.is_some() {
+                found.insert(id.clone());
+            } else {
+                remaining.push(id.clone());
             }
-            ids
-        } else {
-            ids.into_iter().map(|id| id.into()).collect()
-        };
+        }
 
-        let txs = DashMap::with_capacity(ids.len());
-        let mut rxs = Vec::with_capacity(ids.len());
-        let mut remaining_ids = Vec::with_capacity(ids.len());
-        for id in ids {
-            let retain = loop {
-                let either = check_inflight::<T, I, T, String>(
-                    &self.client().inflight,
-                    &id,
-                    self.client().language,
-                    &self.client().identifier,
-                )
-                .await;
-                match either {
-                    Some(Either::Left(rx)) => {
-                        rxs.push(rx);
-                        break false;
-                    }
-                    Some(Either::Right(tx)) => {
-                        txs.insert(id.clone(), tx);
-                        break true;
-                    }
-                    None => {
-                        if let Some(c) =
-                            check_cache::<T, I, T, Self, AUTHENTICATED, FORCE>(self, &id).await
-                        {
-                            result.push(c);
-                            break false;
+        let futs: FuturesUnordered<_> =
+            join_ids(&remaining, self.client().options.bulk_chunk_size.into())
+                .into_iter()
+                .map(|chunk| {
+                    cancellable(self, async move {
+                        let request = build_request::<T, Self, AUTHENTICATED, FORCE>(
+                            self,
+                            T::URL,
+                            &[("ids", &chunk)],
+                        )?;
+                        let response =
+                            exec_req::<Self, AUTHENTICATED, FORCE>(self, request).await?;
+                        match response.status() {
+                            // partial content if some ids don't exist
+                            status if status.is_success() => {
+                                let entries: Vec<IdOnly<I>> =
+                                    parse_raw_response(self, response).await?;
+                                Ok(entries.into_iter().map(|e| e.id).collect())
+                            }
+                            // none of the ids exist
+                            StatusCode::NOT_FOUND => Ok(Vec::new()),
+                            _ => Err(api_error(self, response).await),
                         }
-                    }
-                }
-            };
-            if retain {
-                remaining_ids.push(id);
-            }
-        }
+                    })
+                })
+                .collect();
+        let existing: Vec<Vec<I>> = futs.try_collect().await?;
+        found.extend(existing.into_iter().flatten());
 
-        let result = Mutex::new(result);
-        let chunks = join_ids(&remaining_ids);
-        let futs: FuturesUnordered<_> = chunks
-            .into_iter()
-            .map(|rest| {
-                let rest = Some(format!("ids={rest}"));
-                async {
-                    let request =
-                        build_request::<T, _, Self, AUTHENTICATED, FORCE>(self, T::URL, rest)?;
+        Ok(ids.iter().map(|id| found.contains(id)).collect())
+    }
 
-                    let response = exec_req::<Self, AUTHENTICATED, FORCE>(self, request).await?;
-                    let mut result = result.lock().await;
-                    let index = result.len();
-                    // TODO: consider postponing the locking
-                    cache_response_many(self, response, &mut result).await?;
-
-                    for x in result.iter().skip(index) {
-                        let (_, tx) = txs
-                            .remove(x.id())
-                            .expect("received unexpected entry from api");
-                        // ignoring the error is fine here
-                        // the receiving side will check the cache if nothing got sent
-                        let _ = tx.lock().await.send(x.clone());
-                    }
-                    Result::<(), EndpointError>::Ok(())
-                }
-            })
-            .collect();
-        let mut futs = futs.into_future();
-        let mut error = None;
-        while let (Some(res), fut) = futs.await {
-            futs = fut.into_future();
-            if let Err(e) = res {
-                error = Some(e);
-            }
-        }
-        if let Some(e) = error {
-            return Err(e);
+    /// retrieves an item from cache
+    /// ```
+    /// use gw2lib::{model::items::Item, Client, Requester};
+    ///
+    /// let client = Client::default();
+    /// let from_cache: Option<Item> = client.try_get(&19721);
+    /// ```
+    #[cfg_attr(feature = "tracing", instrument(name = "get cached", skip_all, fields(id, endpoint = %T::URL)))]
+    async fn try_get<
+        T: DeserializeOwned + Serialize + Clone + Endpoint + Send + Sync + 'static,
+        I: DeserializeOwned + Display + Hash + Clone + Sync + 'static,
+    >(
+        &self,
+        id: impl Into<&I> + Send,
+    ) -> Option<T> {
+        let id = id.into();
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("id", id.to_string());
+        if is_absent::<I, T, Self, AUTHENTICATED, FORCE>(self, id).await {
+            return None;
         }
+        check_cache::<T, I, T, Self, AUTHENTICATED, FORCE>(self, id).await
+    }
 
-        let mut result = result.into_inner();
-        for mut rx in rxs {
-            // TODO: check cache again
-            result.push(rx.recv().await?);
-        }
+    /// removes an item from the cache, so the next request for it is sent to
+    /// the api
+    ///
+    /// Only the entry in the language of this requester is removed. Unlike
+    /// [`Requester::invalidate_type`], all other items stay cached. Also
+    /// forgets that the id wasn't found, see [`Client::negative_cache`].
+    /// ```
+    /// use gw2lib::{model::items::Item, Client, Requester};
+    ///
+    /// # async fn example() {
+    /// let client = Client::default();
+    /// client.invalidate::<Item, u32>(&19721).await;
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(id, endpoint = %T::URL)))]
+    async fn invalidate<
+        T: DeserializeOwned + Serialize + Clone + Endpoint + Send + Sync + 'static,
+        I: DeserializeOwned + Display + Hash + Clone + Sync + 'static,
+    >(
+        &self,
+        id: impl Into<&I> + Send,
+    ) {
+        let id = id.into();
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("id", id.to_string());
+        let client = self.client();
+        client
+            .cache
+            .remove::<T, I, T, String>(id, self.request_language(), &client.identifier)
+            .await;
+        clear_absent::<I, T, Self, AUTHENTICATED, FORCE>(self, id).await;
+    }
 
-        Ok(result)
+    /// requests an endpoint that isn't modeled yet and returns the raw json
+    ///
+    /// `path` is in the format `v2/account`. The request respects the rate
+    /// limiter, but is neither cached nor deduplicated. The language and, if
+    /// set, the api key are always sent. The schema version defaults to
+    /// `latest`, use [`Requester::raw_get_versioned`] to pin one.
+    async fn raw_get(
+        &self,
+        path: &str,
+        queries: &[(&str, &str)],
+    ) -> EndpointResult<serde_json::Value> {
+        self.raw_get_versioned(path, queries, "latest").await
     }
 
-    /// requests a page of items and returns the number of total items across
-    /// all pages
-    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
-    async fn page<T: DeserializeOwned + PagedEndpoint + Clone + Send + Sync + 'static>(
+    /// like [`Requester::raw_get`], but requests a specific schema version
+    async fn raw_get_versioned(
         &self,
-        page: usize,
-        page_size: u8,
-        result: &mut Vec<T>,
-    ) -> EndpointResult<usize> {
-        let queries = format!("page={page}&page_size={page_size}");
-        let request =
-            build_request::<T, _, Self, AUTHENTICATED, FORCE>(self, T::URL, Some(queries))?;
+        path: &str,
+        queries: &[(&str, &str)],
+        version: &str,
+    ) -> EndpointResult<serde_json::Value> {
+        self.raw_typed_versioned(path, queries, version).await
+    }
+
+    /// like [`Requester::raw_get`], but deserializes into a caller supplied
+    /// type
+    ///
+    /// `D` doesn't have to be an [`Endpoint`], so the response is neither
+    /// cached nor deduplicated with concurrent requests.
+    async fn raw_typed<D: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        queries: &[(&str, &str)],
+    ) -> EndpointResult<D> {
+        self.raw_typed_versioned(path, queries, "latest").await
+    }
 
-        let response = exec_req::<Self, AUTHENTICATED, FORCE>(self, request).await?;
-        let count = get_header(&response, "x-result-total").unwrap_or(0);
-        let (_expires, res): (_, Vec<T>) = parse_response(self, response).await?;
-        result.extend_from_slice(&res);
+    /// like [`Requester::raw_typed`], but requests a specific schema version
+    async fn raw_typed_versioned<D: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        queries: &[(&str, &str)],
+        version: &str,
+    ) -> EndpointResult<D> {
+        raw_request::<D, Self, AUTHENTICATED, FORCE>(
+            self,
+            Method::GET,
+            path,
+            queries,
+            version,
+            None,
+        )
+        .await
+    }
 
-        Ok(count)
+    /// sends `body` as json to an endpoint that isn't modeled yet and returns
+    /// the raw json response
+    ///
+    /// Behaves like [`Requester::raw_get`] otherwise.
+    async fn raw_post(
+        &self,
+        path: &str,
+        queries: &[(&str, &str)],
+        body: &serde_json::Value,
+    ) -> EndpointResult<serde_json::Value> {
+        let body = serde_json::to_vec(body)?;
+        raw_request::<_, Self, AUTHENTICATED, FORCE>(
+            self,
+            Method::POST,
+            path,
+            queries,
+            "latest",
+            Some(body),
+        )
+        .await
     }
 
-    /// requests all items using the most efficient method available
-    /// ### Remarks
-    /// for most endpoints this means using [`Self::get_all_by_requesting_ids`].
-    /// Compared to [`Self::get_all_by_paging`]
-    /// this needs to perform an additional request to get all ids, but is much
-    /// more cache friendly, being able to utilize the cache and inflight
-    /// mechanisms.
+    /// request all available ids
+    ///
+    /// The order is defined by the api and differs between endpoints. Use
+    /// [`Requester::ids_sorted`] for a stable order.
+    ///
+    /// Requests the endpoint without `ids`, which only returns the ids. Works
+    /// for every endpoint with ids, regardless of [`BulkEndpoint::ALL`].
     #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
-    async fn all<
+    async fn ids<
+        T: DeserializeOwned + Serialize + EndpointWithId<IdType = I> + Clone + Send + Sync + 'static,
+        I: Display + DeserializeOwned + Serialize + Hash + Clone + Send + Sync + 'static,
+    >(
+        &self,
+    ) -> EndpointResult<Vec<I>> {
+        get_or_ids::<T, Vec<I>, Self, AUTHENTICATED, FORCE>(self).await
+    }
+
+    /// request all available ids, sorted ascending
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn ids_sorted<
+        T: DeserializeOwned + Serialize + EndpointWithId<IdType = I> + Clone + Send + Sync + 'static,
+        I: Display + DeserializeOwned + Serialize + Hash + Clone + Ord + Send + Sync + 'static,
+    >(
+        &self,
+    ) -> EndpointResult<Vec<I>> {
+        let mut ids = self.ids::<T, I>().await?;
+        ids.sort_unstable();
+        Ok(ids)
+    }
+
+    /// request all items with an id greater than `after`, in ascending order
+    /// of their ids
+    ///
+    /// Resumes an interrupted sync: persist the id of the last processed
+    /// item and pass it as `after` on restart. This relies on ids keeping
+    /// their order between runs, which holds for the numeric ids of the
+    /// api. See [`Requester::ids_sorted`].
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn all_from<
         T: DeserializeOwned
             + Serialize
             + EndpointWithId<IdType = I>
@@ -334,25 +825,25 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>: Sized + Sync
             + Send
             + Sync
             + 'static,
-        I: Display + DeserializeOwned + Serialize + Hash + Clone + Send + Sync + Eq + 'static,
+        I: Display + DeserializeOwned + Serialize + Hash + Clone + Ord + Send + Sync + 'static,
     >(
         &self,
+        after: I,
     ) -> EndpointResult<Vec<T>> {
-        if T::ALL {
-            self.get_all_by_ids_all().await
-        // paging cannot utilize the cache, so we won't use it by default
-        // } else if T::PAGING {
-        //     self.get_all_by_paging()
-        } else {
-            self.get_all_by_requesting_ids().await
-        }
+        let mut ids = self.ids_sorted::<T, I>().await?;
+        ids.retain(|id| *id > after);
+        let mut result = self.many::<T, I>(ids).await?;
+        result.sort_unstable_by(|a, b| a.id().cmp(b.id()));
+        Ok(result)
     }
 
-    /// Gets all items by querying ids=all
+    /// requests all items and returns those matching `pred`
     ///
-    /// use [`Self::all`] to use the most efficient way to request all items
-    #[cfg_attr(feature = "tracing", instrument(name = "get all by ids all", skip_all, fields(endpoint = %T::URL)))]
-    async fn get_all_by_ids_all<
+    /// For endpoints that don't support `ids=all`, the items are requested
+    /// in batches and filtered batch by batch, so the full set is never
+    /// held at once. Items not matching `pred` are still cached.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn all_filtered<
         T: DeserializeOwned
             + Serialize
             + EndpointWithId<IdType = I>
@@ -361,58 +852,146 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>: Sized + Sync
             + Send
             + Sync
             + 'static,
-        I: Display + DeserializeOwned + Hash + Clone + Sync + 'static,
+        I: Display + DeserializeOwned + Serialize + Hash + Clone + Send + Sync + Eq + 'static,
     >(
         &self,
+        pred: impl for<'a> Fn(&'a T) -> bool + Send + Sync,
     ) -> EndpointResult<Vec<T>> {
-        if !T::ALL {
-            return Err(EndpointError::UnsupportedEndpointQuery);
+        if T::ALL {
+            let mut result = self.get_all_by_ids_all::<T, I>().await?;
+            result.retain(|x| pred(x));
+            return Ok(result);
         }
 
-        if let Some(c) =
-            check_cache::<Vec<T>, str, T, Self, AUTHENTICATED, FORCE>(self, "ids=all").await
-        {
-            return Ok(c);
+        let options = &self.client().options;
+        let batch = usize::from(options.bulk_chunk_size) * options.max_concurrency;
+        let ids = self.ids::<T, I>().await?;
+        let mut result = Vec::new();
+        for ids in ids.chunks(batch) {
+            let mut items = self.many::<T, I>(ids.to_vec()).await?;
+            items.retain(|x| pred(x));
+            result.append(&mut items);
         }
+        Ok(result)
+    }
 
-        let request =
-            build_request::<T, _, Self, AUTHENTICATED, FORCE>(self, T::URL, Some("ids=all"))?;
-
-        let response = exec_req::<Self, AUTHENTICATED, FORCE>(self, request).await?;
-        let count = get_header(&response, "x-result-total").unwrap_or(0);
-        let mut result = Vec::with_capacity(count);
-        cache_response_all(self, response, &mut result).await?;
+    /// requests all items of the given rarity
+    ///
+    /// See [`Requester::all_filtered`].
+    #[cfg(feature = "items")]
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    async fn items_by_rarity(&self, rarity: Rarity) -> EndpointResult<Vec<Item>> {
+        self.all_filtered::<Item, ItemId>(|item| item.rarity == rarity)
+            .await
+    }
 
-        Ok(result)
+    /// requests an item and its trading post prices at once
+    ///
+    /// The prices are `None` for items that can't be traded.
+    #[cfg(all(feature = "items", feature = "commerce"))]
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(id)))]
+    async fn item_with_price(&self, id: ItemId) -> EndpointResult<(Item, Option<Prices>)> {
+        futures::try_join!(
+            self.single::<Item, ItemId>(id),
+            self.try_single::<Prices, ItemId>(id),
+        )
     }
 
-    /// Gets all items by querying all pages
+    /// like [`Requester::item_with_price`] for multiple items
     ///
-    /// use [`Self::all`] to use the most efficient way to request all items
-    #[cfg_attr(feature = "tracing", instrument(name = "get all by paging", skip_all, fields(endpoint = %T::URL)))]
-    async fn get_all_by_paging<
-        T: DeserializeOwned + PagedEndpoint + Clone + Send + Sync + 'static,
-    >(
+    /// The items are returned in the order of `ids`, ids that don't exist
+    /// are skipped.
+    #[cfg(all(feature = "items", feature = "commerce"))]
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    async fn items_with_prices(
         &self,
-    ) -> EndpointResult<Vec<T>> {
-        let mut result = Vec::with_capacity(200);
-        let max_items = self.page(0, 200, &mut result).await?;
-        let remaining = max_items.saturating_sub(200);
-        result.reserve_exact(remaining);
+        ids: Vec<ItemId>,
+    ) -> EndpointResult<Vec<(Item, Option<Prices>)>> {
+        let (items, prices) = futures::join!(
+            self.many::<Item, ItemId>(ids.clone()),
+            self.many::<Prices, ItemId>(ids.clone()),
+        );
+        // the api answers with not found if none of the items can be traded
+        let prices = match prices {
+            Err(EndpointError::ApiError(ApiError::NotFound)) => Vec::new(),
+            prices => prices?,
+        };
+        let mut items: HashMap<ItemId, Item> =
+            items?.into_iter().map(|item| (item.id, item)).collect();
+        let mut prices: HashMap<ItemId, Prices> =
+            prices.into_iter().map(|price| (price.id, price)).collect();
+        Ok(ids
+            .iter()
+            .filter_map(|id| Some((items.remove(id)?, prices.remove(id))))
+            .collect())
+    }
 
-        let pages = ((remaining as f64) / 200_f64).ceil() as usize;
-        for page in 0..pages {
-            self.page(page + 1, 200, &mut result).await?;
+    /// the item and all items linked to it through `upgrades_into` and
+    /// `upgrades_from`, like the attuned and infused versions of a ring
+    ///
+    /// Starts with the item itself, followed by the linked items in the
+    /// order they were found. Every item is requested once through
+    /// [`Requester::many`] and cached like any other item.
+    #[cfg(feature = "items")]
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(id)))]
+    async fn upgrade_chain(&self, id: ItemId) -> EndpointResult<Vec<Item>> {
+        let mut seen = BTreeSet::from([id]);
+        let mut next = vec![id];
+        let mut chain = Vec::new();
+        while !next.is_empty() {
+            let mut items = self.many::<Item, ItemId>(next).await?;
+            items.sort_by_key(|item| item.id);
+            next = items
+                .iter()
+                .flat_map(|item| item.upgrades_into.iter().chain(&item.upgrades_from))
+                .flatten()
+                .map(|upgrade| upgrade.item_id)
+                .filter(|id| seen.insert(*id))
+                .collect();
+            chain.extend(items);
         }
+        Ok(chain)
+    }
 
-        Ok(result)
+    /// the legendaries unlocked in the armory of the account, paired with
+    /// the unlocked count and sorted by item id
+    ///
+    /// The items are requested through [`Requester::many`] and cached like
+    /// any other item. Requires the `inventories` and `unlocks` scopes.
+    #[cfg(feature = "characters")]
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    async fn resolved_legendary_armory(&self) -> EndpointResult<Vec<(Item, u32)>> {
+        let armory = self.get::<AccountLegendaryArmory>().await?;
+        let counts: BTreeMap<ItemId, u32> = armory
+            .iter()
+            .map(|unlock| (unlock.id, unlock.count.into()))
+            .collect();
+        let mut items = self
+            .many::<Item, ItemId>(counts.keys().copied().collect())
+            .await?;
+        items.sort_by_key(|item| item.id);
+        Ok(items
+            .into_iter()
+            .map(|item| {
+                let count = counts[&item.id];
+                (item, count)
+            })
+            .collect())
     }
 
-    /// Gets all items by querying all ids
+    /// re-fetches all cached entries of `T` that expire within `within`
     ///
-    /// use [`Self::all`] to use the most efficient way to request all items
-    #[cfg_attr(feature = "tracing", instrument(name = "get all by requesting ids", skip_all, fields(endpoint = %T::URL)))]
-    async fn get_all_by_requesting_ids<
+    /// Keeps a long running cache warm: call this periodically and users
+    /// never wait on an expired entry. Returns the number of refreshed
+    /// entries.
+    ///
+    /// ### Remarks
+    /// This scans the whole cache through [`Cache::expiring`], which caches
+    /// that can't enumerate their entries don't support. The entries are
+    /// requested with the language and identifier of this client, even if
+    /// they were cached by a client with a different one.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn refresh_expiring<
         T: DeserializeOwned
             + Serialize
             + EndpointWithId<IdType = I>
@@ -421,35 +1000,441 @@ pub trait Requester<const AUTHENTICATED: bool, const FORCE: bool>: Sized + Sync
             + Send
             + Sync
             + 'static,
-        I: Display + DeserializeOwned + Serialize + Hash + Clone + Send + Sync + Eq + 'static,
+        I: Display + DeserializeOwned + Hash + Clone + Eq + Send + Sync + 'static,
     >(
         &self,
-    ) -> EndpointResult<Vec<T>> {
-        let ids = self.ids::<T, I>().await?;
-        self.many(ids).await
+        within: Duration,
+    ) -> EndpointResult<usize> {
+        let before = (Utc::now() + within).naive_utc();
+        let expiring = self.client().cache.expiring::<T, T>(before).await;
+        let mut seen = HashSet::with_capacity(expiring.len());
+        let ids: Vec<I> = expiring
+            .iter()
+            .map(|t| t.id().clone())
+            .filter(|id| seen.insert(id.clone()))
+            .collect();
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        self.forced().many::<T, I>(ids).await.map(|x| x.len())
     }
-}
-
-struct SenderGuard<'client, T: Send> {
-    sender: Arc<Mutex<Sender<T>>>,
-    inflight: &'client Inflight,
-    hash: (TypeId, u64),
-}
-
-impl<T: Send> Deref for SenderGuard<'_, T> {
-    type Target = Mutex<Sender<T>>;
 
-    fn deref(&self) -> &Self::Target {
-        &self.sender
+    /// drops all cached entries of `T`, e.g. all skills after a balance patch
+    ///
+    /// This includes the lists cached by [`Requester::all`]. Cached id lists
+    /// are kept, as they only depend on the id type.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn invalidate_type<T: Endpoint + Send + Sync + 'static>(&self) {
+        let cache = &self.client().cache;
+        cache.clear_type::<T>().await;
+        cache.clear_type::<Vec<T>>().await;
     }
-}
 
-impl<T: Send> Drop for SenderGuard<'_, T> {
-    fn drop(&mut self) {
-        let inflight = self.inflight.clone();
-        let hash = self.hash;
+    /// request multiple ids at once
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn many<
+        T: DeserializeOwned
+            + Serialize
+            + EndpointWithId<IdType = I>
+            + BulkEndpoint
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        I: Display + DeserializeOwned + Hash + Clone + Eq + Send + Sync + 'static,
+    >(
+        &self,
+        ids: Vec<impl Into<I> + Send>,
+    ) -> EndpointResult<Vec<T>> {
+        let mut result = Vec::with_capacity(ids.len());
+        let ids = if !FORCE {
+            let ids = extract_many_from_cache(self, ids, &mut result).await;
+            if ids.is_empty() {
+                return Ok(result);
+            }
+            ids
+        } else {
+            ids.into_iter().map(|id| id.into()).collect()
+        };
 
-        let task = async move { inflight.remove(&hash) };
+        let txs = DashMap::with_capacity(ids.len());
+        let mut rxs = Vec::with_capacity(ids.len());
+        let mut remaining_ids = Vec::with_capacity(ids.len());
+        for id in ids {
+            let retain = loop {
+                let either = check_inflight::<T, I, T, String>(
+                    &self.client().inflight,
+                    &id,
+                    self.request_language(),
+                    &self.client().identifier,
+                )
+                .await;
+                match either {
+                    Some(Either::Left(rx)) => {
+                        rxs.push(rx);
+                        break false;
+                    }
+                    Some(Either::Right(tx)) => {
+                        txs.insert(id.clone(), tx);
+                        break true;
+                    }
+                    None => {
+                        if let Some(c) =
+                            check_cache::<T, I, T, Self, AUTHENTICATED, FORCE>(self, &id).await
+                        {
+                            result.push(c);
+                            break false;
+                        }
+                    }
+                }
+            };
+            if retain {
+                remaining_ids.push(id);
+            }
+        }
+
+        let result = Mutex::new(result);
+        let chunks = join_ids(&remaining_ids, self.client().options.bulk_chunk_size.into());
+        let mut futs = futures::stream::iter(chunks)
+            .map(|rest| {
+                let (result, txs) = (&result, &txs);
+                cancellable(self, async move {
+                    let request = build_request::<T, Self, AUTHENTICATED, FORCE>(
+                        self,
+                        T::URL,
+                        &[("ids", &rest)],
+                    )?;
+
+                    let response = exec_req::<Self, AUTHENTICATED, FORCE>(self, request).await?;
+                    let mut result = result.lock().await;
+                    let index = result.len();
+                    // TODO: consider postponing the locking
+                    cache_response_many(self, response, &mut result).await?;
+
+                    for x in result.iter().skip(index) {
+                        let (_, tx) = txs
+                            .remove(x.id())
+                            .expect("received unexpected entry from api");
+                        // ignoring the error is fine here
+                        // the receiving side will check the cache if nothing got sent
+                        let _ = tx.lock().await.send(x.clone());
+                    }
+                    Ok(())
+                })
+            })
+            .buffer_unordered(self.client().options.max_concurrency);
+        let mut error = None;
+        while let Some(res) = futs.next().await {
+            if let Err(e) = res {
+                error = Some(e);
+            }
+        }
+        drop(futs);
+        if let Some(e) = error {
+            return Err(e);
+        }
+
+        let mut result = result.into_inner();
+        cancellable(self, async {
+            for mut rx in rxs {
+                // TODO: check cache again
+                result.push(rx.recv().await?);
+            }
+            Ok(())
+        })
+        .await?;
+
+        Ok(result)
+    }
+
+    /// requests a page of items and returns the number of total items across
+    /// all pages
+    ///
+    /// See [`Requester::page_with_info`] for the other paging headers.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn page<T: DeserializeOwned + PagedEndpoint + Clone + Send + Sync + 'static>(
+        &self,
+        page: usize,
+        page_size: u8,
+        result: &mut Vec<T>,
+    ) -> EndpointResult<usize> {
+        self.page_with_info(page, page_size, result)
+            .await
+            .map(|info| info.total_items)
+    }
+
+    /// like [`Requester::page`], but returns all paging headers of the
+    /// response
+    ///
+    /// Missing headers are derived from the request and the response body.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn page_with_info<T: DeserializeOwned + PagedEndpoint + Clone + Send + Sync + 'static>(
+        &self,
+        page: usize,
+        page_size: u8,
+        result: &mut Vec<T>,
+    ) -> EndpointResult<PageInfo> {
+        let request = build_request::<T, Self, AUTHENTICATED, FORCE>(
+            self,
+            T::URL,
+            &[
+                ("page", &page.to_string()),
+                ("page_size", &page_size.to_string()),
+            ],
+        )?;
+
+        cancellable(self, async {
+            let response = exec_req::<Self, AUTHENTICATED, FORCE>(self, request).await?;
+            let total_items: usize = get_header(&response, "x-result-total").unwrap_or(0);
+            let size: usize = get_header(&response, "x-page-size").unwrap_or(page_size.into());
+            let total_pages = get_header(&response, "x-page-total")
+                .unwrap_or_else(|| total_items.div_ceil(size.max(1)));
+            let result_count = get_header(&response, "x-result-count");
+            let res: Vec<T> = parse_raw_response(self, response).await?;
+            let result_count = result_count.unwrap_or(res.len());
+            result.extend_from_slice(&res);
+
+            Ok(PageInfo {
+                total_items,
+                total_pages,
+                page_size: size,
+                result_count,
+            })
+        })
+        .await
+    }
+
+    /// requests all items using the most efficient method available
+    /// ### Remarks
+    /// for most endpoints this means using [`Self::get_all_by_requesting_ids`].
+    /// Compared to [`Self::get_all_by_paging`]
+    /// this needs to perform an additional request to get all ids, but is much
+    /// more cache friendly, being able to utilize the cache and inflight
+    /// mechanisms.
+    ///
+    /// Concurrent calls for the same endpoint, language and authentication
+    /// share the result of the first one.
+    #[cfg_attr(feature = "tracing", instrument(skip_all, fields(endpoint = %T::URL)))]
+    async fn all<
+        T: DeserializeOwned
+            + Serialize
+            + EndpointWithId<IdType = I>
+            + BulkEndpoint
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        I: Display + DeserializeOwned + Serialize + Hash + Clone + Send + Sync + Eq + 'static,
+    >(
+        &self,
+    ) -> EndpointResult<Vec<T>> {
+        // concurrent calls wait for the first one instead of requesting
+        // everything again. Forced calls and calls that don't store must not
+        // be answered by the others, which might read from the cache.
+        let key = ("all", FORCE, self.cache_store());
+        let tx = loop {
+            let either = check_inflight::<Vec<T>, _, T, String>(
+                &self.client().inflight,
+                &key,
+                self.request_language(),
+                &self.client().identifier,
+            )
+            .await;
+            match either {
+                Some(Either::Left(mut rx)) => {
+                    if let Ok(result) = cancellable(self, async { Ok(rx.recv().await) }).await? {
+                        return Ok(result);
+                    }
+                    // the other call failed, take over
+                }
+                Some(Either::Right(tx)) => break tx,
+                None => {}
+            }
+        };
+
+        let result = if T::ALL {
+            self.get_all_by_ids_all().await?
+        // paging cannot utilize the cache, so we won't use it by default
+        // } else if T::PAGING {
+        //     self.get_all_by_paging()
+        } else {
+            self.get_all_by_requesting_ids().await?
+        };
+        let tx = tx.lock().await;
+        if tx.receiver_count() > 0 {
+            // ignoring the error is fine here
+            // the receiving side will take over if nothing got sent
+            let _ = tx.send(result.clone());
+        }
+        Ok(result)
+    }
+
+    /// Gets all items by querying ids=all
+    ///
+    /// use [`Self::all`] to use the most efficient way to request all items
+    #[cfg_attr(feature = "tracing", instrument(name = "get all by ids all", skip_all, fields(endpoint = %T::URL)))]
+    async fn get_all_by_ids_all<
+        T: DeserializeOwned
+            + Serialize
+            + EndpointWithId<IdType = I>
+            + BulkEndpoint
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        I: Display + DeserializeOwned + Hash + Clone + Sync + 'static,
+    >(
+        &self,
+    ) -> EndpointResult<Vec<T>> {
+        if !T::ALL {
+            return Err(EndpointError::UnsupportedEndpointQuery);
+        }
+
+        if let Some(c) =
+            check_cache::<Vec<T>, str, T, Self, AUTHENTICATED, FORCE>(self, "ids=all").await
+        {
+            return Ok(c);
+        }
+
+        let request =
+            build_request::<T, Self, AUTHENTICATED, FORCE>(self, T::URL, &[("ids", "all")])?;
+
+        cancellable(self, async {
+            let response = exec_req::<Self, AUTHENTICATED, FORCE>(self, request).await?;
+            let count = get_header(&response, "x-result-total").unwrap_or(0);
+            let mut result = Vec::with_capacity(count);
+            cache_response_all(self, response, &mut result).await?;
+
+            Ok(result)
+        })
+        .await
+    }
+
+    /// Gets all items by querying all pages
+    ///
+    /// use [`Self::all`] to use the most efficient way to request all items
+    #[cfg_attr(feature = "tracing", instrument(name = "get all by paging", skip_all, fields(endpoint = %T::URL)))]
+    async fn get_all_by_paging<
+        T: DeserializeOwned + PagedEndpoint + Clone + Send + Sync + 'static,
+    >(
+        &self,
+    ) -> EndpointResult<Vec<T>> {
+        let page_size = self.client().options.bulk_chunk_size;
+        let mut result = Vec::with_capacity(page_size.into());
+        let max_items = self.page(0, page_size, &mut result).await?;
+        result.reserve_exact(max_items.saturating_sub(result.len()));
+
+        // `buffered` yields the pages in order, however they complete
+        let pages: Vec<Vec<T>> =
+            futures::stream::iter(remaining_pages(max_items, page_size.into()))
+                .map(|page| async move {
+                    let mut items = Vec::with_capacity(page_size.into());
+                    self.page(page, page_size, &mut items).await?;
+                    EndpointResult::Ok(items)
+                })
+                .buffered(self.client().options.max_concurrency)
+                .try_collect()
+                .await?;
+        result.extend(pages.into_iter().flatten());
+
+        Ok(result)
+    }
+
+    /// Gets all items by querying all ids
+    ///
+    /// use [`Self::all`] to use the most efficient way to request all items
+    #[cfg_attr(feature = "tracing", instrument(name = "get all by requesting ids", skip_all, fields(endpoint = %T::URL)))]
+    async fn get_all_by_requesting_ids<
+        T: DeserializeOwned
+            + Serialize
+            + EndpointWithId<IdType = I>
+            + BulkEndpoint
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        I: Display + DeserializeOwned + Serialize + Hash + Clone + Send + Sync + Eq + 'static,
+    >(
+        &self,
+    ) -> EndpointResult<Vec<T>> {
+        let ids = self.ids::<T, I>().await?;
+        self.many(ids).await
+    }
+
+    /// which world bosses, daily crafts and map chests the account already
+    /// completed since the last daily reset
+    ///
+    /// The lists of all rewards are cached for a day, the progress of the
+    /// account until the next daily reset, see
+    /// [`Requester::cached_until_reset`]. Requires the `progression` scope.
+    #[cfg(feature = "achievements")]
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    async fn daily_progress(&self) -> EndpointResult<DailyProgress> {
+        let rewards = self.cached(Duration::days(1));
+        let progress = self.cached_until_reset();
+        let (world_bosses, daily_crafting, map_chests) = futures::try_join!(
+            rewards.all::<WorldBoss, String>(),
+            rewards.all::<DailyCrafting, String>(),
+            rewards.all::<MapChest, String>(),
+        )?;
+        let (account_world_bosses, account_daily_crafting, account_map_chests) = futures::try_join!(
+            progress.get::<AccountWorldBosses>(),
+            progress.get::<AccountDailyCrafting>(),
+            progress.get::<AccountMapChests>(),
+        )?;
+        Ok(DailyProgress::new(
+            &world_bosses,
+            &daily_crafting,
+            &map_chests,
+            &account_world_bosses,
+            &account_daily_crafting,
+            &account_map_chests,
+        ))
+    }
+
+    /// fetches all worlds and returns those whose population changed
+    /// compared to `previous`, as `(id, old, new)`
+    ///
+    /// Always requests fresh data, see [`Requester::forced`]. Pass the
+    /// worlds of the previous call to poll for changes.
+    #[cfg(feature = "misc")]
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    async fn world_population_changes(
+        &self,
+        previous: &[World],
+    ) -> EndpointResult<Vec<PopulationChange>> {
+        let current = self.forced().all::<World, WorldId>().await?;
+        Ok(population_changes(previous, &current))
+    }
+}
+
+struct SenderGuard<'client, T: Send + 'static> {
+    sender: Arc<Mutex<Sender<T>>>,
+    inflight: &'client Inflight,
+    hash: (TypeId, u64),
+}
+
+impl<T: Send + 'static> Deref for SenderGuard<'_, T> {
+    type Target = Mutex<Sender<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sender
+    }
+}
+
+impl<T: Send + 'static> Drop for SenderGuard<'_, T> {
+    fn drop(&mut self) {
+        let inflight = self.inflight.clone();
+        let hash = self.hash;
+        let sender = Arc::downgrade(&self.sender);
+
+        // another request might have taken over this slot in the meantime
+        let task = async move {
+            inflight.remove_if(&hash, |_, v| {
+                v.downcast_ref::<Weak<Mutex<Sender<T>>>>()
+                    .is_some_and(|v| v.ptr_eq(&sender))
+            })
+        };
 
         crate::block::spawn(task);
     }
@@ -475,7 +1460,12 @@ async fn check_inflight<
                 .get_mut()
                 .downcast_mut::<Weak<Mutex<Sender<H>>>>()
                 .unwrap();
-            let r = r.upgrade()?;
+            let Some(r) = r.upgrade() else {
+                // the previous request was cancelled or failed and its guard hasn't cleaned up
+                // yet. The caller checks the cache again and takes over afterwards.
+                e.remove();
+                return None;
+            };
             let r = r.lock().await;
             Either::Left(r.subscribe())
         }
@@ -505,10 +1495,38 @@ async fn check_cache<
     id: &I,
 ) -> Option<T> {
     if !F {
-        req.client()
+        let cached = req
+            .client()
             .cache
-            .get::<T, I, E, String>(id, req.client().language, &req.client().identifier)
-            .await
+            .get::<T, I, E, String>(id, req.request_language(), &req.client().identifier)
+            .await;
+        record_cache_lookup(req, E::URL, cached.is_some());
+        cached
+    } else {
+        None
+    }
+}
+
+#[cfg_attr(feature = "tracing", instrument(name = "check cache", skip_all, fields(%id, endpoint = %E::URL)))]
+async fn check_cache_arc<
+    T: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+    I: Display + Hash + Sync + 'static + ?Sized,
+    E: Endpoint,
+    Req: Requester<A, F>,
+    const A: bool,
+    const F: bool,
+>(
+    req: &Req,
+    id: &I,
+) -> Option<Arc<T>> {
+    if !F {
+        let cached = req
+            .client()
+            .cache
+            .get_arc::<T, I, E, String>(id, req.request_language(), &req.client().identifier)
+            .await;
+        record_cache_lookup(req, E::URL, cached.is_some());
+        cached
     } else {
         None
     }
@@ -523,7 +1541,7 @@ async fn get_or_ids<
 >(
     req: &Req,
 ) -> EndpointResult<K> {
-    let lang = req.client().language;
+    let lang = req.request_language();
     if let Some(c) = check_cache::<K, str, T, Req, A, F>(req, "").await {
         return Ok(c);
     }
@@ -537,7 +1555,9 @@ async fn get_or_ids<
         )
         .await;
         match either {
-            Some(Either::Left(mut rx)) => return rx.recv().await.map_err(Into::into),
+            Some(Either::Left(mut rx)) => {
+                return cancellable(req, async { rx.recv().await.map_err(Into::into) }).await
+            }
             Some(Either::Right(tx)) => break tx,
             None => {
                 if let Some(c) = check_cache::<K, str, T, Req, A, F>(req, "").await {
@@ -547,10 +1567,9 @@ async fn get_or_ids<
         }
     };
 
-    let request = build_request::<T, String, Req, A, F>(req, T::URL, None)?;
+    let request = build_request::<T, Req, A, F>(req, T::URL, &[])?;
 
-    let response = exec_req::<Req, A, F>(req, request).await?;
-    let result = cache_response::<str, K, T, Req, A, F>(req, "", response).await?;
+    let result = fetch_and_cache::<str, K, T, Req, A, F>(req, "", request).await?;
     // ignoring the error is fine here
     // the receiving side will check the cache if nothing got sent
     let _ = tx.lock().await.send(result.clone());
@@ -558,6 +1577,23 @@ async fn get_or_ids<
     Ok(result)
 }
 
+/// runs `fut` until the cancellation token of `req` is cancelled
+///
+/// Wraps everything from waiting for the rate limit to reading the body, so
+/// a cancelled request doesn't outlive its token.
+async fn cancellable<K, Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    fut: impl Future<Output = EndpointResult<K>>,
+) -> EndpointResult<K> {
+    match req.cancellation() {
+        Some(token) => token
+            .run_until_cancelled(fut)
+            .await
+            .unwrap_or(Err(EndpointError::Cancelled)),
+        None => fut.await,
+    }
+}
+
 #[cfg_attr(feature = "tracing", instrument(name = "execute request", skip_all, fields(uri = %request.uri().path())))]
 async fn exec_req<Req: Requester<A, F>, const A: bool, const F: bool>(
     req: &Req,
@@ -565,32 +1601,49 @@ async fn exec_req<Req: Requester<A, F>, const A: bool, const F: bool>(
 ) -> EndpointResult<Response<hyper::Body>> {
     wait_for_rate_limit(req).await?;
 
+    let url = RequestUrl::new(request.uri());
+    let authenticated = request
+        .uri()
+        .query()
+        .is_some_and(|q| q.contains("access_token="));
+
+    // `cached` is whether the response is written to the cache. Forced
+    // requests skip the cache lookup, but still store their response.
     #[cfg(feature = "tracing")]
     let span = {
-        let uri = request.uri().path();
-        let ids = request
-            .uri()
-            .query()
-            .unwrap_or_default()
-            .split("ids=")
-            .nth(1)
-            .unwrap_or_default()
-            .split("&")
-            .next()
-            .unwrap_or_default();
-        let span = tracing::info_span!("gw2 request", %uri, %ids);
-        let entered = span.enter();
-        tracing::info!(%uri, %ids, "gw2 request");
-        drop(entered);
+        let span = tracing::info_span!(
+            "gw2 request",
+            url = %url.0,
+            authenticated,
+            cached = req.cache_store(),
+            status = tracing::field::Empty,
+        );
+        span.in_scope(|| tracing::info!(url = %url.0, "gw2 request"));
         span
     };
 
+    let metrics = req.client().options.metrics.as_ref();
+    if let Some(metrics) = metrics {
+        metrics.on_request(&url.0, authenticated);
+    }
+    let start = Instant::now();
+
     let fut = req.client().client.request(request);
 
     #[cfg(feature = "tracing")]
-    let fut = fut.instrument(span);
+    let fut = fut.instrument(span.clone());
+
+    let mut response = fut.await?;
+
+    #[cfg(feature = "tracing")]
+    span.record("status", response.status().as_u16());
+
+    if let Some(metrics) = metrics {
+        metrics.on_response(&url.0, response.status().as_u16(), start.elapsed());
+    }
 
-    fut.await.map_err(Into::into)
+    response.extensions_mut().insert(url);
+    Ok(response)
 }
 
 #[cfg_attr(
@@ -605,54 +1658,175 @@ async fn wait_for_rate_limit<Req: Requester<A, F>, const A: bool, const F: bool>
     Ok(())
 }
 
-fn build_request<T: Endpoint, Q: AsRef<str>, Req: Requester<A, F>, const A: bool, const F: bool>(
+fn build_request<T: Endpoint, Req: Requester<A, F>, const A: bool, const F: bool>(
     req: &Req,
     path: impl AsRef<str>,
-    extra_queries: Option<Q>,
+    extra_queries: &[(&str, &str)],
 ) -> Result<Request<hyper::Body>, EndpointError> {
     if T::AUTHENTICATED && !A {
         return Err(EndpointError::NotAuthenticated);
     }
 
-    let client = req.client();
+    let version = if req.client().options.strict_schema {
+        SchemaVersion::Latest.value()
+    } else {
+        req.request_schema()
+            .map_or(Cow::Borrowed(T::VERSION), |x| x.value())
+    };
 
-    let mut pnq = String::with_capacity(400);
-    pnq.push('/');
-    pnq.push_str(path.as_ref());
-    pnq.push('?');
+    let uri = build_uri(
+        req,
+        path,
+        &version,
+        &[T::QUERY, extra_queries].concat(),
+        T::LOCALE,
+        T::AUTHENTICATED,
+    )?;
+    let method = match T::METHOD {
+        gw2lib_model::Method::Get => Method::GET,
+        gw2lib_model::Method::Post => Method::POST,
+    };
+    Ok(assemble_request(
+        method,
+        uri,
+        schema_header(req, &version),
+        None,
+    ))
+}
 
-    pnq.push_str("v=");
-    pnq.push_str(T::VERSION);
+fn build_uri<Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    path: impl AsRef<str>,
+    version: &str,
+    extra_queries: &[(&str, &str)],
+    locale: bool,
+    authenticated: bool,
+) -> Result<Uri, EndpointError> {
+    let client = req.client();
 
-    if let Some(extra) = extra_queries {
-        pnq.push('&');
-        pnq.push_str(extra.as_ref());
+    let mut query = Vec::with_capacity(extra_queries.len() + 3);
+    if client.options.schema_via_query {
+        query.push(("v".into(), version.into()));
     }
-
-    if T::LOCALE {
-        pnq.push_str("&lang=");
-        pnq.push_str(client.language.as_str());
+    query.extend(extra_queries.iter().map(|&(k, v)| (k.into(), v.into())));
+    if locale {
+        query.push(("lang".into(), req.request_language().as_str().into()));
     }
-
-    if T::AUTHENTICATED {
-        pnq.push_str("&access_token=");
-        pnq.push_str(client.api_key.as_ref().unwrap());
+    if authenticated {
+        let api_key = client.api_key.as_deref().unwrap();
+        query.push(("access_token".into(), api_key.into()));
     }
 
-    let (scheme, host) = client.host.split_once("://").expect("invalid host");
-    let uri = Uri::builder()
-        .scheme(scheme)
+    let mut pnq = String::with_capacity(400);
+    pnq.push('/');
+    pnq.push_str(path.as_ref());
+    if !query.is_empty() {
+        pnq.push('?');
+        pnq.push_str(&serialize_query(&query));
+    }
+
+    let (scheme, host) = client.host.split_once("://").expect("invalid host");
+    Uri::builder()
+        .scheme(scheme)
         .authority(host)
         .path_and_query(pnq)
         .build()
-        .expect("invalid uri");
+        .map_err(EndpointError::InvalidUri)
+}
 
-    let request = hyper::Request::builder()
-        .uri(uri)
-        .body(hyper::Body::empty())
-        .unwrap();
+/// splits an encoded query into decoded key value pairs
+fn parse_query(query: &str) -> impl Iterator<Item = (Cow<'_, str>, Cow<'_, str>)> {
+    fn decode(x: &str) -> Cow<'_, str> {
+        urlencoding::decode(x).unwrap_or(Cow::Borrowed(x))
+    }
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode(key), decode(value))
+        })
+}
+
+/// joins key value pairs into a query, percent-encoding keys and values
+///
+/// Commas and colons are valid in a query and kept as is, the api uses
+/// commas to separate ids and schema versions contain colons.
+fn serialize_query(pairs: &[(Cow<str>, Cow<str>)]) -> String {
+    // the encoding of a literal `%` is `%25`, so this only restores `,` and `:`
+    let encode = |x: &str| {
+        urlencoding::encode(x)
+            .replace("%2C", ",")
+            .replace("%3A", ":")
+    };
+    pairs
+        .iter()
+        .map(|(k, v)| format!("{}={}", encode(k), encode(v)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
 
-    Ok(request)
+/// returns the schema version to send as a header, if it isn't part of the
+/// query
+fn schema_header<'a, Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    version: &'a str,
+) -> Option<&'a str> {
+    (!req.client().options.schema_via_query).then_some(version)
+}
+
+/// attaches a json `body` if present, an empty body otherwise
+fn assemble_request(
+    method: Method,
+    uri: Uri,
+    schema_version: Option<&str>,
+    json: Option<Vec<u8>>,
+) -> Request<hyper::Body> {
+    let mut builder = hyper::Request::builder().method(method).uri(uri);
+    if let Some(version) = schema_version {
+        builder = builder.header("X-Schema-Version", version);
+    }
+    match json {
+        Some(json) => builder
+            .header(CONTENT_TYPE, "application/json")
+            .body(hyper::Body::from(json)),
+        None => builder.body(hyper::Body::empty()),
+    }
+    .unwrap()
+}
+
+/// requests an arbitrary path, bypassing cache and inflight deduplication
+async fn raw_request<K: DeserializeOwned, Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    method: Method,
+    path: &str,
+    queries: &[(&str, &str)],
+    version: &str,
+    json: Option<Vec<u8>>,
+) -> EndpointResult<K> {
+    // raw paths may carry a query of their own
+    let (path, fixed) = path.split_once('?').unwrap_or((path, ""));
+    let fixed: Vec<_> = parse_query(fixed).collect();
+    let queries: Vec<_> = fixed
+        .iter()
+        .map(|(k, v)| (k.as_ref(), v.as_ref()))
+        .chain(queries.iter().copied())
+        .collect();
+    let uri = build_uri(
+        req,
+        path.trim_start_matches('/'),
+        version,
+        &queries,
+        true,
+        A,
+    )?;
+    let request = assemble_request(method, uri, schema_header(req, version), json);
+
+    cancellable(req, async {
+        let response = exec_req::<Req, A, F>(req, request).await?;
+        parse_raw_response(req, response).await
+    })
+    .await
 }
 
 /// returns the remaining ids not found in cache
@@ -674,17 +1848,302 @@ async fn extract_many_from_cache<
         if let Some(cached) = req
             .client()
             .cache
-            .get::<K, I, K, String>(&i, req.client().language, &req.client().identifier)
+            .get::<K, I, K, String>(&i, req.request_language(), &req.client().identifier)
             .await
         {
+            record_cache_lookup(req, K::URL, true);
             result.push(cached);
         } else {
+            record_cache_lookup(req, K::URL, false);
             rest.push(i);
         }
     }
     rest
 }
 
+/// reports a cache hit or miss to the tracing subscriber and the metrics
+fn record_cache_lookup<Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    endpoint: &str,
+    hit: bool,
+) {
+    #[cfg(feature = "tracing")]
+    if hit {
+        tracing::debug!(cached = true, "cache hit");
+    } else {
+        tracing::debug!(cached = false, "cache miss");
+    }
+    if let Some(metrics) = &req.client().options.metrics {
+        if hit {
+            metrics.on_cache_hit(endpoint);
+        } else {
+            metrics.on_cache_miss(endpoint);
+        }
+    }
+}
+
+/// how long a response stays available for revalidation after it expired
+const REVALIDATION_RETENTION: i64 = 24;
+
+/// a response kept for revalidation with `If-Modified-Since`
+///
+/// `value` is shared with the cached response in caches keeping values in
+/// memory, until that one expires.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(bound(deserialize = "K: DeserializeOwned"))]
+struct Revalidation<K> {
+    last_modified: String,
+    value: Arc<K>,
+}
+
+/// remembers that an id of `T` doesn't exist
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+struct Absent<T>(PhantomData<T>);
+
+/// the id of an entry kept next to the cached value of an id, like
+/// [`Absent`] or [`Revalidation`]
+///
+/// Textual cache keys don't include the cached type, so these entries need
+/// ids of their own to not overwrite the value.
+#[derive(Hash)]
+struct InternalId {
+    id: String,
+    kind: &'static str,
+}
+
+impl InternalId {
+    fn absent(id: &(impl Display + ?Sized)) -> Self {
+        Self {
+            id: id.to_string(),
+            kind: "absent",
+        }
+    }
+
+    fn revalidation(id: &(impl Display + ?Sized)) -> Self {
+        Self {
+            id: id.to_string(),
+            kind: "revalidation",
+        }
+    }
+}
+
+impl Display for InternalId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}#{}", self.id, self.kind)
+    }
+}
+
+/// whether `id` is remembered as not found, see [`Client::negative_cache`]
+async fn is_absent<
+    I: Display + Hash + Sync + 'static + ?Sized,
+    T: DeserializeOwned + Serialize + Clone + Endpoint + Send + Sync + 'static,
+    Req: Requester<A, F>,
+    const A: bool,
+    const F: bool,
+>(
+    req: &Req,
+    id: &I,
+) -> bool {
+    let client = req.client();
+    if F || client.options.negative_cache.is_zero() {
+        return false;
+    }
+    client
+        .cache
+        .get::<Absent<T>, InternalId, T, String>(
+            &InternalId::absent(id),
+            req.request_language(),
+            &client.identifier,
+        )
+        .await
+        .is_some()
+}
+
+/// like [`Requester::try_get`], but reads the cache without awaiting
+#[cfg(feature = "blocking")]
+pub(crate) fn try_get_sync<
+    T: DeserializeOwned + Serialize + Clone + Endpoint + Send + Sync + 'static,
+    I: Display + Hash + Sync + 'static + ?Sized,
+    Req: Requester<A, F>,
+    const A: bool,
+    const F: bool,
+>(
+    req: &Req,
+    id: &I,
+) -> Option<T>
+where
+    Req::Caching: SyncCache,
+{
+    if F {
+        return None;
+    }
+    let client = req.client();
+    let lang = req.request_language();
+    if !client.options.negative_cache.is_zero()
+        && client
+            .cache
+            .get_sync::<Absent<T>, InternalId, T, String>(
+                &InternalId::absent(id),
+                lang,
+                &client.identifier,
+            )
+            .is_some()
+    {
+        return None;
+    }
+    let cached = client
+        .cache
+        .get_sync::<T, I, T, String>(id, lang, &client.identifier);
+    record_cache_lookup(req, T::URL, cached.is_some());
+    cached
+}
+
+/// remembers `id` as not found for the duration of
+/// [`Client::negative_cache`]
+async fn cache_absent<
+    I: Display + Hash + Sync + 'static + ?Sized,
+    T: DeserializeOwned + Serialize + Clone + Endpoint + Send + Sync + 'static,
+    Req: Requester<A, F>,
+    const A: bool,
+    const F: bool,
+>(
+    req: &Req,
+    id: &I,
+) {
+    let client = req.client();
+    if client.options.negative_cache.is_zero() || !req.cache_store() {
+        return;
+    }
+    let expires = Utc::now().naive_utc() + client.options.negative_cache;
+    client
+        .cache
+        .insert::<Absent<T>, InternalId, T, String>(
+            &InternalId::absent(id),
+            &Absent(PhantomData),
+            expires,
+            req.request_language(),
+            &client.identifier,
+        )
+        .await;
+}
+
+/// forgets that `id` was not found, after it was found after all
+async fn clear_absent<
+    I: Display + Hash + Sync + 'static + ?Sized,
+    T: DeserializeOwned + Serialize + Clone + Endpoint + Send + Sync + 'static,
+    Req: Requester<A, F>,
+    const A: bool,
+    const F: bool,
+>(
+    req: &Req,
+    id: &I,
+) {
+    let client = req.client();
+    if client.options.negative_cache.is_zero() || !req.cache_store() {
+        return;
+    }
+    client
+        .cache
+        .remove::<Absent<T>, InternalId, T, String>(
+            &InternalId::absent(id),
+            req.request_language(),
+            &client.identifier,
+        )
+        .await;
+}
+
+/// sends `request` and caches the response under `id`
+///
+/// With [`Client::revalidate`], a request for an expired response that had a
+/// `Last-Modified` header carries `If-Modified-Since`, and `304 Not Modified`
+/// returns the expired response with a renewed expiry.
+async fn fetch_and_cache<
+    I: Hash + Sync + 'static + Display + ?Sized,
+    K: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
+    T: Endpoint,
+    Req: Requester<A, F>,
+    const A: bool,
+    const F: bool,
+>(
+    req: &Req,
+    id: &I,
+    mut request: Request<hyper::Body>,
+) -> EndpointResult<K> {
+    cancellable(req, async move {
+        let client = req.client();
+        if !client.options.revalidate || F || !req.cache_store() {
+            let response = exec_req::<Req, A, F>(req, request).await?;
+            return cache_response::<I, K, T, Req, A, F>(req, id, response).await;
+        }
+
+        let revalidation_id = InternalId::revalidation(id);
+        let stale = client
+            .cache
+            .get::<Revalidation<K>, InternalId, T, String>(
+                &revalidation_id,
+                req.request_language(),
+                &client.identifier,
+            )
+            .await;
+        if let Some(value) = stale
+            .as_ref()
+            .and_then(|stale| HeaderValue::from_str(&stale.last_modified).ok())
+        {
+            request.headers_mut().insert(IF_MODIFIED_SINCE, value);
+        }
+
+        let response = exec_req::<Req, A, F>(req, request).await?;
+        let (last_modified, result) = match stale {
+            Some(stale) if response.status() == StatusCode::NOT_MODIFIED => {
+                let expires = get_cache_expiry(req, &response);
+                client
+                    .cache
+                    .insert::<K, I, T, String>(
+                        id,
+                        &stale.value,
+                        expires,
+                        req.request_language(),
+                        &client.identifier,
+                    )
+                    .await;
+                (Some(stale.last_modified), Arc::unwrap_or_clone(stale.value))
+            }
+            _ => {
+                let last_modified = get_header::<String>(&response, LAST_MODIFIED)
+                    .filter(|_| !is_no_store(&response));
+                let result = cache_response::<I, K, T, Req, A, F>(req, id, response).await?;
+                (last_modified, result)
+            }
+        };
+
+        if let Some(last_modified) = last_modified {
+            let retention = Utc::now().naive_utc() + Duration::hours(REVALIDATION_RETENTION);
+            let value = client
+                .cache
+                .get_arc::<K, I, T, String>(id, req.request_language(), &client.identifier)
+                .await
+                .unwrap_or_else(|| Arc::new(result.clone()));
+            let revalidation = Revalidation {
+                last_modified,
+                value,
+            };
+            client
+                .cache
+                .insert::<Revalidation<K>, InternalId, T, String>(
+                    &revalidation_id,
+                    &revalidation,
+                    retention,
+                    req.request_language(),
+                    &client.identifier,
+                )
+                .await;
+        }
+
+        Ok(result)
+    })
+    .await
+}
+
 async fn cache_response<
     I: Hash + Sync + 'static + Display + ?Sized,
     K: DeserializeOwned + Serialize + Clone + Send + Sync + 'static,
@@ -697,7 +2156,11 @@ async fn cache_response<
     id: &I,
     response: Response<hyper::Body>,
 ) -> Result<K, EndpointError> {
+    let store = req.cache_store() && !is_no_store(&response);
     let (expires, result): (_, K) = parse_response(req, response).await?;
+    if !store {
+        return Ok(result);
+    }
 
     req.client()
         .cache
@@ -705,7 +2168,7 @@ async fn cache_response<
             id,
             &result,
             expires,
-            req.client().language,
+            req.request_language(),
             &req.client().identifier,
         )
         .await;
@@ -731,20 +2194,25 @@ async fn cache_response_many<
     response: Response<hyper::Body>,
     result: &mut Vec<K>,
 ) -> Result<(), EndpointError> {
-    let (expires, res): (_, Vec<K>) = parse_response(req, response).await?;
+    let start = result.len();
+    let store = req.cache_store() && !is_no_store(&response);
+    let expires = parse_response_into(req, response, result).await?;
+    if !store {
+        return Ok(());
+    }
 
-    for t in res {
+    for t in &result[start..] {
         req.client()
             .cache
             .insert::<K, I, K, String>(
                 t.id(),
-                &t,
+                t,
                 expires,
-                req.client().language,
+                req.request_language(),
                 &req.client().identifier,
             )
             .await;
-        result.push(t);
+        clear_absent::<I, K, Req, A, F>(req, t.id()).await;
     }
 
     Ok(())
@@ -768,7 +2236,13 @@ async fn cache_response_all<
     response: Response<hyper::Body>,
     result: &mut Vec<K>,
 ) -> Result<(), EndpointError> {
-    let (expires, res): (_, Vec<K>) = parse_response(req, response).await?;
+    let start = result.len();
+    let store = req.cache_store() && !is_no_store(&response);
+    let expires = parse_response_into(req, response, result).await?;
+    if !store {
+        return Ok(());
+    }
+    let res = result.split_off(start);
 
     req.client()
         .cache
@@ -776,7 +2250,7 @@ async fn cache_response_all<
             "ids=all",
             &res,
             expires,
-            req.client().language,
+            req.request_language(),
             &req.client().identifier,
         )
         .await;
@@ -788,10 +2262,11 @@ async fn cache_response_all<
                 t.id(),
                 &t,
                 expires,
-                req.client().language,
+                req.request_language(),
                 &req.client().identifier,
             )
             .await;
+        clear_absent::<I, K, Req, A, F>(req, t.id()).await;
         result.push(t);
     }
 
@@ -799,7 +2274,7 @@ async fn cache_response_all<
 }
 
 async fn parse_response<
-    K: DeserializeOwned + Clone + Send + Sync + 'static,
+    K: DeserializeOwned + Serialize,
     Req: Requester<A, F>,
     const A: bool,
     const F: bool,
@@ -807,79 +2282,2779 @@ async fn parse_response<
     req: &Req,
     response: Response<hyper::Body>,
 ) -> Result<(NaiveDateTime, K), EndpointError> {
-    let status = response.status();
-    if !status.is_success() {
-        let bytes = hyper::body::to_bytes(response.into_body()).await?;
-        let error = serde_json::from_slice::<'_, ErrorResponse>(&bytes);
-        return Err(EndpointError::ApiError(match (status.as_u16(), error) {
-            (401, _) => ApiError::Unauthorized,
-            (400, Ok(ErrorResponse { text })) if &text == "invalid key" => ApiError::Unauthorized,
-            (400, Ok(ErrorResponse { text })) if &text == "Invalid access token" => {
-                ApiError::Unauthorized
-            }
-            (400, Ok(ErrorResponse { text })) if &text == "account does not have game access" => {
-                ApiError::MissingGameAccess
-            }
-            (429, _) => {
-                let _ = req.client().rate_limiter.penalize().await;
-                ApiError::RateLimited
-            }
-            (_, Ok(ErrorResponse { text })) => ApiError::Other(status, text),
-            _ => {
-                let body = String::from_utf8_lossy(&bytes);
-                ApiError::Other(status, body.to_string())
-            }
-        }));
+    let (expires, url, body) = read_response(req, response).await?;
+    let result: K = deserialize_body(req, &body, &url)?;
+    if req.client().options.strict_schema {
+        check_unknown_fields(&body, &result, url)?;
     }
-    let expires = get_cache_expiry(req, &response);
-    let body = hyper::body::aggregate(response.into_body()).await?;
-    let result: K = serde_json::from_reader(&mut body.reader())?;
     Ok((expires, result))
 }
 
-fn get_cache_expiry<Req: Requester<A, F>, const A: bool, const F: bool>(
+/// parses a list response and appends its entries to `result`
+#[cfg(not(feature = "stream-parse"))]
+async fn parse_response_into<
+    K: DeserializeOwned + Serialize,
+    Req: Requester<A, F>,
+    const A: bool,
+    const F: bool,
+>(
     req: &Req,
-    response: &Response<hyper::Body>,
-) -> NaiveDateTime {
-    let duration = req.cache_duration();
-    let expires = if !duration.is_zero() {
-        duration
-    } else {
-        get_expire_from_header(response)
-    };
-    Utc::now().naive_utc() + expires
+    response: Response<hyper::Body>,
+    result: &mut Vec<K>,
+) -> Result<NaiveDateTime, EndpointError> {
+    let (expires, res): (_, Vec<K>) = parse_response(req, response).await?;
+    result.extend(res);
+    Ok(expires)
 }
 
-/// concatenates ids, separated by comma: 1,2,3,4
-/// chunked in 200 per batch
+/// parses a list response and appends its entries to `result`
 ///
-/// panics when `ids.len() == 0`
-fn join_ids<I: Display + 'static>(ids: &[I]) -> Vec<String> {
-    use std::fmt::Write;
-    let modulo = ids.len() % 200 != 0;
-    let ceil = ids.len() / 200 + (modulo as usize);
-    let mut result = Vec::with_capacity(ceil);
-    for ids in ids.chunks(200) {
-        let mut query_string = String::with_capacity(6 * ids.len()); // arbitrary. most ids are 5 digits + comma
-        write!(&mut query_string, "{}", ids[0]).expect("failed to concatenate ids");
-        for i in ids.iter().skip(1) {
-            write!(&mut query_string, ",{i}").expect("failed to concatenate ids");
+/// The entries are parsed one by one as the body arrives, so neither the
+/// whole body nor an intermediate list of entries is held in memory next to
+/// `result`. This lowers the peak memory of large responses like `ids=all`.
+#[cfg(feature = "stream-parse")]
+async fn parse_response_into<
+    K: DeserializeOwned + Serialize,
+    Req: Requester<A, F>,
+    const A: bool,
+    const F: bool,
+>(
+    req: &Req,
+    response: Response<hyper::Body>,
+    result: &mut Vec<K>,
+) -> Result<NaiveDateTime, EndpointError> {
+    use hyper::body::HttpBody;
+
+    let (expires, url, mut body) = check_response(req, response).await?;
+    let strict_schema = req.client().options.strict_schema;
+    let mut list = stream_parse::ListSplitter::default();
+    let mut entries = |list: &mut stream_parse::ListSplitter| -> EndpointResult<()> {
+        loop {
+            let entry = match list.next_entry() {
+                Ok(Some(entry)) => entry,
+                Ok(None) => return Ok(()),
+                Err(source) => return Err(malformed_list(req, source, list, &url)),
+            };
+            let parsed: K = deserialize_body(req, entry, &url)?;
+            if strict_schema {
+                check_unknown_fields(entry, &parsed, url.clone())?;
+            }
+            result.push(parsed);
         }
-        result.push(query_string);
+    };
+    while let Some(chunk) = body.data().await {
+        list.push(&chunk?);
+        entries(&mut list)?;
     }
-    result
+    list.finish()
+        .map_err(|source| malformed_list(req, source, &list, &url))?;
+    Ok(expires)
 }
 
-fn get_expire_from_header(response: &Response<hyper::Body>) -> Duration {
-    let exp = get_header(response, "cache-control").unwrap_or(300);
-    Duration::seconds(exp)
+#[cfg(feature = "stream-parse")]
+fn malformed_list<Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    source: serde_json::Error,
+    list: &stream_parse::ListSplitter,
+    url: &str,
+) -> EndpointError {
+    EndpointError::Deserialization {
+        source,
+        body: truncate_body(list.rest(), req.client().options.error_body_limit),
+        url: url.to_string(),
+    }
 }
 
-fn get_header<T: FromStr>(response: &Response<hyper::Body>, header: &str) -> Option<T> {
-    response
-        .headers()
-        .iter()
-        .find(|x| x.0 == header)
-        .and_then(|(_, d)| d.to_str().ok())
-        .and_then(|d| d.parse::<T>().ok())
+#[cfg(feature = "stream-parse")]
+mod stream_parse {
+    use serde::de::Error;
+
+    #[derive(Default, Clone, Copy, PartialEq, Eq)]
+    enum State {
+        /// before the opening `[`
+        #[default]
+        Start,
+        /// after the opening `[`, before the first entry
+        Open,
+        /// after a `,`, before the next entry
+        Comma,
+        /// inside of an entry starting at the given offset
+        Entry(usize),
+        /// after the closing `]`
+        Done,
+    }
+
+    /// splits a json list into its entries as the body arrives
+    ///
+    /// Only the bytes of the entry that isn't complete yet are kept between
+    /// chunks.
+    #[derive(Default)]
+    pub(super) struct ListSplitter {
+        pub(super) buffer: Vec<u8>,
+        /// offset up to which `buffer` was scanned
+        pos: usize,
+        state: State,
+        /// nesting of objects and lists inside of the current entry
+        depth: usize,
+        in_string: bool,
+        escaped: bool,
+    }
+
+    impl ListSplitter {
+        /// appends the next chunk of the body
+        pub(super) fn push(&mut self, chunk: &[u8]) {
+            // drop the entries that were already returned
+            let consumed = match self.state {
+                State::Entry(start) => {
+                    self.state = State::Entry(0);
+                    start
+                }
+                _ => self.pos,
+            };
+            self.buffer.drain(..consumed);
+            self.pos -= consumed;
+            self.buffer.extend_from_slice(chunk);
+        }
+
+        /// the next complete entry of the pushed chunks
+        pub(super) fn next_entry(&mut self) -> Result<Option<&[u8]>, serde_json::Error> {
+            while let Some(&byte) = self.buffer.get(self.pos) {
+                let pos = self.pos;
+                self.pos += 1;
+                match self.state {
+                    _ if byte.is_ascii_whitespace() && !self.in_string => {}
+                    State::Start if byte == b'[' => self.state = State::Open,
+                    State::Open if byte == b']' => self.state = State::Done,
+                    State::Open | State::Comma => {
+                        self.state = State::Entry(pos);
+                        self.scan(byte);
+                    }
+                    State::Entry(start) => {
+                        if self.in_string || self.depth > 0 || !matches!(byte, b',' | b']') {
+                            self.scan(byte);
+                            continue;
+                        }
+                        self.state = if byte == b',' {
+                            State::Comma
+                        } else {
+                            State::Done
+                        };
+                        return Ok(Some(&self.buffer[start..pos]));
+                    }
+                    State::Start | State::Done => {
+                        self.pos = pos;
+                        return Err(Error::custom("expected a list"));
+                    }
+                }
+            }
+            Ok(None)
+        }
+
+        fn scan(&mut self, byte: u8) {
+            if self.in_string {
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => self.in_string = false,
+                    _ => {}
+                }
+            } else {
+                match byte {
+                    b'"' => self.in_string = true,
+                    b'{' | b'[' => self.depth += 1,
+                    b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+        }
+
+        /// checks that the list was complete
+        pub(super) fn finish(&self) -> Result<(), serde_json::Error> {
+            match self.state {
+                State::Done => Ok(()),
+                _ => Err(Error::custom("unexpected end of the list")),
+            }
+        }
+
+        /// the bytes that weren't returned as entries yet
+        pub(super) fn rest(&self) -> &[u8] {
+            match self.state {
+                State::Entry(start) => &self.buffer[start..],
+                _ => &self.buffer[self.pos..],
+            }
+        }
+    }
+}
+
+/// like [`parse_response`], but for types that can't be checked with
+/// [`Client::strict_schema`]
+async fn parse_raw_response<
+    K: DeserializeOwned,
+    Req: Requester<A, F>,
+    const A: bool,
+    const F: bool,
+>(
+    req: &Req,
+    response: Response<hyper::Body>,
+) -> Result<K, EndpointError> {
+    let (_, url, body) = read_response(req, response).await?;
+    deserialize_body(req, &body, &url)
+}
+
+/// maps error responses and returns the expiry, url and body otherwise
+async fn read_response<Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    response: Response<hyper::Body>,
+) -> Result<(NaiveDateTime, String, Bytes), EndpointError> {
+    let (expires, url, body) = check_response(req, response).await?;
+    let body = hyper::body::to_bytes(body).await?;
+    Ok((expires, url, body))
+}
+
+/// like [`read_response`], but leaves the body unread
+async fn check_response<Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    response: Response<hyper::Body>,
+) -> Result<(NaiveDateTime, String, hyper::Body), EndpointError> {
+    if !response.status().is_success() {
+        return Err(api_error(req, response).await);
+    }
+    let response = expect_json(req, response).await?;
+    let expires = get_cache_expiry(req, &response);
+    let url = response
+        .extensions()
+        .get::<RequestUrl>()
+        .map(|u| u.0.clone())
+        .unwrap_or_default();
+    Ok((expires, url, response.into_body()))
+}
+
+/// maps a non success response to an error
+async fn api_error<Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    response: Response<hyper::Body>,
+) -> EndpointError {
+    let status = response.status();
+    let content_type = json_content_type(&response).err();
+    if status == StatusCode::SERVICE_UNAVAILABLE {
+        // the body is an html maintenance page, not worth reading
+        let retry_after = get_header::<String>(&response, RETRY_AFTER)
+            .and_then(|h| parse_retry_after(&h, Utc::now()));
+        return EndpointError::ServiceUnavailable { retry_after };
+    }
+    let bytes = match hyper::body::to_bytes(response.into_body()).await {
+        Ok(bytes) => bytes,
+        Err(e) => return e.into(),
+    };
+    let error = serde_json::from_slice::<'_, ErrorResponse>(&bytes);
+    if let (Err(_), Some(content_type)) = (&error, content_type) {
+        return unexpected_content_type(req, content_type, status, &bytes);
+    }
+    EndpointError::ApiError(match (status.as_u16(), error) {
+        (401, _) => ApiError::Unauthorized,
+        (400, Ok(ErrorResponse { text })) if &text == "invalid key" => ApiError::Unauthorized,
+        (400, Ok(ErrorResponse { text })) if &text == "Invalid access token" => {
+            ApiError::Unauthorized
+        }
+        (400, Ok(ErrorResponse { text })) if &text == "account does not have game access" => {
+            ApiError::MissingGameAccess
+        }
+        (404, _) => ApiError::NotFound,
+        (429, _) => {
+            let _ = req.client().rate_limiter.penalize().await;
+            ApiError::RateLimited
+        }
+        (_, Ok(ErrorResponse { text })) => ApiError::Other(status, text),
+        _ => {
+            let body = String::from_utf8_lossy(&bytes);
+            ApiError::Other(status, body.to_string())
+        }
+    })
+}
+
+/// returns the response if it is json, reads it into an error otherwise
+async fn expect_json<Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    response: Response<hyper::Body>,
+) -> EndpointResult<Response<hyper::Body>> {
+    match json_content_type(&response) {
+        Ok(()) => Ok(response),
+        Err(content_type) => {
+            let status = response.status();
+            let body = hyper::body::to_bytes(response.into_body()).await?;
+            Err(unexpected_content_type(req, content_type, status, &body))
+        }
+    }
+}
+
+/// checks the `Content-Type` header, returning it if it isn't json
+///
+/// Responses without the header are assumed to be json.
+fn json_content_type(response: &Response<hyper::Body>) -> Result<(), String> {
+    let Some(content_type) = get_header::<String>(response, CONTENT_TYPE) else {
+        return Ok(());
+    };
+    let mime = content_type.split(';').next().unwrap_or_default().trim();
+    let mime = mime.to_ascii_lowercase();
+    if mime == "application/json" || mime.ends_with("+json") {
+        Ok(())
+    } else {
+        Err(content_type)
+    }
+}
+
+fn unexpected_content_type<Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    content_type: String,
+    status: StatusCode,
+    body: &[u8],
+) -> EndpointError {
+    EndpointError::UnexpectedContentType {
+        content_type,
+        status,
+        body_preview: truncate_body(body, req.client().options.error_body_limit),
+    }
+}
+
+/// parses a `Retry-After` header value, either in seconds or as http date
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<std::time::Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(std::time::Duration::from_secs(seconds));
+    }
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    Some((at.with_timezone(&Utc) - now).to_std().unwrap_or_default())
+}
+
+/// only deserializes the id of an entry
+#[derive(serde::Deserialize)]
+struct IdOnly<I> {
+    id: I,
+}
+
+fn deserialize_body<K: DeserializeOwned, Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    body: &[u8],
+    url: &str,
+) -> Result<K, EndpointError> {
+    #[cfg(feature = "unknown-fields")]
+    let result =
+        gw2lib_model::capture_unknown_fields(req.client().options.capture_unknown_fields, || {
+            parse(body)
+        });
+    #[cfg(not(feature = "unknown-fields"))]
+    let result = parse(body);
+    result.map_err(|source| {
+        let limit = req.client().options.error_body_limit;
+        EndpointError::Deserialization {
+            source,
+            body: truncate_body(body, limit),
+            url: url.to_string(),
+        }
+    })
+}
+
+/// deserializes a model from a response body, or from an entry of a list
+/// with `stream-parse`
+///
+/// Error responses and the comparison of [`Client::strict_schema`] are
+/// parsed into [`serde_json::Value`]s elsewhere.
+#[inline]
+fn parse<T: DeserializeOwned>(body: &[u8]) -> Result<T, serde_json::Error> {
+    serde_json::from_slice(body)
+}
+
+/// compares the top level keys of the response with the keys that `result`
+/// serializes to
+fn check_unknown_fields<K: Serialize>(body: &[u8], result: &K, url: String) -> EndpointResult<()> {
+    let raw: serde_json::Value = serde_json::from_slice(body)?;
+    // e.g. `Projected` values, which no longer match the response
+    let Ok(parsed) = serde_json::to_value(result) else {
+        return Ok(());
+    };
+    let mut fields = BTreeSet::new();
+    collect_unknown_fields(&raw, &parsed, &mut fields);
+    if fields.is_empty() {
+        Ok(())
+    } else {
+        Err(EndpointError::UnknownFields {
+            fields: fields.into_iter().collect(),
+            url,
+        })
+    }
+}
+
+fn collect_unknown_fields(
+    raw: &serde_json::Value,
+    parsed: &serde_json::Value,
+    fields: &mut BTreeSet<String>,
+) {
+    use serde_json::Value;
+    match (raw, parsed) {
+        (Value::Object(raw), Value::Object(parsed)) => {
+            fields.extend(raw.keys().filter(|k| !parsed.contains_key(*k)).cloned());
+        }
+        (Value::Array(raw), Value::Array(parsed)) => {
+            for (raw, parsed) in raw.iter().zip(parsed) {
+                collect_unknown_fields(raw, parsed, fields);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// lossily decodes at most `limit` bytes of `body`
+fn truncate_body(body: &[u8], limit: usize) -> String {
+    let mut body = String::from_utf8_lossy(&body[..body.len().min(limit)]).into_owned();
+    // a multi byte character might have been cut in half
+    if body.len() > limit {
+        let mut end = limit;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        body.truncate(end);
+    }
+    body
+}
+
+/// request url without the access token, attached to responses for error
+/// reporting
+#[derive(Clone)]
+struct RequestUrl(String);
+
+impl RequestUrl {
+    fn new(uri: &Uri) -> Self {
+        let mut url = uri.path().to_string();
+        let query = uri
+            .query()
+            .unwrap_or_default()
+            .split('&')
+            .filter(|x| !x.is_empty() && !x.starts_with("access_token="))
+            .collect::<Vec<_>>()
+            .join("&");
+        if !query.is_empty() {
+            url.push('?');
+            url.push_str(&query);
+        }
+        Self(url)
+    }
+}
+
+fn get_cache_expiry<Req: Requester<A, F>, const A: bool, const F: bool>(
+    req: &Req,
+    response: &Response<hyper::Body>,
+) -> NaiveDateTime {
+    if let Some(at) = req.cache_expiry() {
+        return at;
+    }
+    let duration = req.cache_duration();
+    let mut expires = if !duration.is_zero() {
+        duration
+    } else if let Some(duration) = req.client().options.default_cache_duration {
+        duration
+    } else {
+        get_expire_from_header(response)
+    };
+    if let Some(jitter) = &req.client().options.cache_jitter {
+        expires = jitter.apply(expires);
+    }
+    Utc::now()
+        .naive_utc()
+        .checked_add_signed(expires)
+        .unwrap_or(NaiveDateTime::MAX)
+}
+
+/// the pages left to request after the first one, for `total` items in
+/// pages of `page_size`
+fn remaining_pages(total: usize, page_size: usize) -> std::ops::Range<usize> {
+    1..total.div_ceil(page_size).max(1)
+}
+
+/// concatenates ids, separated by comma: 1,2,3,4
+/// chunked in `chunk_size` per batch
+fn join_ids<I: Display + 'static>(ids: &[I], chunk_size: usize) -> Vec<String> {
+    use std::fmt::Write;
+    let mut result = Vec::with_capacity(ids.len().div_ceil(chunk_size));
+    for ids in ids.chunks(chunk_size) {
+        let mut query_string = String::with_capacity(6 * ids.len()); // arbitrary. most ids are 5 digits + comma
+        write!(&mut query_string, "{}", ids[0]).expect("failed to concatenate ids");
+        for i in ids.iter().skip(1) {
+            write!(&mut query_string, ",{i}").expect("failed to concatenate ids");
+        }
+        result.push(query_string);
+    }
+    result
+}
+
+fn get_expire_from_header(response: &Response<hyper::Body>) -> Duration {
+    let exp = get_header::<String>(response, CACHE_CONTROL)
+        .and_then(|h| parse_max_age(&h))
+        .unwrap_or(300);
+    Duration::seconds(exp)
+}
+
+/// extracts `max-age` from a `cache-control` header value like `public,
+/// max-age=300`
+fn parse_max_age(value: &str) -> Option<i64> {
+    value.split(',').find_map(|directive| {
+        let (key, value) = directive.split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case("max-age")
+            .then(|| value.trim().trim_matches('"').parse().ok())
+            .flatten()
+    })
+}
+
+/// whether the `cache-control` header forbids storing the response
+///
+/// `private` is not checked, it only concerns caches shared between users.
+fn is_no_store(response: &Response<hyper::Body>) -> bool {
+    get_header::<String>(response, CACHE_CONTROL).is_some_and(|value| {
+        value
+            .split(',')
+            .any(|directive| directive.trim().eq_ignore_ascii_case("no-store"))
+    })
+}
+
+fn get_header<T: FromStr>(
+    response: &Response<hyper::Body>,
+    header: impl AsHeaderName,
+) -> Option<T> {
+    response
+        .headers()
+        .get(header)
+        .and_then(|d| d.to_str().ok())
+        .and_then(|d| d.trim().parse::<T>().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use gw2lib_model::EndpointInfo;
+
+    use super::*;
+
+    fn response_with(name: &str, value: &str) -> Response<hyper::Body> {
+        Response::builder()
+            .header(name, value)
+            .body(hyper::Body::empty())
+            .unwrap()
+    }
+
+    /// a minimal bulk endpoint, for tests that don't need a real model
+    #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Thing {
+        id: u32,
+        #[serde(default)]
+        name: String,
+    }
+
+    impl Endpoint for Thing {
+        const AUTHENTICATED: bool = false;
+        const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+        const LOCALE: bool = false;
+        const URL: &'static str = "v2/things";
+        const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+    }
+
+    impl EndpointWithId for Thing {
+        type IdType = u32;
+    }
+
+    impl BulkEndpoint for Thing {
+        const ALL: bool = false;
+
+        fn id(&self) -> &Self::IdType {
+            &self.id
+        }
+    }
+
+    fn thing(id: u32) -> Thing {
+        Thing {
+            id,
+            ..Default::default()
+        }
+    }
+
+    /// a minimal item, as returned by `v2/items`
+    #[cfg(feature = "items")]
+    fn item_json(id: u32) -> serde_json::Value {
+        serde_json::json!({
+            "id": id, "chat_link": "", "name": "", "rarity": "Basic", "level": 0,
+            "vendor_value": 0, "flags": [], "game_types": [], "restrictions": [],
+            "type": "Trophy"
+        })
+    }
+
+    #[test]
+    fn header_lookup_ignores_case() {
+        let response = response_with("X-Result-Total", "42");
+        assert_eq!(get_header::<usize>(&response, "x-result-total"), Some(42));
+        assert_eq!(get_header::<usize>(&response, "X-RESULT-TOTAL"), Some(42));
+    }
+
+    #[test]
+    fn missing_or_invalid_header() {
+        let response = response_with("X-Result-Total", "many");
+        assert_eq!(get_header::<usize>(&response, "x-result-total"), None);
+        assert_eq!(get_header::<usize>(&response, "x-page-total"), None);
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn cancelled_request() {
+        use gw2lib_model::misc::build::Build;
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let client = Client::empty();
+            for _ in 0..2 {
+                let token = CancellationToken::new();
+                token.cancel();
+                let req = client.with_cancellation(token);
+                let result: EndpointResult<Build> = Requester::get(&req).await;
+                assert!(matches!(result, Err(EndpointError::Cancelled)));
+            }
+        });
+    }
+
+    #[test]
+    fn cancelled_while_reading_body() {
+        use std::time::Duration;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| {
+            MockResponse::json(serde_json::to_string(&thing(1)).unwrap())
+                .body_delay(Duration::from_millis(500))
+        });
+        run(async {
+            let client = api.client();
+            let token = CancellationToken::new();
+            let req = client.with_cancellation(token.clone());
+            let start = Instant::now();
+            let (result, ()) =
+                futures::join!(Requester::single::<Thing, u32>(&req, 1_u32), async {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    token.cancel();
+                });
+            assert!(matches!(result, Err(EndpointError::Cancelled)));
+            assert!(start.elapsed() < Duration::from_millis(500));
+
+            // the cancelled request doesn't block the id
+            let result = Requester::single::<Thing, u32>(&client, 1_u32).await;
+            assert_eq!(result.unwrap(), thing(1));
+        });
+        assert_eq!(api.requests().len(), 2);
+    }
+
+    #[test]
+    fn cancelled_while_waiting_for_inflight() {
+        use std::time::Duration;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| {
+            MockResponse::json(serde_json::to_string(&thing(1)).unwrap())
+                .delay(Duration::from_millis(500))
+        });
+        run(async {
+            let client = api.client();
+            let token = CancellationToken::new();
+            let req = client.with_cancellation(token.clone());
+            let waiter = async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                let start = Instant::now();
+                let result = Requester::single::<Thing, u32>(&req, 1_u32).await;
+                (result, start.elapsed())
+            };
+            let cancel = async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                token.cancel();
+            };
+            let (leader, (waiter, elapsed), ()) = futures::join!(
+                Requester::single::<Thing, u32>(&client, 1_u32),
+                waiter,
+                cancel
+            );
+            assert_eq!(leader.unwrap(), thing(1));
+            assert!(matches!(waiter, Err(EndpointError::Cancelled)));
+            assert!(elapsed < Duration::from_millis(500));
+        });
+        assert_eq!(api.requests().len(), 1);
+    }
+
+    #[test]
+    fn cancelled_many() {
+        use std::time::Duration;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| {
+            let things: Vec<Thing> = request
+                .query("ids")
+                .unwrap()
+                .split("%2C")
+                .flat_map(|ids| ids.split(','))
+                .map(|id| thing(id.parse().unwrap()))
+                .collect();
+            MockResponse::json(serde_json::to_string(&things).unwrap())
+                .body_delay(Duration::from_millis(500))
+        });
+        run(async {
+            let client = api.client().bulk_chunk_size(2);
+            let token = CancellationToken::new();
+            let req = client.with_cancellation(token.clone());
+            let start = Instant::now();
+            let (result, ()) = futures::join!(
+                Requester::many::<Thing, u32>(&req, (1..=6_u32).collect()),
+                async {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    token.cancel();
+                }
+            );
+            assert!(matches!(result, Err(EndpointError::Cancelled)));
+            assert!(start.elapsed() < Duration::from_millis(500));
+
+            let mut things = Requester::many::<Thing, u32>(&client, (1..=6_u32).collect())
+                .await
+                .unwrap();
+            things.sort_by_key(|t| t.id);
+            assert_eq!(things, (1..=6).map(thing).collect::<Vec<_>>());
+        });
+        assert_eq!(api.requests().len(), 6);
+    }
+
+    #[cfg(feature = "items")]
+    #[test]
+    fn metrics_on_cache_lookup() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use gw2lib_model::items::Item;
+
+        use crate::{cache::InMemoryCache, metrics::Metrics};
+
+        #[derive(Default)]
+        struct Lookups {
+            hits: AtomicUsize,
+            misses: AtomicUsize,
+        }
+
+        impl Metrics for Lookups {
+            fn on_cache_hit(&self, endpoint: &str) {
+                assert_eq!(endpoint, Item::URL);
+                self.hits.fetch_add(1, Ordering::Relaxed);
+            }
+
+            fn on_cache_miss(&self, endpoint: &str) {
+                assert_eq!(endpoint, Item::URL);
+                self.misses.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let metrics = Arc::new(Lookups::default());
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let client = Client::empty()
+                .cache(Arc::new(InMemoryCache::default()))
+                .metrics(metrics.clone());
+            let id = 19721;
+            assert!(
+                check_cache::<String, u32, Item, _, false, false>(&client, &id)
+                    .await
+                    .is_none()
+            );
+            let expires = (Utc::now() + Duration::hours(1)).naive_utc();
+            client
+                .cache
+                .insert::<String, u32, Item, String>(
+                    &id,
+                    &"cached".to_string(),
+                    expires,
+                    client.language,
+                    &None,
+                )
+                .await;
+            assert!(
+                check_cache::<String, u32, Item, _, false, false>(&client, &id)
+                    .await
+                    .is_some()
+            );
+        });
+        assert_eq!(metrics.hits.load(Ordering::Relaxed), 1);
+        assert_eq!(metrics.misses.load(Ordering::Relaxed), 1);
+    }
+
+    #[cfg(feature = "items")]
+    #[test]
+    fn ids_sorted() {
+        use gw2lib_model::items::Item;
+
+        use crate::cache::InMemoryCache;
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let client = Client::empty().cache(Arc::new(InMemoryCache::default()));
+            let expires = (Utc::now() + Duration::hours(1)).naive_utc();
+            client
+                .cache
+                .insert::<Vec<u32>, str, Item, String>(
+                    "",
+                    &vec![24, 19721, 2, 68],
+                    expires,
+                    client.language,
+                    &None,
+                )
+                .await;
+            let ids = Requester::ids_sorted::<Item, u32>(&client).await.unwrap();
+            assert_eq!(ids, [2, 24, 68, 19721]);
+        });
+    }
+
+    #[cfg(feature = "items")]
+    #[test]
+    fn ids_do_not_fetch_items() {
+        use gw2lib_model::items::Item;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let body = serde_json::to_string(&(1..=60_000).collect::<Vec<u32>>()).unwrap();
+        let api = MockApi::new(move |_| MockResponse::json(body.clone()));
+        run(async {
+            let client = api.client();
+            let ids = Requester::ids::<Item, u32>(&client).await.unwrap();
+            assert_eq!(ids.len(), 60_000);
+        });
+        let requests = api.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].query("ids"), None);
+    }
+
+    #[cfg(feature = "items")]
+    #[test]
+    fn expiring_entries() {
+        use gw2lib_model::items::Item;
+
+        use crate::cache::InMemoryCache;
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let cache = InMemoryCache::default();
+            let now = Utc::now().naive_utc();
+            for (id, expires) in [(1u32, 1), (2, 10), (3, 60)] {
+                cache
+                    .insert::<u32, u32, Item, String>(
+                        &id,
+                        &id,
+                        now + Duration::minutes(expires),
+                        Language::En,
+                        &None,
+                    )
+                    .await;
+            }
+            cache
+                .insert::<String, u32, Item, String>(
+                    &4,
+                    &"other type".to_string(),
+                    now,
+                    Language::En,
+                    &None,
+                )
+                .await;
+
+            let mut expiring: Vec<u32> = cache
+                .expiring::<u32, Item>(now + Duration::minutes(15))
+                .await
+                .into_iter()
+                .map(|x| *x)
+                .collect();
+            expiring.sort_unstable();
+            assert_eq!(expiring, [1, 2]);
+        });
+    }
+
+    #[cfg(feature = "stream-parse")]
+    #[test]
+    fn stream_parse_across_chunks() {
+        let split = |chunks: &[&[u8]]| {
+            let mut list = stream_parse::ListSplitter::default();
+            let mut entries = Vec::new();
+            for chunk in chunks {
+                // only the incomplete entry is kept
+                let kept = list.rest().len();
+                list.push(chunk);
+                assert_eq!(list.buffer.len(), kept + chunk.len());
+                while let Some(entry) = list.next_entry()? {
+                    entries.push(serde_json::from_slice::<serde_json::Value>(entry).unwrap());
+                }
+            }
+            list.finish().map(|()| entries)
+        };
+
+        let chunks: [&[u8]; 5] = [
+            b" [{\"id\": 1, \"name\": \"a,]\\\"}\"},",
+            b"",
+            b" {\"i",
+            b"d\": 2, \"tags\": [[], {}]}",
+            b"] ",
+        ];
+        assert_eq!(
+            split(&chunks).unwrap(),
+            [
+                serde_json::json!({ "id": 1, "name": "a,]\"}" }),
+                serde_json::json!({ "id": 2, "tags": [[], {}] }),
+            ]
+        );
+        assert_eq!(
+            split(&[b"[", b" ]"]).unwrap(),
+            Vec::<serde_json::Value>::new()
+        );
+        assert!(split(&[b"{\"id\": 1}"]).is_err());
+        assert!(split(&[b"[{\"id\": 1}"]).is_err());
+        assert!(split(&[b"[1] 2"]).is_err());
+    }
+
+    #[cfg(feature = "stream-parse")]
+    #[test]
+    fn stream_parse_many() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| {
+            MockResponse::json(serde_json::to_string(&[thing(1), thing(2)]).unwrap())
+        });
+        run(async {
+            let client = api.client();
+            let things: Vec<Thing> = Requester::many(&client, vec![1_u32, 2]).await.unwrap();
+            assert_eq!(things, [thing(1), thing(2)]);
+        });
+
+        let api = MockApi::new(|_| MockResponse::json(r#"[{"id": 1, "size": 2}]"#));
+        run(async {
+            let client = api.client().strict_schema(true);
+            let error = Requester::many::<Thing, u32>(&client, vec![1_u32]).await;
+            assert!(matches!(
+                error,
+                Err(EndpointError::UnknownFields { fields, .. }) if fields == ["size"]
+            ));
+        });
+    }
+
+    #[cfg(all(feature = "unknown-fields", feature = "misc"))]
+    #[test]
+    fn capture_unknown_fields() {
+        use gw2lib_model::misc::build::Build;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| MockResponse::json(r#"{"id": 1, "new": [2]}"#));
+        run(async {
+            let build: Build = Requester::get(&api.client()).await.unwrap();
+            assert_eq!(build.id, 1);
+            assert!(build.extra.is_empty());
+
+            let client = api
+                .client()
+                .capture_unknown_fields(true)
+                .strict_schema(true);
+            let build: Build = Requester::get(&client).await.unwrap();
+            assert_eq!(build.extra.len(), 1);
+            assert_eq!(build.extra["new"], serde_json::json!([2]));
+        });
+    }
+
+    /// peak memory and time of parsing a response of 20k items, about the
+    /// size of `v2/items?ids=all`
+    ///
+    /// Linux only. Run it on its own in release mode, once with and once
+    /// without `stream-parse`:
+    /// `cargo test --release --lib -- --ignored --nocapture parse_20k_items`
+    #[cfg(feature = "items")]
+    #[test]
+    #[ignore]
+    fn parse_20k_items() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        fn status_kb(field: &str) -> u64 {
+            let status = std::fs::read_to_string("/proc/self/status").unwrap();
+            let line = status.lines().find_map(|l| l.strip_prefix(field)).unwrap();
+            line.trim().trim_end_matches(" kB").parse().unwrap()
+        }
+
+        let api = MockApi::new(|_| MockResponse::json(""));
+        run(async {
+            let client = api.client();
+            let (mut sender, body) = hyper::Body::channel();
+            // the body arrives in chunks, without being held in memory as a
+            // whole
+            tokio::spawn(async move {
+                let mut chunk = b"[".to_vec();
+                for id in 0..20_000 {
+                    let mut item = item_json(id);
+                    item["name"] = "a sizeable name".repeat(60).into();
+                    serde_json::to_writer(&mut chunk, &item).unwrap();
+                    chunk.push(if id < 19_999 { b',' } else { b']' });
+                    if chunk.len() > 16 * 1024 || id == 19_999 {
+                        let data = Bytes::from(std::mem::take(&mut chunk));
+                        sender.send_data(data).await.unwrap();
+                    }
+                }
+            });
+
+            std::fs::write("/proc/self/clear_refs", "5").unwrap();
+            let before = status_kb("VmRSS:");
+            let start = Instant::now();
+            let mut items: Vec<Item> = Vec::new();
+            parse_response_into(&client, Response::new(body), &mut items)
+                .await
+                .unwrap();
+            let elapsed = start.elapsed();
+            assert_eq!(items.len(), 20_000);
+            println!(
+                "parsed {} items in {elapsed:?}, peak rss +{} kB",
+                items.len(),
+                status_kb("VmHWM:") - before
+            );
+        });
+    }
+
+    #[test]
+    fn join_ids_in_chunks() {
+        assert_eq!(join_ids(&[1, 2, 3, 4, 5], 2), ["1,2", "3,4", "5"]);
+        assert_eq!(join_ids(&[1, 2, 3, 4], 2), ["1,2", "3,4"]);
+        assert_eq!(join_ids(&(0..401).collect::<Vec<_>>(), 200).len(), 3);
+        assert!(join_ids::<u32>(&[], 200).is_empty());
+    }
+
+    #[test]
+    fn paging_requests_exact_pages() {
+        for (total, pages) in [
+            (0, 1..1),
+            (1, 1..1),
+            (199, 1..1),
+            (200, 1..1),
+            (201, 1..2),
+            (400, 1..2),
+            (401, 1..3),
+        ] {
+            assert_eq!(remaining_pages(total, 200), pages, "{total} items");
+        }
+        assert_eq!(remaining_pages(5, 2), 1..3);
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn paging_keeps_order() {
+        use gw2lib_model::misc::files::File;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| {
+            let page: u64 = request.query("page").unwrap().parse().unwrap();
+            let body = serde_json::json!([
+                { "id": format!("{}", page * 2), "icon": "" },
+                { "id": format!("{}", page * 2 + 1), "icon": "" },
+            ]);
+            // later pages finish first
+            MockResponse::json(body.to_string())
+                .header("x-result-total", 8)
+                .delay(std::time::Duration::from_millis(40 - page * 10))
+        });
+        let files: Vec<File> = run(async {
+            let client = api.client().bulk_chunk_size(2).max_concurrency(4);
+            Requester::get_all_by_paging(&client).await.unwrap()
+        });
+        let ids: Vec<_> = files.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(ids, ["0", "1", "2", "3", "4", "5", "6", "7"]);
+        assert_eq!(api.requests().len(), 4);
+    }
+
+    #[cfg(all(feature = "items", feature = "misc"))]
+    #[test]
+    fn concurrent_all_requests_once() {
+        use gw2lib_model::{items::Item, misc::worlds::World};
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| {
+            let body = if request.url.starts_with("/v2/worlds") {
+                r#"[{"id": 1001, "name": "Anvil Rock", "population": "High"}]"#.to_string()
+            } else if let Some(ids) = request.query("ids") {
+                let items: Vec<_> = ids
+                    .split(',')
+                    .map(|id| item_json(id.parse().unwrap()))
+                    .collect();
+                serde_json::to_string(&items).unwrap()
+            } else {
+                "[1, 2, 3]".to_string()
+            };
+            MockResponse::json(body).delay(std::time::Duration::from_millis(20))
+        });
+        run(async {
+            let client = api.client();
+            let (first, second) = futures::join!(
+                Requester::all::<World, _>(&client),
+                Requester::all::<World, _>(&client),
+            );
+            assert_eq!(first.unwrap(), second.unwrap());
+
+            let (first, second) = futures::join!(
+                Requester::all::<Item, _>(&client),
+                Requester::all::<Item, _>(&client),
+            );
+            assert_eq!(first.unwrap().len(), 3);
+            assert_eq!(second.unwrap().len(), 3);
+        });
+        let requests: Vec<_> = api
+            .requests()
+            .iter()
+            .map(|x| x.url.split('?').next().unwrap().to_string())
+            .collect();
+        assert_eq!(requests, ["/v2/worlds", "/v2/items", "/v2/items"]);
+    }
+
+    #[test]
+    fn forced_all_is_not_shared() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let fetched = AtomicU32::new(0);
+        let api = MockApi::new(move |request| {
+            let delay = std::time::Duration::from_millis(20);
+            if request.query("ids").is_none() {
+                return MockResponse::json("[1]")
+                    .header("cache-control", "no-store")
+                    .delay(delay);
+            }
+            let name = fetched.fetch_add(1, Ordering::SeqCst).to_string();
+            MockResponse::json(serde_json::to_string(&[Thing { id: 1, name }]).unwrap())
+                .header("cache-control", "max-age=300")
+                .delay(delay)
+        });
+        run(async {
+            let client = api.client();
+            let _: Vec<Thing> = Requester::all(&client).await.unwrap();
+
+            // the ids are requested again, while the things are cached
+            let forced = client.forced();
+            let (cached, forced) = futures::join!(
+                Requester::all::<Thing, u32>(&client),
+                Requester::all::<Thing, u32>(&forced),
+            );
+            assert_eq!(cached.unwrap()[0].name, "0");
+            assert_eq!(forced.unwrap()[0].name, "1");
+        });
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn page_with_info_reads_headers() {
+        use gw2lib_model::misc::files::File;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| {
+            let body = match request.query("page") {
+                Some("2") => r#"[{"id": "4", "icon": ""}]"#,
+                _ => r#"[{"id": "0", "icon": ""}, {"id": "1", "icon": ""}]"#,
+            };
+            let response = MockResponse::json(body).header("x-result-total", 5);
+            if request.query("page") == Some("2") {
+                return response;
+            }
+            response
+                .header("x-page-total", 3)
+                .header("x-page-size", 2)
+                .header("x-result-count", 2)
+        });
+        let (info, fallback, files) = run(async {
+            let client = api.client();
+            let mut files: Vec<File> = Vec::new();
+            let info = Requester::page_with_info(&client, 0, 2, &mut files)
+                .await
+                .unwrap();
+            let fallback = Requester::page_with_info(&client, 2, 2, &mut files)
+                .await
+                .unwrap();
+            (info, fallback, files)
+        });
+        assert_eq!(
+            info,
+            PageInfo {
+                total_items: 5,
+                total_pages: 3,
+                page_size: 2,
+                result_count: 2,
+            }
+        );
+        assert_eq!(
+            fallback,
+            PageInfo {
+                total_items: 5,
+                total_pages: 3,
+                page_size: 2,
+                result_count: 1,
+            }
+        );
+        assert_eq!(files.len(), 3);
+    }
+
+    #[test]
+    fn debug_redacts_api_key() {
+        let client = Client::empty().api_key("secret-key");
+        let debug = format!("{client:?}");
+        assert!(!debug.contains("secret-key"));
+        assert!(debug.contains("***"));
+        assert!(debug.contains("https://api.guildwars2.com"));
+
+        let debug = format!("{:?}", client.cached(Duration::minutes(1)).clone());
+        assert!(!debug.contains("secret-key"));
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn clones_share_the_cache() {
+        use gw2lib_model::misc::files::File;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| MockResponse::json(r#"{"id": "map_complete", "icon": ""}"#));
+        run(async {
+            let client = api.client();
+            let clone = client.clone().language(Language::De);
+            let _: File = Requester::single(&clone, "map_complete".to_string())
+                .await
+                .unwrap();
+            let cached: Option<File> =
+                Requester::try_get(&client, &"map_complete".to_string()).await;
+            assert!(cached.is_some());
+        });
+        assert_eq!(api.requests().len(), 1);
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn revalidate_with_if_modified_since() {
+        use gw2lib_model::misc::build::Build;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        const LAST_MODIFIED: &str = "Wed, 14 Oct 2026 08:00:00 GMT";
+        let api = MockApi::new(|request| match request.header("if-modified-since") {
+            Some(since) => {
+                assert_eq!(since, LAST_MODIFIED);
+                MockResponse::json("").status(304)
+            }
+            None => MockResponse::json(r#"{"id": 1}"#)
+                .header("cache-control", "max-age=0")
+                .header("last-modified", LAST_MODIFIED),
+        });
+        run(async {
+            let client = api.client().revalidate(true);
+            for _ in 0..2 {
+                let build: Build = Requester::get(&client).await.unwrap();
+                assert_eq!(build.id, 1);
+            }
+        });
+        let requests = api.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].header("if-modified-since"), Some(LAST_MODIFIED));
+    }
+
+    #[test]
+    fn revalidation_has_a_key_of_its_own() {
+        use crate::{
+            cache::{Cache, DynamicCache},
+            mock::{run, JsonCache, MockApi, MockResponse},
+        };
+
+        let api = MockApi::new(|_| {
+            MockResponse::json(r#"{"id": 1}"#)
+                .header("cache-control", "max-age=300")
+                .header("last-modified", "Wed, 14 Oct 2026 08:00:00 GMT")
+        });
+        let json = Arc::new(JsonCache::default());
+        run(async {
+            let client = api
+                .client()
+                .cache(Arc::new(DynamicCache::from_arc(json.clone())))
+                .revalidate(true);
+            let _: Thing = Requester::single(&client, 1_u32).await.unwrap();
+            assert_eq!(Requester::try_get(&client, &1_u32).await, Some(thing(1)));
+
+            // in memory, the revalidation shares the cached value
+            let client = api.client().revalidate(true);
+            let _: Thing = Requester::single(&client, 1_u32).await.unwrap();
+            let cached = client
+                .cache
+                .get_arc::<Thing, u32, Thing, String>(&1, Language::En, &client.identifier)
+                .await
+                .unwrap();
+            let revalidation = client
+                .cache
+                .get::<Revalidation<Thing>, InternalId, Thing, String>(
+                    &InternalId::revalidation(&1),
+                    Language::En,
+                    &client.identifier,
+                )
+                .await
+                .unwrap();
+            assert!(Arc::ptr_eq(&cached, &revalidation.value));
+        });
+        let keys: Vec<_> = json.entries().into_iter().map(|(key, _)| key).collect();
+        assert_eq!(
+            keys,
+            [
+                "gw2lib_static_v2/things_1",
+                "gw2lib_static_v2/things_1#revalidation"
+            ]
+        );
+    }
+
+    #[cfg(feature = "items")]
+    #[test]
+    fn in_language_caches_separately() {
+        use gw2lib_model::items::Item;
+
+        use crate::{
+            cache::Cache,
+            mock::{run, MockApi, MockResponse},
+        };
+
+        let api = MockApi::new(|request| {
+            let name = match request.query("lang") {
+                Some("de") => "Gabe der Erforschung",
+                _ => "Gift of Exploration",
+            };
+            let mut item = item_json(19677);
+            item["name"] = name.into();
+            MockResponse::json(item.to_string())
+        });
+        run(async {
+            let client = api.client();
+            let en: Item = Requester::single(&client, 19677_u32).await.unwrap();
+            let de: Item = Requester::single(&client.in_language(Language::De), 19677_u32)
+                .await
+                .unwrap();
+            assert_eq!(en.name, "Gift of Exploration");
+            assert_eq!(de.name, "Gabe der Erforschung");
+
+            for (lang, name) in [
+                (Language::En, "Gift of Exploration"),
+                (Language::De, "Gabe der Erforschung"),
+            ] {
+                let cached = client
+                    .cache
+                    .get::<Item, u32, Item, String>(&19677, lang, &None)
+                    .await
+                    .unwrap();
+                assert_eq!(cached.name, name);
+            }
+        });
+        let requests = api.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].query("lang"), Some("en"));
+        assert_eq!(requests[1].query("lang"), Some("de"));
+    }
+
+    #[cfg(all(feature = "items", feature = "commerce"))]
+    #[test]
+    fn items_with_prices_of_untradeable_items() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        // 1 is tradeable, 2 is account bound
+        let api = MockApi::new(|request| {
+            let path = request.url.split('?').next().unwrap_or_default();
+            let ids: Vec<u32> = match (path.rsplit_once('/'), request.query("ids")) {
+                (_, Some(ids)) => ids.split(',').map(|id| id.parse().unwrap()).collect(),
+                (Some((_, id)), None) => vec![id.parse().unwrap()],
+                _ => unreachable!(),
+            };
+            let single = request.query("ids").is_none();
+            let body: Vec<_> = if path.starts_with("/v2/commerce/prices") {
+                ids.into_iter()
+                    .filter(|&id| id == 1)
+                    .map(|id| {
+                        serde_json::json!({
+                            "id": id, "whitelisted": false,
+                            "buys": {"unit_price": 10, "quantity": 1},
+                            "sells": {"unit_price": 20, "quantity": 2}
+                        })
+                    })
+                    .collect()
+            } else {
+                ids.into_iter().map(item_json).collect()
+            };
+            match (single, body.as_slice()) {
+                (_, []) => {
+                    MockResponse::json(r#"{"text": "all ids provided are invalid"}"#).status(404)
+                }
+                (true, [x]) => MockResponse::json(x.to_string()),
+                _ => MockResponse::json(serde_json::to_string(&body).unwrap()),
+            }
+        });
+        run(async {
+            let client = api.client();
+            let (item, price) = Requester::item_with_price(&client, 1).await.unwrap();
+            assert_eq!(item.id, 1);
+            assert_eq!(price.unwrap().sells.unit_price, 20);
+            let (item, price) = Requester::item_with_price(&client, 2).await.unwrap();
+            assert_eq!(item.id, 2);
+            assert!(price.is_none());
+
+            let client = api.client();
+            let items = Requester::items_with_prices(&client, vec![2, 1])
+                .await
+                .unwrap();
+            let items: Vec<_> = items
+                .iter()
+                .map(|(item, price)| (item.id, price.as_ref().map(|x| x.buys.unit_price)))
+                .collect();
+            assert_eq!(items, [(2, None), (1, Some(10))]);
+
+            let items = Requester::items_with_prices(&api.client(), vec![2])
+                .await
+                .unwrap();
+            assert_eq!(items.len(), 1);
+            assert!(items[0].1.is_none());
+        });
+    }
+
+    #[cfg(feature = "items")]
+    #[test]
+    fn upgrade_chain_follows_links() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        // 1 attunes into 2, which infuses into 3
+        let api = MockApi::new(|request| {
+            let items: Vec<_> = request
+                .query("ids")
+                .unwrap()
+                .split(',')
+                .map(|id| {
+                    let id = id.parse::<u32>().unwrap();
+                    let link =
+                        |upgrade, id| serde_json::json!([{"upgrade": upgrade, "item_id": id}]);
+                    let (into, from) = match id {
+                        1 => (link("Attunement", 2), serde_json::Value::Null),
+                        2 => (link("Infusion", 3), link("Attunement", 1)),
+                        _ => (serde_json::Value::Null, link("Infusion", 2)),
+                    };
+                    let mut item = item_json(id);
+                    item["upgrades_into"] = into;
+                    item["upgrades_from"] = from;
+                    item
+                })
+                .collect();
+            MockResponse::json(serde_json::to_string(&items).unwrap())
+        });
+        let (chain, again) = run(async {
+            let client = api.client();
+            let chain = Requester::upgrade_chain(&client, 2).await.unwrap();
+            let again = Requester::upgrade_chain(&client, 1).await.unwrap();
+            (chain, again)
+        });
+        let ids: Vec<ItemId> = chain.iter().map(|item| item.id).collect();
+        assert_eq!(ids, [2, 1, 3]);
+        let ids: Vec<ItemId> = again.iter().map(|item| item.id).collect();
+        assert_eq!(ids, [1, 2, 3]);
+
+        let requested: Vec<_> = api
+            .requests()
+            .iter()
+            .map(|x| x.query("ids").unwrap().to_string())
+            .collect();
+        assert_eq!(requested, ["2", "3,1"]);
+    }
+
+    #[cfg(feature = "characters")]
+    #[test]
+    fn resolved_legendary_armory_joins_items() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| {
+            let path = request.url.split('?').next().unwrap_or_default();
+            match path.trim_start_matches('/') {
+                "v2/account/legendaryarmory" => {
+                    MockResponse::json(r#"[{"id": 80111, "count": 2}, {"id": 30684, "count": 1}]"#)
+                }
+                "v2/items" => {
+                    let items: Vec<_> = request
+                        .query("ids")
+                        .unwrap()
+                        .split(',')
+                        .map(|id| item_json(id.parse().unwrap()))
+                        .collect();
+                    MockResponse::json(serde_json::to_string(&items).unwrap())
+                }
+                _ => MockResponse::json("{}").status(404),
+            }
+        });
+        let armory = run(async {
+            let client = api.client().api_key("key");
+            Requester::resolved_legendary_armory(&client).await.unwrap();
+            Requester::resolved_legendary_armory(&client).await.unwrap()
+        });
+        let armory: Vec<_> = armory
+            .iter()
+            .map(|(item, count)| (item.id, *count))
+            .collect();
+        assert_eq!(armory, [(30684, 1), (80111, 2)]);
+
+        let items = api
+            .requests()
+            .iter()
+            .filter(|x| x.url.contains("v2/items"))
+            .count();
+        assert_eq!(items, 1);
+    }
+
+    #[cfg(feature = "achievements")]
+    #[test]
+    fn daily_progress_marks_completed() {
+        use gw2lib_model::daily_rewards::DailyEntry;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| {
+            let path = request.url.split('?').next().unwrap_or_default();
+            let body = match path.trim_start_matches('/') {
+                "v2/worldbosses" => {
+                    r#"[{"id": "admiral_taidha_covington"}, {"id": "shadow_behemoth"}]"#
+                }
+                "v2/dailycrafting" => r#"[{"id": "charged_quartz_crystal"}]"#,
+                "v2/mapchests" => r#"[{"id": "auric_basin_heros_choice_chest"}]"#,
+                "v2/account/worldbosses" => r#"["shadow_behemoth"]"#,
+                "v2/account/dailycrafting" => r#"["charged_quartz_crystal"]"#,
+                "v2/account/mapchests" => "[]",
+                _ => return MockResponse::json("{}").status(404),
+            };
+            MockResponse::json(body)
+        });
+        let progress = run(async {
+            let client = api.client().api_key("key");
+            Requester::daily_progress(&client).await.unwrap()
+        });
+        fn completed(entries: &[DailyEntry]) -> Vec<(&str, bool)> {
+            entries
+                .iter()
+                .map(|x| (x.id.as_str(), x.completed))
+                .collect()
+        }
+        assert_eq!(
+            completed(&progress.world_bosses),
+            [
+                ("admiral_taidha_covington", false),
+                ("shadow_behemoth", true)
+            ]
+        );
+        assert_eq!(
+            completed(&progress.daily_crafting),
+            [("charged_quartz_crystal", true)]
+        );
+        assert_eq!(
+            completed(&progress.map_chests),
+            [("auric_basin_heros_choice_chest", false)]
+        );
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn cached_until_reset_expires_at_reset() {
+        use chrono::TimeZone;
+        use gw2lib_model::misc::build::Build;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| {
+            MockResponse::json(r#"{"id": 115267}"#).header("cache-control", "max-age=300")
+        });
+        run(async {
+            let client = api.client();
+            let before_reset = Utc.with_ymd_and_hms(2100, 1, 1, 23, 59, 0).unwrap();
+            let req = client.cached_until(reset::next_daily_reset(before_reset));
+            let _: Build = Requester::get(&req).await.unwrap();
+
+            let (_, expires) = client
+                .cache
+                .get_with_expiry::<Build, str, Build, String>("", client.language, &None)
+                .await
+                .unwrap();
+            let reset = Utc.with_ymd_and_hms(2100, 1, 2, 0, 0, 0).unwrap();
+            assert_eq!(expires, reset.naive_utc());
+        });
+    }
+
+    #[test]
+    fn single_or_default_on_not_found() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| match request.url.as_str() {
+            url if url.contains("/1?") => MockResponse::json(r#"{"id": 1}"#),
+            url if url.contains("/2?") => {
+                MockResponse::json(r#"{"text": "no such id"}"#).status(404)
+            }
+            _ => MockResponse::json(r#"{"text": "internal error"}"#).status(500),
+        });
+        run(async {
+            let client = api.client();
+            let found: Thing = Requester::single_or_default(&client, 1_u32).await.unwrap();
+            assert_eq!(found, thing(1));
+            let missing: Thing = Requester::single_or_default(&client, 2_u32).await.unwrap();
+            assert_eq!(missing, Thing::default());
+            let failed: EndpointResult<Thing> = Requester::single_or_default(&client, 3_u32).await;
+            assert!(matches!(
+                failed,
+                Err(EndpointError::ApiError(ApiError::Other(..)))
+            ));
+            let missing: EndpointResult<Thing> = Requester::single(&client, 2_u32).await;
+            assert!(matches!(
+                missing,
+                Err(EndpointError::ApiError(ApiError::NotFound))
+            ));
+        });
+    }
+
+    #[test]
+    fn single_with_source() {
+        use std::time::Duration;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api =
+            MockApi::new(|_| MockResponse::json(r#"{"id": 1}"#).delay(Duration::from_millis(50)));
+        run(async {
+            let client = api.client();
+            let (network, inflight) = futures::join!(
+                Requester::single_with_source::<Thing, u32>(&client, 1_u32),
+                async {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    Requester::single_with_source::<Thing, u32>(&client, 1_u32).await
+                },
+            );
+            assert_eq!(network.unwrap(), (thing(1), DataSource::Network));
+            assert_eq!(inflight.unwrap(), (thing(1), DataSource::Inflight));
+            let cached = Requester::single_with_source::<Thing, u32>(&client, 1_u32).await;
+            assert_eq!(cached.unwrap(), (thing(1), DataSource::Cache));
+        });
+        assert_eq!(api.requests().len(), 1);
+    }
+
+    #[test]
+    fn no_store_leaves_cache_untouched() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|req| match req.query("ids") {
+            Some(_) => MockResponse::json(r#"[{"id": 2}, {"id": 3}]"#),
+            None => MockResponse::json(r#"{"id": 1}"#),
+        });
+        run(async {
+            let client = api.client();
+            let cached = |id: &'static u32| Requester::try_get::<Thing, u32>(&client, id);
+
+            let found: Thing = Requester::single(&client.forced_no_store(), 1_u32)
+                .await
+                .unwrap();
+            assert_eq!(found, thing(1));
+            assert_eq!(cached(&1).await, None);
+            let things: Vec<Thing> = Requester::many(&client.no_store(), vec![2_u32, 3])
+                .await
+                .unwrap();
+            assert_eq!(things.len(), 2);
+            assert_eq!(cached(&2).await, None);
+            assert_eq!(api.requests().len(), 2);
+
+            // reading the cache still works without storing
+            let _: Thing = Requester::single(&client, 1_u32).await.unwrap();
+            let _: Thing = Requester::single(&client.no_store(), 1_u32).await.unwrap();
+            assert_eq!(api.requests().len(), 3);
+        });
+    }
+
+    #[test]
+    fn no_store_header_skips_cache() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|req| match req.query("ids") {
+            Some(_) => MockResponse::json(r#"[{"id": 2}]"#)
+                .header("cache-control", "private, No-Store, max-age=300"),
+            None if req.url.contains("/1?") => {
+                MockResponse::json(r#"{"id": 1}"#).header("cache-control", "no-store")
+            }
+            None => MockResponse::json(r#"{"id": 3}"#).header("cache-control", "max-age=300"),
+        });
+        run(async {
+            let client = api.client();
+            let cached = |id: &'static u32| Requester::try_get::<Thing, u32>(&client, id);
+
+            let found: Thing = Requester::single(&client, 1_u32).await.unwrap();
+            assert_eq!(found, thing(1));
+            assert_eq!(cached(&1).await, None);
+            let things: Vec<Thing> = Requester::many(&client, vec![2_u32]).await.unwrap();
+            assert_eq!(things, [thing(2)]);
+            assert_eq!(cached(&2).await, None);
+            let _: Thing = Requester::single(&client, 3_u32).await.unwrap();
+            assert_eq!(cached(&3).await, Some(thing(3)));
+        });
+    }
+
+    #[test]
+    fn invalidate_refetches_one_id() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| match request.url.as_str() {
+            url if url.contains("/1?") => MockResponse::json(r#"{"id": 1}"#),
+            _ => MockResponse::json(r#"{"id": 2}"#),
+        });
+        run(async {
+            let client = api.client();
+            for id in [1_u32, 2] {
+                let _: Thing = Requester::single(&client, id).await.unwrap();
+            }
+            assert_eq!(api.requests().len(), 2);
+
+            Requester::invalidate::<Thing, u32>(&client, &1).await;
+            assert_eq!(Requester::try_get::<Thing, u32>(&client, &1).await, None);
+            assert!(Requester::try_get::<Thing, u32>(&client, &2)
+                .await
+                .is_some());
+            for id in [1_u32, 2] {
+                let _: Thing = Requester::single(&client, id).await.unwrap();
+            }
+            let requests = api.requests();
+            assert_eq!(requests.len(), 3);
+            assert!(requests[2].url.contains("/1?"));
+        });
+    }
+
+    #[test]
+    fn non_localized_cache_shared_across_languages() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Price {
+            id: u32,
+        }
+
+        impl Endpoint for Price {
+            const AUTHENTICATED: bool = false;
+            const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+            const LOCALE: bool = false;
+            const URL: &'static str = "v2/commerce/prices";
+            const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+        }
+
+        impl EndpointWithId for Price {
+            type IdType = u32;
+        }
+
+        impl BulkEndpoint for Price {
+            const ALL: bool = false;
+
+            fn id(&self) -> &Self::IdType {
+                &self.id
+            }
+        }
+
+        let api = MockApi::new(|req| match req.query("ids") {
+            Some(_) => MockResponse::json(r#"[{"id": 2}, {"id": 3}]"#),
+            None => MockResponse::json(r#"{"id": 1}"#),
+        });
+        run(async {
+            let client = api.client();
+            let german = client.in_language(Language::De);
+            let en: Price = Requester::single(&client, 1_u32).await.unwrap();
+            let de: Price = Requester::single(&german, 1_u32).await.unwrap();
+            assert_eq!(en, de);
+            let _: Vec<Price> = Requester::many(&client, vec![2_u32, 3]).await.unwrap();
+            let de: Vec<Price> = Requester::many(&german, vec![2_u32, 3]).await.unwrap();
+            assert_eq!(de.len(), 2);
+            assert_eq!(api.requests().len(), 2);
+        });
+    }
+
+    #[cfg(all(feature = "items", feature = "blocking"))]
+    #[test]
+    fn try_get_sync_reads_cache_within_runtime() {
+        use gw2lib_model::items::Item;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| {
+            if request.url.starts_with("/v2/items/2") {
+                return MockResponse::json(r#"{"text": "no such id"}"#).status(404);
+            }
+            MockResponse::json(item_json(1).to_string())
+        });
+        run(async {
+            let client = api.client().negative_cache(Duration::minutes(5));
+            let _: Item = Requester::single(&client, 1_u32).await.unwrap();
+            let missing: Option<Item> = Requester::try_single(&client, 2_u32).await.unwrap();
+            assert!(missing.is_none());
+
+            // blocking on the runtime here would panic
+            let item: Option<Item> = crate::Requester::try_get_sync(&client, &1_u32);
+            assert_eq!(item.map(|x| x.id), Some(1));
+            let items: Vec<Option<Item>> =
+                crate::Requester::try_get_many_sync(&client, &[1_u32, 2, 3]);
+            let ids: Vec<_> = items.iter().map(|x| x.as_ref().map(|x| x.id)).collect();
+            assert_eq!(ids, [Some(1), None, None]);
+            let forced: Option<Item> = crate::Requester::try_get_sync(&client.forced(), &1_u32);
+            assert!(forced.is_none());
+        });
+        assert_eq!(api.requests().len(), 2);
+    }
+
+    #[cfg(feature = "items")]
+    #[test]
+    fn try_single_remembers_missing_ids() {
+        use gw2lib_model::items::Item;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| MockResponse::json(r#"{"text": "no such id"}"#).status(404));
+        run(async {
+            let client = api.client().negative_cache(Duration::minutes(5));
+            for _ in 0..3 {
+                let item: Option<Item> = Requester::try_single(&client, 1_u32).await.unwrap();
+                assert!(item.is_none());
+            }
+            let item: Option<Item> = Requester::try_single(&client.forced(), 1_u32)
+                .await
+                .unwrap();
+            assert!(item.is_none());
+        });
+        assert_eq!(api.requests().len(), 2);
+    }
+
+    #[cfg(feature = "items")]
+    #[test]
+    fn tombstones_have_keys_of_their_own() {
+        use gw2lib_model::items::Item;
+
+        use crate::{
+            cache::DynamicCache,
+            mock::{run, JsonCache, MockApi, MockResponse},
+        };
+
+        let api = MockApi::new(|request| {
+            if request.url.starts_with("/v2/items/2") {
+                return MockResponse::json(r#"{"text": "no such id"}"#).status(404);
+            }
+            MockResponse::json(item_json(1).to_string())
+        });
+        let json = Arc::new(JsonCache::default());
+        run(async {
+            let client = api
+                .client()
+                .cache(Arc::new(DynamicCache::from_arc(json.clone())))
+                .negative_cache(Duration::minutes(5));
+            let _: Item = Requester::single(&client, 1_u32).await.unwrap();
+            let missing: Option<Item> = Requester::try_single(&client, 2_u32).await.unwrap();
+            assert!(missing.is_none());
+            let cached: Option<Item> = Requester::try_get(&client, &1_u32).await;
+            assert!(cached.is_some());
+        });
+        let keys: Vec<_> = json.entries().into_iter().map(|(key, _)| key).collect();
+        assert_eq!(
+            keys,
+            [
+                "gw2lib_static_v2/items_en_1",
+                "gw2lib_static_v2/items_en_2#absent"
+            ]
+        );
+    }
+
+    #[test]
+    fn text_keyed_cache_keeps_bulk_results() {
+        use crate::{
+            cache::DynamicCache,
+            mock::{run, JsonCache, MockApi, MockResponse},
+        };
+
+        let api = MockApi::new(|_| MockResponse::json(r#"[{"id": 1}, {"id": 2}]"#));
+        let json = Arc::new(JsonCache::default());
+        run(async {
+            let client = api
+                .client()
+                .cache(Arc::new(DynamicCache::from_arc(json.clone())))
+                .negative_cache(Duration::minutes(5));
+            let things: Vec<Thing> = Requester::many(&client, vec![1_u32, 2]).await.unwrap();
+            assert_eq!(things.len(), 2);
+            assert_eq!(Requester::try_get(&client, &1_u32).await, Some(thing(1)));
+            assert_eq!(Requester::try_get(&client, &2_u32).await, Some(thing(2)));
+
+            Requester::invalidate::<Thing, u32>(&client, &1).await;
+            assert_eq!(Requester::try_get::<Thing, u32>(&client, &1).await, None);
+            assert_eq!(Requester::try_get(&client, &2_u32).await, Some(thing(2)));
+        });
+        let keys: Vec<_> = json.entries().into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, ["gw2lib_static_v2/things_2"]);
+    }
+
+    #[cfg(feature = "items")]
+    #[test]
+    fn found_value_replaces_tombstone() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        use gw2lib_model::items::Item;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let exists = Arc::new(AtomicBool::new(false));
+        let api = MockApi::new({
+            let exists = exists.clone();
+            move |_| {
+                if !exists.load(Ordering::SeqCst) {
+                    return MockResponse::json(r#"{"text": "no such id"}"#).status(404);
+                }
+                let mut item = item_json(19677);
+                item["name"] = "Gift of Exploration".into();
+                MockResponse::json(item.to_string())
+            }
+        });
+        run(async {
+            let client = api.client().negative_cache(Duration::minutes(5));
+            for _ in 0..2 {
+                let result: EndpointResult<Item> = Requester::single(&client, 19677_u32).await;
+                assert!(matches!(
+                    result,
+                    Err(EndpointError::ApiError(ApiError::NotFound))
+                ));
+                let cached: Option<Item> = Requester::try_get(&client, &19677_u32).await;
+                assert!(cached.is_none());
+            }
+            assert_eq!(api.requests().len(), 1);
+
+            exists.store(true, Ordering::SeqCst);
+            let item: Item = Requester::single(&client.forced(), 19677_u32)
+                .await
+                .unwrap();
+            assert_eq!(item.name, "Gift of Exploration");
+            let item: Item = Requester::single(&client, 19677_u32).await.unwrap();
+            assert_eq!(item.name, "Gift of Exploration");
+            let cached: Option<Item> = Requester::try_get(&client, &19677_u32).await;
+            assert!(cached.is_some());
+        });
+        assert_eq!(api.requests().len(), 2);
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn arc_client_across_tasks() {
+        use gw2lib_model::misc::build::Build;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| MockResponse::json(r#"{"id": 115267}"#));
+        run(async {
+            let client = Arc::new(api.client());
+            let tasks: Vec<_> = (0..2)
+                .map(|_| {
+                    let client = client.clone();
+                    tokio::spawn(async move {
+                        let build: Build = Requester::get(&client).await.unwrap();
+                        build
+                    })
+                })
+                .collect();
+            for task in tasks {
+                assert_eq!(task.await.unwrap().id, 115267);
+            }
+            let build: Build = Requester::get(&client.forced()).await.unwrap();
+            assert_eq!(build.id, 115267);
+        });
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn endpoint_method() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        #[derive(Clone, serde::Deserialize, serde::Serialize)]
+        struct Token {
+            subtoken: String,
+        }
+
+        impl Endpoint for Token {
+            const AUTHENTICATED: bool = false;
+            const LOCALE: bool = false;
+            const METHOD: gw2lib_model::Method = gw2lib_model::Method::Post;
+            const URL: &'static str = "v2/token";
+            const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+        }
+
+        impl FixedEndpoint for Token {}
+
+        let api = MockApi::new(|request| {
+            if request.url.contains("v2/token") {
+                MockResponse::json(r#"{"subtoken": "abc"}"#)
+            } else {
+                MockResponse::json(r#"{"id": 115267}"#)
+            }
+        });
+        run(async {
+            let client = api.client();
+            let token: Token = Requester::get(&client).await.unwrap();
+            assert_eq!(token.subtoken, "abc");
+            let _: gw2lib_model::misc::build::Build = Requester::get(&client).await.unwrap();
+        });
+        let requests = api.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[1].method, "GET");
+    }
+
+    #[test]
+    fn all_from_resumes_after_checkpoint() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| match request.query("ids") {
+            Some(ids) => {
+                let things: Vec<_> = ids
+                    .split("%2C")
+                    .flat_map(|x| x.split(','))
+                    .map(|id| serde_json::json!({ "id": id.parse::<u32>().unwrap() }))
+                    .collect();
+                MockResponse::json(serde_json::to_string(&things).unwrap())
+            }
+            None => MockResponse::json("[7, 3, 1, 5, 2]"),
+        });
+        let things = run(async {
+            let client = api.client();
+            Requester::all_from::<Thing, u32>(&client, 2).await.unwrap()
+        });
+        let ids: Vec<u32> = things.iter().map(|x| x.id).collect();
+        assert_eq!(ids, [3, 5, 7]);
+        let requested = api.requests()[1].query("ids").unwrap().to_string();
+        assert!(!requested.contains('1') && !requested.contains('2'));
+    }
+
+    #[test]
+    fn all_filtered_caches_skipped_items() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|req| match req.query("ids") {
+            Some(ids) => {
+                let things: Vec<_> = ids
+                    .split("%2C")
+                    .flat_map(|x| x.split(','))
+                    .map(|id| serde_json::json!({ "id": id.parse::<u32>().unwrap() }))
+                    .collect();
+                MockResponse::json(serde_json::to_string(&things).unwrap())
+            }
+            None => MockResponse::json("[1, 2, 3, 4, 5]"),
+        });
+        run(async {
+            let client = api.client().bulk_chunk_size(2).max_concurrency(1);
+            let even = Requester::all_filtered::<Thing, u32>(&client, |x| x.id % 2 == 0)
+                .await
+                .unwrap();
+            let mut ids: Vec<u32> = even.iter().map(|x| x.id).collect();
+            ids.sort_unstable();
+            assert_eq!(ids, [2, 4]);
+
+            let odd: Option<Thing> = Requester::try_get(&client, &3_u32).await;
+            assert_eq!(odd.unwrap().id, 3);
+        });
+        // the ids, then three batches of two
+        assert_eq!(api.requests().len(), 4);
+    }
+
+    #[test]
+    fn all_by_ids_caps_concurrency() {
+        use std::time::{Duration, Instant};
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let delay = Duration::from_millis(50);
+        let api = MockApi::new(move |req| match req.query("ids") {
+            Some(ids) => {
+                let things: Vec<_> = ids
+                    .split("%2C")
+                    .flat_map(|x| x.split(','))
+                    .map(|id| serde_json::json!({ "id": id.parse::<u32>().unwrap() }))
+                    .collect();
+                MockResponse::json(serde_json::to_string(&things).unwrap()).delay(delay)
+            }
+            None => {
+                MockResponse::json(serde_json::to_string(&(0..1000).collect::<Vec<_>>()).unwrap())
+            }
+        });
+        let elapsed = run(async {
+            let client = api.client().max_concurrency(2);
+            let start = Instant::now();
+            let things: Vec<Thing> = Requester::all(&client).await.unwrap();
+            let elapsed = start.elapsed();
+            let ids: HashSet<u32> = things.iter().map(|x| x.id).collect();
+            assert_eq!(ids.len(), 1000);
+
+            let again: Vec<Thing> = Requester::all(&client).await.unwrap();
+            assert_eq!(again.len(), 1000);
+            elapsed
+        });
+        // five chunks of 200, two at a time
+        assert!(elapsed >= delay * 3);
+        // the second call is served from the cache
+        assert_eq!(api.requests().len(), 1 + 5);
+    }
+
+    #[test]
+    fn projected_many() {
+        use crate::{
+            mock::{run, MockApi, MockResponse},
+            Projected, ProjectedId,
+        };
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Label(u32, String);
+
+        impl From<Thing> for Label {
+            fn from(thing: Thing) -> Self {
+                Self(thing.id, thing.name.to_uppercase())
+            }
+        }
+
+        impl ProjectedId<u32> for Label {
+            fn id(&self) -> &u32 {
+                &self.0
+            }
+        }
+
+        let api = MockApi::new(|_| {
+            MockResponse::json(r#"[{"id": 1, "name": "one"}, {"id": 2, "name": "two"}]"#)
+        });
+        run(async {
+            let client = api.client();
+            let labels: Vec<Projected<Thing, Label>> =
+                Requester::many(&client, vec![1_u32, 2]).await.unwrap();
+            let labels: Vec<_> = labels.into_iter().map(Projected::into_inner).collect();
+            assert_eq!(labels, [Label(1, "ONE".into()), Label(2, "TWO".into())]);
+
+            let cached: Option<Projected<Thing, Label>> = Requester::try_get(&client, &2_u32).await;
+            assert_eq!(cached.unwrap().value, Label(2, "TWO".into()));
+
+            // the labels don't match the response anymore
+            let client = api.client().strict_schema(true);
+            let labels: Vec<Projected<Thing, Label>> =
+                Requester::many(&client, vec![1_u32, 2]).await.unwrap();
+            assert_eq!(labels.len(), 2);
+        });
+        assert_eq!(api.requests().len(), 2);
+    }
+
+    #[test]
+    fn projected_stays_out_of_serializing_caches() {
+        use crate::{
+            cache::DynamicCache,
+            mock::{run, JsonCache, MockApi, MockResponse},
+            Projected, ProjectedId,
+        };
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Name(u32, String);
+
+        impl From<Thing> for Name {
+            fn from(thing: Thing) -> Self {
+                Self(thing.id, thing.name)
+            }
+        }
+
+        impl ProjectedId<u32> for Name {
+            fn id(&self) -> &u32 {
+                &self.0
+            }
+        }
+
+        let api = MockApi::new(|_| MockResponse::json(r#"[{"id": 1, "name": "one"}]"#));
+        let json = Arc::new(JsonCache::default());
+        run(async {
+            let client = api
+                .client()
+                .cache(Arc::new(DynamicCache::from_arc(json.clone())));
+            let names: Vec<Projected<Thing, Name>> =
+                Requester::many(&client, vec![1_u32]).await.unwrap();
+            assert_eq!(names[0].value, Name(1, "one".into()));
+            assert!(json.entries().is_empty());
+
+            // cached entries of the model itself are projected when read
+            let _: Vec<Thing> = Requester::many(&client, vec![1_u32]).await.unwrap();
+            let cached: Option<Projected<Thing, Name>> = Requester::try_get(&client, &1_u32).await;
+            assert_eq!(cached.unwrap().value, Name(1, "one".into()));
+        });
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn dyn_cache_selected_at_runtime() {
+        use gw2lib_model::misc::build::Build;
+
+        use crate::{
+            cache::{DynamicCache, InMemoryCache},
+            mock::{run, MockApi, MockResponse},
+            rate_limit::{DynRateLimiter, NoopRateLimiter},
+        };
+
+        let api = MockApi::new(|_| MockResponse::json(r#"{"id": 115267}"#));
+        run(async {
+            let dynamic = Arc::new(DynamicCache::new(InMemoryCache::default()));
+            let no_limit: DynRateLimiter = Box::new(NoopRateLimiter);
+            let client = api.client().cache(dynamic).rate_limiter(no_limit);
+            for _ in 0..2 {
+                let build: Build = Requester::get(&client).await.unwrap();
+                assert_eq!(build.id, 115267);
+            }
+
+            let client = client.dyn_cache(crate::NoopCache);
+            let _: Build = Requester::get(&client).await.unwrap();
+        });
+        assert_eq!(api.requests().len(), 2);
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn world_population_changes_against_snapshot() {
+        use gw2lib_model::misc::worlds::PopulationLevel;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| {
+            MockResponse::json(
+                r#"[
+                    {"id": 1001, "name": "Anvil Rock", "population": "VeryHigh"},
+                    {"id": 1002, "name": "Borlis Pass", "population": "Medium"},
+                    {"id": 1003, "name": "Yak's Bend", "population": "Full"}
+                ]"#,
+            )
+        });
+        let world = |id, population| World {
+            id,
+            name: String::new(),
+            population,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
+        };
+        let previous = vec![
+            world(1001, PopulationLevel::Full),
+            world(1002, PopulationLevel::Medium),
+            world(1004, PopulationLevel::High),
+        ];
+        run(async {
+            let client = api.client();
+            let changes = Requester::world_population_changes(&client, &previous)
+                .await
+                .unwrap();
+            assert_eq!(
+                changes,
+                [(1001, PopulationLevel::Full, PopulationLevel::VeryHigh)]
+            );
+            let _ = Requester::world_population_changes(&client, &previous).await;
+        });
+        assert_eq!(api.requests().len(), 2);
+    }
+
+    #[test]
+    fn cached_std_matches_cached() {
+        let client = Client::empty();
+        let chrono = client.cached(Duration::seconds(90));
+        let std = client.cached_std(std::time::Duration::from_secs(90));
+        assert_eq!(
+            Requester::cache_duration(&std),
+            Requester::cache_duration(&chrono)
+        );
+        let capped = client.cached_std(std::time::Duration::MAX);
+        assert_eq!(Requester::cache_duration(&capped), Duration::MAX);
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn default_cache_duration_precedence() {
+        use gw2lib_model::misc::build::Build;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| {
+            MockResponse::json(r#"{"id": 115267}"#).header("cache-control", "max-age=300")
+        });
+        run(async {
+            async fn expiry<C: Cache + Send + Sync>(cache: &C) -> Duration {
+                let (_, expires) = cache
+                    .get_with_expiry::<Build, str, Build, String>("", Language::En, &None)
+                    .await
+                    .unwrap();
+                expires - Utc::now().naive_utc()
+            }
+
+            let client = api.client();
+            let _: Build = Requester::get(&client).await.unwrap();
+            let header = expiry(&*client.cache).await;
+            assert!(header <= Duration::minutes(5) && header > Duration::minutes(4));
+
+            let client = client.with_default_cache_duration(Duration::hours(1));
+            let _: Build = Requester::get(&client.forced()).await.unwrap();
+            let default = expiry(&*client.cache).await;
+            assert!(default <= Duration::hours(1) && default > Duration::minutes(59));
+
+            let forced = client.forced();
+            let _: Build = Requester::get(&forced.cached(Duration::seconds(5)))
+                .await
+                .unwrap();
+            assert!(expiry(&*client.cache).await <= Duration::seconds(5));
+        });
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn seeded_cache_jitter() {
+        use gw2lib_model::misc::build::Build;
+
+        use crate::{
+            mock::{run, MockApi, MockResponse},
+            Jitter,
+        };
+
+        let api = MockApi::new(|_| {
+            MockResponse::json(r#"{"id": 115267}"#).header("cache-control", "max-age=300")
+        });
+        run(async {
+            let client = api.client().cache_jitter_with(Jitter::with_seed(0.5, 7));
+            let _: Build = Requester::get(&client).await.unwrap();
+            let (_, expires) = client
+                .cache
+                .get_with_expiry::<Build, str, Build, String>("", Language::En, &None)
+                .await
+                .unwrap();
+            let expected = Jitter::with_seed(0.5, 7).apply(Duration::seconds(300));
+            let offset = expires - Utc::now().naive_utc() - expected;
+            assert!(offset <= Duration::zero() && offset > -Duration::seconds(1));
+        });
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn service_unavailable_during_maintenance() {
+        use gw2lib_model::misc::build::Build;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| {
+            MockResponse::json("<html><body>maintenance</body></html>")
+                .status(503)
+                .header("content-type", "text/html")
+                .header("retry-after", "120")
+        });
+        run(async {
+            let client = api.client();
+            let result: EndpointResult<Build> = Requester::get(&client).await;
+            assert!(matches!(
+                result,
+                Err(EndpointError::ServiceUnavailable {
+                    retry_after: Some(d)
+                }) if d == std::time::Duration::from_secs(120)
+            ));
+        });
+    }
+
+    #[cfg(all(feature = "items", feature = "misc"))]
+    #[test]
+    fn html_error_page() {
+        use gw2lib_model::items::Item;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|req| {
+            let response = MockResponse::json("<html><body>access denied</body></html>")
+                .header("content-type", "text/html; charset=utf-8");
+            if req.url.contains("/v2/items") {
+                response
+            } else {
+                response.status(502)
+            }
+        });
+        run(async {
+            let client = api.client().error_body_limit(12);
+            let result: EndpointResult<Vec<Item>> = Requester::many(&client, vec![1_u32]).await;
+            match result {
+                Err(EndpointError::UnexpectedContentType {
+                    content_type,
+                    status,
+                    body_preview,
+                }) => {
+                    assert_eq!(content_type, "text/html; charset=utf-8");
+                    assert_eq!(status, StatusCode::OK);
+                    assert_eq!(body_preview, "<html><body>");
+                }
+                other => panic!("unexpected result: {other:?}"),
+            }
+            let build: EndpointResult<gw2lib_model::misc::build::Build> =
+                Requester::get(&client).await;
+            assert!(matches!(
+                build,
+                Err(EndpointError::UnexpectedContentType { status, .. })
+                    if status == StatusCode::BAD_GATEWAY
+            ));
+        });
+    }
+
+    #[test]
+    fn retry_after_http_date() {
+        use chrono::TimeZone;
+
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 27, 0).unwrap();
+        let wait = parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now);
+        assert_eq!(wait, Some(std::time::Duration::from_secs(60)));
+        let past = parse_retry_after("Wed, 21 Oct 2015 07:26:00 GMT", now);
+        assert_eq!(past, Some(std::time::Duration::ZERO));
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[cfg(all(feature = "characters", feature = "misc", not(feature = "blocking")))]
+    #[test]
+    fn join_all_endpoints() {
+        use gw2lib_model::{game_mechanics::outfits::Outfit, misc::worlds::World};
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|req| {
+            if req.url.contains("/v2/worlds") {
+                MockResponse::json(r#"[{"id": 1001, "name": "Anvil Rock", "population": "High"}]"#)
+            } else {
+                MockResponse::json(
+                    r#"[{"id": 1, "name": "Cook's Outfit", "icon": "", "unlock_items": []}]"#,
+                )
+            }
+        });
+        run(async {
+            let client = api.client();
+            let (worlds, outfits) = crate::join_all!(client => World, Outfit).await.unwrap();
+            assert_eq!(worlds[0].id, 1001);
+            assert_eq!(outfits[0].id, 1);
+            let (again,) = crate::join_all!(client => World).await.unwrap();
+            assert_eq!(again, worlds);
+        });
+        assert_eq!(api.requests().len(), 2);
+    }
+
+    #[cfg(all(
+        feature = "items",
+        feature = "characters",
+        feature = "misc",
+        not(feature = "blocking")
+    ))]
+    #[test]
+    fn fetch_different_endpoints() {
+        use gw2lib_model::{game_mechanics::outfits::Outfit, items::Item, misc::worlds::World};
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|req| {
+            if req.url.starts_with("/v2/worlds") {
+                MockResponse::json(r#"{"id": 1001, "name": "Anvil Rock", "population": "High"}"#)
+            } else if req.url.starts_with("/v2/outfits") {
+                MockResponse::json(
+                    r#"{"id": 1, "name": "Cook's Outfit", "icon": "", "unlock_items": []}"#,
+                )
+            } else {
+                let mut item = item_json(19721);
+                item["name"] = "Glob of Ectoplasm".into();
+                MockResponse::json(item.to_string())
+            }
+        });
+        run(async {
+            let client = api.client();
+            let fetched =
+                crate::fetch!(client; item: Item = 19721, world: World = 1001, outfit: Outfit = 1)
+                    .await
+                    .unwrap();
+            assert_eq!(fetched.item.name, "Glob of Ectoplasm");
+            assert_eq!(fetched.world.id, 1001);
+            assert_eq!(fetched.outfit.id, 1);
+
+            let single = crate::fetch!(client; world: World = 1001).await.unwrap();
+            assert_eq!(single.world, fetched.world);
+        });
+        assert_eq!(api.requests().len(), 3);
+    }
+
+    #[test]
+    fn bulk_chunk_size_is_capped() {
+        assert_eq!(Client::empty().options.bulk_chunk_size, 200);
+        assert_eq!(
+            Client::empty().bulk_chunk_size(2).options.bulk_chunk_size,
+            2
+        );
+        assert_eq!(
+            Client::empty().bulk_chunk_size(0).options.bulk_chunk_size,
+            1
+        );
+        assert_eq!(
+            Client::empty().bulk_chunk_size(500).options.bulk_chunk_size,
+            200
+        );
+    }
+
+    #[test]
+    fn unknown_fields() {
+        #[derive(serde::Deserialize, serde::Serialize)]
+        struct Model {
+            id: u32,
+            name: Option<String>,
+        }
+
+        let body = br#"[{"id": 1, "name": null, "new": 2}, {"id": 2, "other": 3}]"#;
+        let result: Vec<Model> = serde_json::from_slice(body).unwrap();
+        let error = check_unknown_fields(body, &result, "/v2/models".to_string()).unwrap_err();
+        match error {
+            EndpointError::UnknownFields { fields, url } => {
+                assert_eq!(fields, ["new", "other"]);
+                assert_eq!(url, "/v2/models");
+            }
+            e => panic!("unexpected error: {e}"),
+        }
+
+        let body = br#"{"id": 1}"#;
+        let result: Model = serde_json::from_slice(body).unwrap();
+        assert!(check_unknown_fields(body, &result, String::new()).is_ok());
+    }
+
+    #[cfg(feature = "characters")]
+    #[test]
+    fn endpoint_query_is_sent() {
+        use gw2lib_model::authenticated::account::buildstorage::AccountBuildStorage;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| MockResponse::json("[]"));
+        run(async {
+            let client = api.client().api_key("key");
+            let _: AccountBuildStorage = Requester::get(&client).await.unwrap();
+        });
+        let request = &api.requests()[0];
+        assert!(request.url.starts_with("/v2/account/buildstorage?"));
+        assert_eq!(request.query("ids"), Some("all"));
+    }
+
+    #[test]
+    fn query_in_raw_path() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| MockResponse::json("{}"));
+        run(async {
+            let client = api.client();
+            let _ = Requester::raw_get(&client, "/v2/x?name=a%20b", &[("page", "0")]).await;
+        });
+        let request = &api.requests()[0];
+        assert!(request.url.starts_with("/v2/x?"));
+        assert_eq!(request.query("name"), Some("a%20b"));
+        assert_eq!(request.query("page"), Some("0"));
+    }
+
+    #[test]
+    fn query_without_fixed_part() {
+        let client = Client::empty().api_key("key");
+        let uri = build_uri(
+            &client,
+            "v2/items",
+            "latest",
+            &[("ids", "1,2,3")],
+            true,
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            uri.path_and_query().unwrap(),
+            "/v2/items?v=latest&ids=1,2,3&lang=en&access_token=key"
+        );
+
+        let client = Client::empty().schema_via_query(false);
+        let uri = build_uri(&client, "v2/build", "latest", &[], false, false).unwrap();
+        assert_eq!(uri.path_and_query().unwrap(), "/v2/build");
+    }
+
+    #[test]
+    fn malformed_raw_path_is_an_error() {
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| MockResponse::json("{}"));
+        run(async {
+            let client = api.client();
+            let result = Requester::raw_get(&client, "v2/a b", &[]).await;
+            assert!(matches!(result, Err(EndpointError::InvalidUri(_))));
+        });
+        assert!(api.requests().is_empty());
+    }
+
+    #[test]
+    fn query_values_are_encoded() {
+        let client = Client::empty().schema_via_query(false);
+        let uri = build_uri(
+            &client,
+            "v2/guild/search",
+            "latest",
+            &[("name", "Me & You=#1"), ("ids", "a b,c"), ("v", "12:00")],
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            uri.path_and_query().unwrap(),
+            "/v2/guild/search?name=Me%20%26%20You%3D%231&ids=a%20b,c&v=12:00"
+        );
+    }
+
+    #[test]
+    fn schema_version_as_header() {
+        let client = Client::empty().schema_via_query(false);
+        let uri = build_uri(&client, "v2/build", "latest", &[], true, false).unwrap();
+        assert_eq!(uri.path_and_query().unwrap(), "/v2/build?lang=en");
+        let request = assemble_request(Method::GET, uri, schema_header(&client, "latest"), None);
+        assert_eq!(request.headers().get("x-schema-version").unwrap(), "latest");
+
+        let client = Client::empty();
+        let uri = build_uri(&client, "v2/build", "latest", &[], true, false).unwrap();
+        assert_eq!(uri.path_and_query().unwrap(), "/v2/build?v=latest&lang=en");
+        assert_eq!(schema_header(&client, "latest"), None);
+    }
+
+    #[cfg(feature = "misc")]
+    #[test]
+    fn schema_version_override() {
+        use chrono::TimeZone;
+        use gw2lib_model::misc::build::Build;
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|_| MockResponse::json(r#"{"id": 115267}"#));
+        run(async {
+            let client = api.client().schema_via_query(false);
+            let forced = client.forced();
+            let date = Utc.with_ymd_and_hms(2022, 3, 23, 19, 0, 0).unwrap();
+            for version in [
+                None,
+                Some(SchemaVersion::Pinned("2019-05-16T00:00:00.000Z")),
+                Some(SchemaVersion::Latest),
+                Some(SchemaVersion::Date(date)),
+            ] {
+                let _: Build = match version {
+                    Some(version) => Requester::get(&forced.schema_version(version)).await,
+                    None => Requester::get(&forced).await,
+                }
+                .unwrap();
+            }
+        });
+        let versions: Vec<_> = api
+            .requests()
+            .iter()
+            .map(|x| x.header("x-schema-version").unwrap().to_string())
+            .collect();
+        assert_eq!(
+            versions,
+            [
+                <gw2lib_model::misc::build::Build as Endpoint>::VERSION,
+                "2019-05-16T00:00:00.000Z",
+                "latest",
+                "2022-03-23T19:00:00.000Z",
+            ]
+        );
+    }
+
+    #[cfg(all(feature = "items", feature = "characters"))]
+    #[test]
+    fn api_key_only_sent_to_authenticated_endpoints() {
+        use gw2lib_model::{authenticated::account::skins::AccountSkins, items::Item};
+
+        use crate::mock::{run, MockApi, MockResponse};
+
+        let api = MockApi::new(|request| {
+            if request.url.contains("v2/account/skins") {
+                MockResponse::json("[1, 2]")
+            } else {
+                MockResponse::json(item_json(19677).to_string())
+            }
+        });
+        run(async {
+            let client = api.client().api_key("secret");
+            let _: Item = Requester::single(&client, 19677_u32).await.unwrap();
+            let _: AccountSkins = Requester::get(&client).await.unwrap();
+        });
+        let requests = api.requests();
+        assert!(requests[0].url.contains("v2/items"));
+        assert!(requests[0].header("authorization").is_none());
+        assert!(!requests[0].url.contains("secret"));
+        assert!(requests[1].url.contains("access_token=secret"));
+    }
+
+    #[test]
+    fn get_has_empty_body() {
+        let uri: Uri = "https://api.guildwars2.com/v2/build".parse().unwrap();
+        let request = assemble_request(Method::GET, uri, None, None);
+        assert_eq!(request.method(), Method::GET);
+        assert!(request.headers().get(CONTENT_TYPE).is_none());
+        let body = futures::executor::block_on(hyper::body::to_bytes(request.into_body())).unwrap();
+        assert!(body.is_empty());
+    }
+
+    #[test]
+    fn post_with_json_body() {
+        let uri: Uri = "https://api.guildwars2.com/v2/createsubtoken"
+            .parse()
+            .unwrap();
+        let json = serde_json::json!({ "permissions": ["account"] });
+        let request = assemble_request(
+            Method::POST,
+            uri.clone(),
+            None,
+            Some(serde_json::to_vec(&json).unwrap()),
+        );
+        assert_eq!(request.method(), Method::POST);
+        assert_eq!(request.uri(), &uri);
+        assert_eq!(
+            request.headers().get(CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+        let body = futures::executor::block_on(hyper::body::to_bytes(request.into_body())).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            json
+        );
+    }
+
+    #[test]
+    fn request_url_strips_access_token() {
+        let uri: Uri = "https://api.guildwars2.com/v2/account?v=latest&access_token=secret"
+            .parse()
+            .unwrap();
+        assert_eq!(RequestUrl::new(&uri).0, "/v2/account?v=latest");
+        let uri: Uri = "https://api.guildwars2.com/v2/account?access_token=secret"
+            .parse()
+            .unwrap();
+        assert_eq!(RequestUrl::new(&uri).0, "/v2/account");
+    }
+
+    #[test]
+    fn truncated_body_keeps_char_boundaries() {
+        assert_eq!(truncate_body(b"[1, 2, 3]", 4), "[1, ");
+        assert_eq!(truncate_body(b"[1]", 4096), "[1]");
+        assert_eq!(truncate_body("\"äöü\"".as_bytes(), 4), "\"ä");
+    }
+
+    #[test]
+    fn cache_control_max_age() {
+        let response = response_with("Cache-Control", "public, max-age=86400");
+        assert_eq!(get_expire_from_header(&response), Duration::seconds(86400));
+        let response = response_with("CACHE-CONTROL", "MAX-AGE=60");
+        assert_eq!(get_expire_from_header(&response), Duration::seconds(60));
+        let response = response_with("cache-control", "no-cache");
+        assert_eq!(get_expire_from_header(&response), Duration::seconds(300));
+    }
 }
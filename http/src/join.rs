@@ -0,0 +1,95 @@
+/// requests all items of several endpoints at once, see
+/// [`Requester::all`](crate::Requester::all)
+///
+/// Returns the results as a tuple, in the order of the endpoints, or the
+/// first error. All requests share the cache, the rate limiter and the
+/// deduplication of running requests of the client.
+///
+/// Like the methods of [`Requester`](crate::Requester), this returns a
+/// future, or the result directly with the `blocking` feature.
+/// ## Example
+/// ```no_run
+/// use gw2lib::{
+///     join_all,
+///     model::items::{recipes::Recipe, skins::Skin, Item},
+///     Client,
+/// };
+///
+/// # async fn example() -> gw2lib::EndpointResult<()> {
+/// let client = Client::default();
+/// let (items, recipes, skins) = join_all!(client => Item, Recipe, Skin).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! join_all {
+    ($client:expr => $($endpoint:ty),+ $(,)?) => {{
+        let client = &$client;
+        $crate::__private::join(async move {
+            $crate::__private::try_join!(
+                $($crate::__private::Requester::all::<$endpoint, _>(client)),+
+            )
+        })
+    }};
+}
+
+/// requests single items of different endpoints at once, see
+/// [`Requester::single`](crate::Requester::single)
+///
+/// Returns a struct with a field per request, named like in the invocation,
+/// or the first error. The ids have to be of the id type of the endpoint. The
+/// requests run concurrently and share the cache, the rate limiter and the
+/// deduplication of running requests of the client.
+///
+/// Like the methods of [`Requester`](crate::Requester), this returns a
+/// future, or the result directly with the `blocking` feature.
+/// ## Example
+/// ```no_run
+/// use gw2lib::{
+///     fetch,
+///     model::items::{recipes::Recipe, skins::Skin, Item},
+///     Client,
+/// };
+///
+/// # async fn example() -> gw2lib::EndpointResult<()> {
+/// let client = Client::default();
+/// let fetched = fetch!(client; item: Item = 19721, recipe: Recipe = 1, skin: Skin = 10).await?;
+/// println!("{} and {}", fetched.item.name, fetched.skin.name);
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! fetch {
+    ($client:expr; $($name:ident: $endpoint:ty = $id:expr),+ $(,)?) => {{
+        struct Fetched {
+            $($name: $endpoint),+
+        }
+        let client = &$client;
+        $(let $name: <$endpoint as $crate::__private::EndpointWithId>::IdType = $id;)+
+        $crate::__private::join(async move {
+            let ($($name,)+) = $crate::__private::try_join!(
+                $($crate::__private::Requester::single::<$endpoint, _>(client, $name)),+
+            )?;
+            $crate::EndpointResult::Ok(Fetched { $($name),+ })
+        })
+    }};
+}
+
+pub mod private {
+    use std::future::Future;
+
+    pub use futures::try_join;
+    pub use gw2lib_model::EndpointWithId;
+
+    pub use crate::client::AsyncRequester as Requester;
+
+    #[cfg(feature = "blocking")]
+    pub fn join<F: Future>(fut: F) -> F::Output {
+        crate::block::block(fut)
+    }
+
+    #[cfg(not(feature = "blocking"))]
+    pub fn join<F: Future>(fut: F) -> F {
+        fut
+    }
+}
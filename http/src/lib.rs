@@ -1,11 +1,24 @@
 pub(crate) mod block;
 pub mod cache;
 mod client;
+mod join;
+pub mod metrics;
+#[cfg(test)]
+// not every feature set has tests using every helper
+#[allow(dead_code)]
+mod mock;
+pub mod prelude;
+mod projected;
 pub mod rate_limit;
+pub mod reset;
 pub use client::*;
 pub use gw2lib_model as model;
+#[doc(hidden)]
+pub use join::private as __private;
+pub use projected::{Projected, ProjectedId};
 use thiserror::Error;
 use tokio::sync::broadcast::error::RecvError;
+pub use tokio_util::sync::CancellationToken;
 
 use crate::{
     cache::{Cache, NoopCache},
@@ -28,11 +41,53 @@ pub enum EndpointError {
     ApiError(ApiError),
     #[error("failed to retrieve item from already running request: {0}")]
     InflightReceiveFailed(#[from] RecvError),
+    /// the response contained fields the model doesn't know about
+    ///
+    /// only returned with [`Client::strict_schema`]
+    #[error("response from {url} contains unknown fields: {fields:?}")]
+    UnknownFields { fields: Vec<String>, url: String },
+    #[error("request was cancelled")]
+    Cancelled,
+    /// the path or a query of the request doesn't form a valid uri, e.g. a
+    /// malformed path passed to [`Requester::raw_get`]
+    #[error("invalid request uri: {0}")]
+    InvalidUri(#[source] hyper::http::Error),
     #[error("invalid json response: {0}")]
     InvalidJsonResponse(#[from] serde_json::Error),
+    /// the response could not be deserialized into the requested model
+    ///
+    /// `body` is truncated to [`Client::error_body_limit`] bytes and `url`
+    /// never contains the api key
+    #[error("failed to deserialize response from {url}: {source}")]
+    Deserialization {
+        source: serde_json::Error,
+        body: String,
+        url: String,
+    },
+    /// the api answered with `503 Service Unavailable`, usually during
+    /// maintenance
+    ///
+    /// `retry_after` is taken from the `Retry-After` header, if present
+    #[error("gw2 api is unavailable")]
+    ServiceUnavailable {
+        retry_after: Option<std::time::Duration>,
+    },
+    /// the response is not json, e.g. an html error page of a proxy
+    ///
+    /// `body_preview` is truncated to [`Client::error_body_limit`] bytes
+    #[error("expected json, got {content_type} ({status})")]
+    UnexpectedContentType {
+        content_type: String,
+        status: hyper::StatusCode,
+        body_preview: String,
+    },
 }
 
+/// an error status returned by the api
+///
+/// More variants may be added for statuses that need special handling.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum ApiError {
     #[error("invalid key")]
     Unauthorized,
@@ -40,8 +95,160 @@ pub enum ApiError {
     MissingGameAccess,
     #[error("too many requests")]
     RateLimited,
+    /// the requested id or endpoint doesn't exist
+    #[error("not found")]
+    NotFound,
     #[error("{0}: {1}")]
     Other(hyper::StatusCode, String),
 }
 
-type EndpointResult<T> = Result<T, EndpointError>;
+impl EndpointError {
+    /// a fitting http status for a web backend answering on behalf of the
+    /// gw2 api
+    ///
+    /// Status codes of the api are passed through, missing authentication is
+    /// `401`, maintenance is `503`, exceeding the rate limiter is `429`, a
+    /// malformed or unsupported request is `500` and everything else that
+    /// went wrong upstream is `502 Bad Gateway`.
+    pub fn to_http_status(&self) -> u16 {
+        match self {
+            EndpointError::NotAuthenticated => 401,
+            EndpointError::ApiError(ApiError::Unauthorized) => 401,
+            EndpointError::ApiError(ApiError::MissingGameAccess) => 403,
+            EndpointError::ApiError(ApiError::NotFound) => 404,
+            EndpointError::ApiError(ApiError::RateLimited) => 429,
+            EndpointError::ApiError(ApiError::Other(status, _)) => status.as_u16(),
+            EndpointError::ServiceUnavailable { .. } => 503,
+            EndpointError::RateLimiterBucketExceeded => 429,
+            EndpointError::InvalidUri(_) | EndpointError::UnsupportedEndpointQuery => 500,
+            EndpointError::RateLimiterCrashed(_)
+            | EndpointError::RequestFailed(_)
+            | EndpointError::InflightReceiveFailed(_)
+            | EndpointError::UnknownFields { .. }
+            | EndpointError::Cancelled
+            | EndpointError::InvalidJsonResponse(_)
+            | EndpointError::Deserialization { .. }
+            | EndpointError::UnexpectedContentType { .. } => 502,
+        }
+    }
+}
+
+pub type EndpointResult<T> = Result<T, EndpointError>;
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use super::*;
+
+    fn json_error() -> serde_json::Error {
+        serde_json::from_str::<u32>("\"not a number\"").unwrap_err()
+    }
+
+    fn hyper_error() -> hyper::Error {
+        let (sender, body) = hyper::Body::channel();
+        sender.abort();
+        futures::executor::block_on(hyper::body::to_bytes(body)).unwrap_err()
+    }
+
+    #[test]
+    fn request_failed_source() {
+        let error: EndpointError = hyper_error().into();
+        let source = error.source().expect("missing source");
+        assert!(source.downcast_ref::<hyper::Error>().is_some());
+    }
+
+    #[test]
+    fn inflight_source() {
+        let error: EndpointError = RecvError::Closed.into();
+        let source = error.source().expect("missing source");
+        assert!(matches!(
+            source.downcast_ref::<RecvError>(),
+            Some(RecvError::Closed)
+        ));
+    }
+
+    #[test]
+    fn invalid_json_source() {
+        let error: EndpointError = json_error().into();
+        let source = error.source().expect("missing source");
+        assert!(source.downcast_ref::<serde_json::Error>().is_some());
+    }
+
+    #[test]
+    fn deserialization_source() {
+        let error = EndpointError::Deserialization {
+            source: json_error(),
+            body: "\"not a number\"".to_string(),
+            url: "/v2/items/19721".to_string(),
+        };
+        let source = error.source().expect("missing source");
+        assert!(source.downcast_ref::<serde_json::Error>().is_some());
+        assert!(error.to_string().contains("/v2/items/19721"));
+    }
+
+    #[test]
+    fn http_status() {
+        let status = |error: EndpointError| error.to_http_status();
+        assert_eq!(status(EndpointError::NotAuthenticated), 401);
+        assert_eq!(status(EndpointError::ApiError(ApiError::Unauthorized)), 401);
+        assert_eq!(
+            status(EndpointError::ApiError(ApiError::MissingGameAccess)),
+            403
+        );
+        assert_eq!(status(EndpointError::ApiError(ApiError::NotFound)), 404);
+        assert_eq!(status(EndpointError::ApiError(ApiError::RateLimited)), 429);
+        assert_eq!(
+            status(EndpointError::ApiError(ApiError::Other(
+                hyper::StatusCode::BAD_REQUEST,
+                "invalid id".to_string()
+            ))),
+            400
+        );
+        assert_eq!(
+            status(EndpointError::ServiceUnavailable { retry_after: None }),
+            503
+        );
+        let uri_error = hyper::Uri::builder()
+            .path_and_query("/a b")
+            .build()
+            .unwrap_err();
+        assert_eq!(status(EndpointError::InvalidUri(uri_error)), 500);
+        assert_eq!(status(EndpointError::UnsupportedEndpointQuery), 500);
+        assert_eq!(status(EndpointError::RateLimiterBucketExceeded), 429);
+
+        let upstream = [
+            EndpointError::RateLimiterCrashed("redis".to_string()),
+            EndpointError::RequestFailed(hyper_error()),
+            EndpointError::InflightReceiveFailed(RecvError::Closed),
+            EndpointError::UnknownFields {
+                fields: vec!["new_field".to_string()],
+                url: "/v2/items".to_string(),
+            },
+            EndpointError::Cancelled,
+            EndpointError::InvalidJsonResponse(json_error()),
+            EndpointError::Deserialization {
+                source: json_error(),
+                body: String::new(),
+                url: "/v2/items".to_string(),
+            },
+            EndpointError::UnexpectedContentType {
+                content_type: "text/html".to_string(),
+                status: hyper::StatusCode::OK,
+                body_preview: String::new(),
+            },
+        ];
+        for error in upstream {
+            assert_eq!(error.to_http_status(), 502, "{error}");
+        }
+    }
+
+    #[test]
+    fn api_error_display() {
+        let error = EndpointError::ApiError(ApiError::Unauthorized);
+        assert_eq!(
+            error.to_string(),
+            "gw2 api returned non success status: invalid key"
+        );
+    }
+}
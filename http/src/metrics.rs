@@ -0,0 +1,57 @@
+use std::{fmt, time::Duration};
+
+/// hooks for recording metrics of api requests
+///
+/// Implement this to forward request and cache statistics to a backend of
+/// your choice, like `metrics` or `prometheus`. Every method defaults to a
+/// no-op, so only the interesting ones need to be implemented.
+///
+/// `url` and `endpoint` never contain the api key.
+///
+/// ## Example
+/// ```
+/// use std::sync::{
+///     atomic::{AtomicUsize, Ordering},
+///     Arc,
+/// };
+///
+/// use gw2lib::{metrics::Metrics, Client};
+///
+/// #[derive(Default)]
+/// struct CacheHits(AtomicUsize);
+///
+/// impl Metrics for CacheHits {
+///     fn on_cache_hit(&self, _endpoint: &str) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let client = Client::empty().metrics(Arc::new(CacheHits::default()));
+/// ```
+pub trait Metrics: Send + Sync {
+    /// called right before a request is sent
+    fn on_request(&self, _url: &str, _authenticated: bool) {}
+
+    /// called once the response headers arrived
+    ///
+    /// not called if the connection failed
+    fn on_response(&self, _url: &str, _status: u16, _duration: Duration) {}
+
+    /// called when a response is served from the cache
+    fn on_cache_hit(&self, _endpoint: &str) {}
+
+    /// called when a cache lookup found nothing
+    fn on_cache_miss(&self, _endpoint: &str) {}
+
+    /// called by a rate limiter when it delays a request by `delay`
+    ///
+    /// only called by rate limiters this was passed to, like
+    /// [`BucketRateLimiter::metrics`](crate::rate_limit::BucketRateLimiter::metrics)
+    fn on_throttle(&self, _delay: Duration) {}
+}
+
+impl fmt::Debug for dyn Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Metrics")
+    }
+}
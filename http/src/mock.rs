@@ -0,0 +1,285 @@
+//! an in process api for tests, answering requests without network access
+
+use std::{
+    collections::BTreeMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use hyper::{
+    client::connect::{Connected, Connection},
+    service::Service,
+    Uri,
+};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, DuplexStream, ReadBuf};
+
+use crate::{
+    cache::{CacheKey, CacheValue, Cached, DynCache, InMemoryCache},
+    BucketRateLimiter, Client,
+};
+
+/// the answer of the mock api to a request
+pub(crate) struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: String,
+    /// how long to wait before answering
+    pub delay: Duration,
+    /// how long to wait between the headers and the body
+    pub body_delay: Duration,
+}
+
+impl MockResponse {
+    pub fn json(body: impl Into<String>) -> Self {
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body: body.into(),
+            delay: Duration::ZERO,
+            body_delay: Duration::ZERO,
+        }
+    }
+
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn header(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.headers.push((name, value.to_string()));
+        self
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn body_delay(mut self, delay: Duration) -> Self {
+        self.body_delay = delay;
+        self
+    }
+}
+
+type Handler = dyn Fn(&MockRequest) -> MockResponse + Send + Sync;
+
+/// a request as seen by the mock api
+#[derive(Clone, Debug)]
+pub(crate) struct MockRequest {
+    pub method: String,
+    /// path and query
+    pub url: String,
+    /// header lines, with lowercase names
+    pub headers: Vec<(String, String)>,
+}
+
+impl MockRequest {
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.url
+            .split_once('?')?
+            .1
+            .split('&')
+            .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// a hyper connector routing every request to a handler
+#[derive(Clone)]
+pub(crate) struct MockApi {
+    handler: Arc<Handler>,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockApi {
+    pub fn new(handler: impl Fn(&MockRequest) -> MockResponse + Send + Sync + 'static) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            requests: Default::default(),
+        }
+    }
+
+    /// all requests received so far
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// a client with the default cache and rate limiter talking to this api
+    ///
+    /// must be called within a tokio runtime
+    pub fn client(&self) -> Client<InMemoryCache, BucketRateLimiter, MockApi, false> {
+        let client = hyper::Client::builder().build(self.clone());
+        let mut client = Client::from_hyper(client);
+        client.host = "http://mock".to_string();
+        client
+    }
+
+    async fn serve(self, mut stream: DuplexStream) {
+        let mut buf = Vec::new();
+        let mut chunk = [0; 1024];
+        let head_end = loop {
+            if let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                break end;
+            }
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(n) => buf.extend_from_slice(&chunk[..n]),
+            }
+        };
+        let head = String::from_utf8_lossy(&buf[..head_end]);
+        let mut lines = head.split("\r\n");
+        let request_line = lines.next().unwrap_or_default();
+        let request = MockRequest {
+            method: request_line
+                .split(' ')
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            url: request_line
+                .split(' ')
+                .nth(1)
+                .unwrap_or_default()
+                .to_string(),
+            headers: lines
+                .filter_map(|line| line.split_once(':'))
+                .map(|(k, v)| (k.trim().to_lowercase(), v.trim().to_string()))
+                .collect(),
+        };
+        self.requests.lock().unwrap().push(request.clone());
+
+        let response = (self.handler)(&request);
+        if !response.delay.is_zero() {
+            tokio::time::sleep(response.delay).await;
+        }
+        let mut out = format!(
+            "HTTP/1.1 {} Mock\r\ncontent-length: {}\r\nconnection: close\r\n",
+            response.status,
+            response.body.len()
+        );
+        for (name, value) in &response.headers {
+            out.push_str(&format!("{name}: {value}\r\n"));
+        }
+        out.push_str("\r\n");
+        if !response.body_delay.is_zero() {
+            let _ = stream.write_all(out.as_bytes()).await;
+            out.clear();
+            tokio::time::sleep(response.body_delay).await;
+        }
+        out.push_str(&response.body);
+        let _ = stream.write_all(out.as_bytes()).await;
+        let _ = stream.shutdown().await;
+    }
+}
+
+impl Service<Uri> for MockApi {
+    type Error = std::io::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<MockStream, Self::Error>> + Send>>;
+    type Response = MockStream;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        tokio::spawn(self.clone().serve(server));
+        Box::pin(async move { Ok(MockStream(client)) })
+    }
+}
+
+/// the client side of a connection to [`MockApi`]
+pub(crate) struct MockStream(DuplexStream);
+
+impl Connection for MockStream {
+    fn connected(&self) -> Connected {
+        Connected::new()
+    }
+}
+
+impl AsyncRead for MockStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for MockStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
+
+/// runs `f` on a fresh runtime
+pub(crate) fn run<F: Future>(f: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(f)
+}
+
+/// stores json by the textual key only, like an external cache would
+#[derive(Default)]
+pub(crate) struct JsonCache(Mutex<BTreeMap<String, String>>);
+
+impl JsonCache {
+    /// all entries, ordered by key
+    pub fn entries(&self) -> Vec<(String, String)> {
+        let entries = self.0.lock().unwrap();
+        entries
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl DynCache for JsonCache {
+    async fn insert(&self, key: CacheKey, value: CacheValue, _expiring: NaiveDateTime) {
+        // like the redis cache, values that can't be serialized are skipped
+        if let Some(json) = value.to_json() {
+            self.0.lock().unwrap().insert(key.name, json);
+        }
+    }
+
+    async fn get(&self, key: &CacheKey) -> Option<(Cached, Option<NaiveDateTime>)> {
+        let json = self.0.lock().unwrap().get(&key.name)?.clone();
+        Some((Cached::Json(json), None))
+    }
+
+    async fn remove(&self, key: &CacheKey) {
+        self.0.lock().unwrap().remove(&key.name);
+    }
+
+    async fn cleanup(&self) {}
+
+    async fn wipe_static(&self) {}
+
+    async fn wipe_authenticated(&self) {}
+}
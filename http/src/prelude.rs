@@ -0,0 +1,11 @@
+//! the traits and types needed by most users of this crate
+//!
+//! ```
+//! use gw2lib::prelude::*;
+//! ```
+//!
+//! Models aren't part of the prelude, import them from [`crate::model`].
+
+pub use gw2lib_model::{BulkEndpoint, Endpoint, EndpointWithId, FixedEndpoint, Language};
+
+pub use crate::{Client, EndpointError, EndpointResult, Requester};
@@ -0,0 +1,142 @@
+use std::{fmt, marker::PhantomData, ops::Deref};
+
+use gw2lib_model::{BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId, FixedEndpoint, Method};
+use serde::{de::DeserializeOwned, ser::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// a model converted into `U` right after it was parsed
+///
+/// `Projected` forwards the endpoint traits of `T`, so it can be requested
+/// like `T` itself, including through [`Requester::many`] and
+/// [`Requester::all`]. The cache only keeps the converted values.
+///
+/// Requesting multiple ids needs the id of the converted value, implement
+/// [`ProjectedId`] for `U` for that.
+///
+/// ### Remarks
+/// `U` can't be turned back into `T`, so `Projected` values fail to
+/// serialize. Caches that store serialized values, like the redis cache,
+/// skip them and keep only entries of `T` itself, which are read as
+/// `Projected` as well. [`Client::strict_schema`] doesn't check them.
+/// ## Example
+/// ```no_run
+/// use gw2lib::{
+///     model::items::{Item, ItemId},
+///     Client, Projected, ProjectedId, Requester,
+/// };
+///
+/// #[derive(Clone)]
+/// struct Name {
+///     id: ItemId,
+///     name: String,
+/// }
+///
+/// impl From<Item> for Name {
+///     fn from(item: Item) -> Self {
+///         Self {
+///             id: item.id,
+///             name: item.name,
+///         }
+///     }
+/// }
+///
+/// impl ProjectedId<ItemId> for Name {
+///     fn id(&self) -> &ItemId {
+///         &self.id
+///     }
+/// }
+///
+/// # async fn example() -> gw2lib::EndpointResult<()> {
+/// let client = Client::default();
+/// let names: Vec<Projected<Item, Name>> = client.many(vec![19721_u32, 19993]).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Requester::many`]: crate::Requester::many
+/// [`Requester::all`]: crate::Requester::all
+/// [`Client::strict_schema`]: crate::Client::strict_schema
+pub struct Projected<T, U> {
+    pub value: U,
+    source: PhantomData<fn() -> T>,
+}
+
+/// the id of a [`Projected`] value
+pub trait ProjectedId<I> {
+    fn id(&self) -> &I;
+}
+
+impl<T, U> Projected<T, U> {
+    pub fn into_inner(self) -> U {
+        self.value
+    }
+}
+
+impl<T, U> Deref for Projected<T, U> {
+    type Target = U;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, U: Clone> Clone for Projected<T, U> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            source: PhantomData,
+        }
+    }
+}
+
+impl<T, U: fmt::Debug> fmt::Debug for Projected<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Projected").field(&self.value).finish()
+    }
+}
+
+impl<'de, T: DeserializeOwned, U: From<T>> Deserialize<'de> for Projected<T, U> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = T::deserialize(deserializer)?;
+        Ok(Self {
+            value: U::from(value),
+            source: PhantomData,
+        })
+    }
+}
+
+impl<T, U> Serialize for Projected<T, U> {
+    fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(S::Error::custom("projected values can't be serialized"))
+    }
+}
+
+impl<T: Endpoint, U> Endpoint for Projected<T, U> {
+    const AUTHENTICATED: bool = T::AUTHENTICATED;
+    const INFO: EndpointInfo = T::INFO;
+    const LOCALE: bool = T::LOCALE;
+    const METHOD: Method = T::METHOD;
+    const URL: &'static str = T::URL;
+    const VERSION: &'static str = T::VERSION;
+}
+
+impl<T: EndpointWithId, U> EndpointWithId for Projected<T, U> {
+    type IdType = T::IdType;
+
+    fn format_id(id: &Self::IdType) -> String {
+        T::format_id(id)
+    }
+
+    fn format_url(id: &str) -> String {
+        T::format_url(id)
+    }
+}
+
+impl<T: FixedEndpoint, U> FixedEndpoint for Projected<T, U> {}
+
+impl<T: BulkEndpoint, U: ProjectedId<T::IdType>> BulkEndpoint for Projected<T, U> {
+    const ALL: bool = T::ALL;
+
+    fn id(&self) -> &Self::IdType {
+        self.value.id()
+    }
+}
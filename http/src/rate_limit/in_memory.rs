@@ -1,16 +1,26 @@
-use std::time::{Duration, Instant};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
 use tokio::sync::Mutex;
 
-use crate::{rate_limit::RateLimiter, EndpointError};
+use crate::{metrics::Metrics, rate_limit::RateLimiter, EndpointError};
 
+#[derive(Debug)]
 pub struct BucketRateLimiter {
     /// maximum number of requests in burst
     burst: usize,
     /// requests per minute
     refill: usize,
     time: Mutex<Instant>,
+    /// whether the last request was delayed
+    throttled: AtomicBool,
+    metrics: Option<Arc<dyn Metrics>>,
 }
 
 impl BucketRateLimiter {
@@ -24,6 +34,35 @@ impl BucketRateLimiter {
             burst,
             refill,
             time: base.into(),
+            throttled: AtomicBool::new(false),
+            metrics: None,
+        }
+    }
+
+    /// reports every delayed request to [`Metrics::on_throttle`]
+    ///
+    /// Pass the same instance to [`Client::metrics`](crate::Client::metrics)
+    /// to collect everything in one place.
+    pub fn metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// notifies about delayed requests and logs when throttling starts and
+    /// ends
+    fn record_delay(&self, delay: Duration) {
+        let throttled = !delay.is_zero();
+        let was_throttled = self.throttled.swap(throttled, Ordering::Relaxed);
+        #[cfg(feature = "tracing")]
+        match (was_throttled, throttled) {
+            (false, true) => tracing::info!(?delay, "rate limiter started throttling"),
+            (true, false) => tracing::info!("rate limiter stopped throttling"),
+            _ => {}
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = was_throttled;
+        if let (true, Some(metrics)) = (throttled, &self.metrics) {
+            metrics.on_throttle(delay);
         }
     }
 }
@@ -50,7 +89,10 @@ impl RateLimiter for BucketRateLimiter {
         }
         *time += Duration::from_millis(ratio * num as u64);
 
-        Ok(time.checked_duration_since(now).unwrap_or(Duration::ZERO))
+        let delay = time.checked_duration_since(now).unwrap_or(Duration::ZERO);
+        drop(time);
+        self.record_delay(delay);
+        Ok(delay)
     }
 
     async fn penalize(&self) -> Result<(), EndpointError> {
@@ -66,3 +108,37 @@ impl RateLimiter for BucketRateLimiter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Delays(Mutex<Vec<Duration>>);
+
+    impl Metrics for Delays {
+        fn on_throttle(&self, delay: Duration) {
+            self.0.lock().unwrap().push(delay);
+        }
+    }
+
+    #[test]
+    fn reports_throttling() {
+        let delays = Arc::new(Delays::default());
+        let limiter = BucketRateLimiter::new(2, 60).metrics(delays.clone());
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            for _ in 0..4 {
+                limiter.take(1).await.unwrap();
+            }
+        });
+        let delays = delays.0.lock().unwrap();
+        assert_eq!(delays.len(), 2);
+        assert!(delays[0] > Duration::ZERO);
+        assert!(delays[1] > delays[0]);
+    }
+}
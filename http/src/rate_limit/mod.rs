@@ -22,11 +22,15 @@ pub trait RateLimiter {
     async fn penalize(&self) -> Result<(), EndpointError>;
 }
 
+/// a rate limiter picked at runtime, see
+/// [`Client::dyn_builder`](crate::Client::dyn_builder)
+pub type DynRateLimiter = Box<dyn RateLimiter + Send + Sync>;
+
 #[async_trait]
 impl<T, K> RateLimiter for T
 where
     T: Deref<Target = K> + Sync,
-    K: RateLimiter + Sync,
+    K: RateLimiter + Sync + ?Sized,
 {
     async fn take(&self, num: usize) -> Result<Duration, EndpointError> {
         self.deref().take(num).await
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 
 use crate::{rate_limit::RateLimiter, EndpointError};
 
+#[derive(Clone, Copy, Debug, Default)]
 pub struct NoopRateLimiter;
 #[async_trait]
 impl RateLimiter for NoopRateLimiter {
@@ -0,0 +1,114 @@
+//! helpers around the daily and weekly resets of the game
+//!
+//! The daily reset happens at 00:00 UTC, the weekly reset on Monday at
+//! 07:30 UTC.
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc};
+
+const WEEKLY_RESET: NaiveTime = match NaiveTime::from_hms_opt(7, 30, 0) {
+    Some(time) => time,
+    None => unreachable!(),
+};
+
+/// the first daily reset strictly after `now`
+///
+/// If `now` is exactly at reset, this returns the reset a day later.
+pub fn next_daily_reset(now: DateTime<Utc>) -> DateTime<Utc> {
+    (now.date_naive() + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc()
+}
+
+/// the first weekly reset strictly after `now`
+///
+/// If `now` is exactly at reset, this returns the reset a week later.
+pub fn next_weekly_reset(now: DateTime<Utc>) -> DateTime<Utc> {
+    let monday = now.date_naive() - Duration::days(now.weekday().num_days_from_monday().into());
+    let reset = monday.and_time(WEEKLY_RESET).and_utc();
+    if reset > now {
+        reset
+    } else {
+        reset + Duration::weeks(1)
+    }
+}
+
+/// whether no daily reset happened between `a` and `b`
+pub fn is_same_reset_day(a: DateTime<Utc>, b: DateTime<Utc>) -> bool {
+    a.date_naive() == b.date_naive()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, s).unwrap()
+    }
+
+    #[test]
+    fn daily_reset() {
+        assert_eq!(
+            next_daily_reset(at(2024, 3, 9, 12, 0, 0)),
+            at(2024, 3, 10, 0, 0, 0)
+        );
+        assert_eq!(
+            next_daily_reset(at(2024, 3, 9, 23, 59, 59)),
+            at(2024, 3, 10, 0, 0, 0)
+        );
+        assert_eq!(
+            next_daily_reset(at(2024, 12, 31, 18, 0, 0)),
+            at(2025, 1, 1, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn daily_reset_exactly_at_reset() {
+        assert_eq!(
+            next_daily_reset(at(2024, 3, 10, 0, 0, 0)),
+            at(2024, 3, 11, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn weekly_reset() {
+        // 2024-03-11 is a monday
+        assert_eq!(
+            next_weekly_reset(at(2024, 3, 9, 12, 0, 0)),
+            at(2024, 3, 11, 7, 30, 0)
+        );
+        assert_eq!(
+            next_weekly_reset(at(2024, 3, 11, 7, 29, 59)),
+            at(2024, 3, 11, 7, 30, 0)
+        );
+        assert_eq!(
+            next_weekly_reset(at(2024, 3, 11, 0, 0, 0)),
+            at(2024, 3, 11, 7, 30, 0)
+        );
+        assert_eq!(
+            next_weekly_reset(at(2024, 3, 17, 23, 59, 59)),
+            at(2024, 3, 18, 7, 30, 0)
+        );
+    }
+
+    #[test]
+    fn weekly_reset_exactly_at_reset() {
+        assert_eq!(
+            next_weekly_reset(at(2024, 3, 11, 7, 30, 0)),
+            at(2024, 3, 18, 7, 30, 0)
+        );
+    }
+
+    #[test]
+    fn same_reset_day() {
+        assert!(is_same_reset_day(
+            at(2024, 3, 9, 0, 0, 0),
+            at(2024, 3, 9, 23, 59, 59)
+        ));
+        assert!(!is_same_reset_day(
+            at(2024, 3, 9, 23, 59, 59),
+            at(2024, 3, 10, 0, 0, 0)
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-#![cfg(feature = "blocking")]
+#![cfg(all(feature = "blocking", feature = "characters"))]
 
 use gw2lib::{model::authenticated::account::bank::Bank, Requester};
 
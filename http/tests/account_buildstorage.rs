@@ -0,0 +1,35 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{
+    model::authenticated::{account::buildstorage::AccountBuildStorage, characters::Profession},
+    Requester,
+};
+
+pub mod setup;
+
+#[test]
+fn eff_testing() {
+    let client = setup::setup();
+    let _: AccountBuildStorage = client.get().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"[{
+        "name": "Fresh Air",
+        "profession": "Elementalist",
+        "specializations": [
+            { "id": 41, "traits": [232, 214, 226] },
+            { "id": null, "traits": [null, null, null] },
+            { "id": 48, "traits": [2144, 2204, 2208] }
+        ],
+        "skills": { "heal": 5503, "utilities": [5542, 5541, null], "elite": 5516 },
+        "aquatic_skills": { "heal": 5569, "utilities": [5570, 5567, 5639], "elite": null }
+    }]"#;
+    let storage: AccountBuildStorage = serde_json::from_str(json).unwrap();
+    let build = &storage[0];
+    assert_eq!(build.name.as_deref(), Some("Fresh Air"));
+    assert_eq!(build.profession, Some(Profession::Elementalist));
+    assert_eq!(build.specializations[1].id, None);
+    assert_eq!(build.skills.utilities[2], None);
+}
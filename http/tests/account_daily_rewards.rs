@@ -0,0 +1,12 @@
+#![cfg(all(feature = "blocking", feature = "achievements"))]
+
+use gw2lib::Requester;
+
+pub mod setup;
+
+#[test]
+fn eff_testing() {
+    let client = setup::setup();
+    let progress = client.daily_progress().unwrap();
+    assert!(!progress.world_bosses.is_empty());
+}
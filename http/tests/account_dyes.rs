@@ -0,0 +1,12 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{model::authenticated::account::dyes::AccountDyes, Requester};
+
+pub mod setup;
+
+#[test]
+fn eff_testing() {
+    let client = setup::setup();
+    let unlocked: AccountDyes = client.get().unwrap();
+    assert!(!unlocked.is_empty());
+}
@@ -0,0 +1,24 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{model::authenticated::account::finishers::AccountFinishers, Requester};
+
+pub mod setup;
+
+#[test]
+fn eff_testing() {
+    let client = setup::setup();
+    let _: AccountFinishers = client.get().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"[
+        { "id": 1, "permanent": true },
+        { "id": 14, "permanent": false, "quantity": 5 }
+    ]"#;
+    let finishers: AccountFinishers = serde_json::from_str(json).unwrap();
+    assert!(finishers[0].permanent);
+    assert_eq!(finishers[0].quantity, None);
+    assert!(!finishers[1].permanent);
+    assert_eq!(finishers[1].quantity, Some(5));
+}
@@ -0,0 +1,32 @@
+#![cfg(all(feature = "blocking", feature = "misc"))]
+
+use gw2lib::{
+    model::authenticated::account::homestead::{
+        AccountHomesteadDecorations, AccountHomesteadGlyphs,
+    },
+    Requester,
+};
+
+pub mod setup;
+
+#[test]
+fn decorations() {
+    let client = setup::setup();
+    let _: AccountHomesteadDecorations = client.get().unwrap();
+}
+
+#[test]
+fn glyphs() {
+    let client = setup::setup();
+    let _: AccountHomesteadGlyphs = client.get().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"[{ "id": 11, "count": 4 }, { "id": 12, "count": 1 }]"#;
+    let decorations: AccountHomesteadDecorations = serde_json::from_str(json).unwrap();
+    assert_eq!(decorations[0].count, 4);
+    let glyphs: AccountHomesteadGlyphs =
+        serde_json::from_str(r#"["harvesting_volatility", "logging_bountiful"]"#).unwrap();
+    assert_eq!(glyphs.len(), 2);
+}
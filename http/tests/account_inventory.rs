@@ -1,6 +1,9 @@
-#![cfg(feature = "blocking")]
+#![cfg(all(feature = "blocking", feature = "characters"))]
 
-use gw2lib::{model::authenticated::account::inventory::AccountInventory, Requester};
+use gw2lib::{
+    model::authenticated::{account::inventory::AccountInventory, characters::Binding},
+    Requester,
+};
 
 pub mod setup;
 
@@ -9,3 +12,21 @@ fn eff_testing() {
     let client = setup::setup();
     let _: AccountInventory = client.get().unwrap();
 }
+
+#[test]
+fn partially_filled() {
+    let json = r#"[
+        null,
+        { "id": 78599, "count": 1, "binding": "Account" },
+        null,
+        { "id": 49308, "count": 3 }
+    ]"#;
+    let inventory: AccountInventory = serde_json::from_str(json).unwrap();
+    assert_eq!(inventory.len(), 4);
+    assert!(inventory[0].is_none());
+    assert!(inventory[2].is_none());
+    let slot = inventory[1].as_ref().unwrap();
+    assert_eq!(slot.id, 78599);
+    assert_eq!(slot.binding, Some(Binding::Account));
+    assert_eq!(inventory[3].as_ref().unwrap().count, 3);
+}
@@ -0,0 +1,19 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{model::authenticated::account::legendary_armory::AccountLegendaryArmory, Requester};
+
+pub mod setup;
+
+#[test]
+fn eff_testing() {
+    let client = setup::setup();
+    let _: AccountLegendaryArmory = client.get().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"[{ "id": 80111, "count": 1 }, { "id": 81908, "count": 2 }]"#;
+    let armory: AccountLegendaryArmory = serde_json::from_str(json).unwrap();
+    assert_eq!(armory.len(), 2);
+    assert_eq!(armory[1].count, 2);
+}
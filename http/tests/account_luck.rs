@@ -0,0 +1,23 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{model::authenticated::account::luck::AccountLuck, Requester};
+
+pub mod setup;
+
+#[test]
+fn eff_testing() {
+    let client = setup::setup();
+    let _: AccountLuck = client.get().unwrap();
+}
+
+#[test]
+fn parse() {
+    let luck: AccountLuck = serde_json::from_str(r#"[{ "id": "luck", "value": 8120 }]"#).unwrap();
+    assert_eq!(luck, AccountLuck(8120));
+}
+
+#[test]
+fn parse_empty() {
+    let luck: AccountLuck = serde_json::from_str("[]").unwrap();
+    assert_eq!(luck, AccountLuck(0));
+}
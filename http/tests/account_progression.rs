@@ -0,0 +1,25 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{model::authenticated::account::progression::AccountProgression, Requester};
+
+pub mod setup;
+
+#[test]
+fn eff_testing() {
+    let client = setup::setup();
+    let _: AccountProgression = client.get().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"[
+        { "id": "fractal_agony_impedance", "value": 15 },
+        { "id": "fractal_empowerment", "value": 250 },
+        { "id": "luck", "value": 8120 }
+    ]"#;
+    let progression: AccountProgression = serde_json::from_str(json).unwrap();
+    assert_eq!(progression.len(), 3);
+    assert_eq!(progression.value("fractal_agony_impedance"), Some(15));
+    assert_eq!(progression.value("luck"), Some(8120));
+    assert_eq!(progression.value("unknown"), None);
+}
@@ -0,0 +1,12 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{model::authenticated::account::skins::AccountSkins, Requester};
+
+pub mod setup;
+
+#[test]
+fn eff_testing() {
+    let client = setup::setup();
+    let unlocked: AccountSkins = client.get().unwrap();
+    assert!(!unlocked.is_empty());
+}
@@ -0,0 +1,19 @@
+#![cfg(all(feature = "blocking", feature = "wvw"))]
+
+use gw2lib::{model::authenticated::account::wvw::AccountWvw, Requester};
+
+pub mod setup;
+
+#[test]
+fn eff_testing() {
+    let client = setup::setup();
+    let _: AccountWvw = client.get().unwrap();
+}
+
+#[test]
+fn parse() {
+    let wvw: AccountWvw = serde_json::from_str(r#"{ "team": 11005, "rank": 1042 }"#).unwrap();
+    assert_eq!(wvw.team, Some(11005));
+    let unassigned: AccountWvw = serde_json::from_str("{}").unwrap();
+    assert_eq!(unassigned.team, None);
+}
@@ -0,0 +1,35 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{
+    model::story::backstory::{BackstoryAnswer, BackstoryAnswerId, BackstoryQuestion},
+    Requester,
+};
+
+pub mod setup;
+
+#[test]
+fn all_questions() {
+    let client = setup::setup();
+    let _: Vec<BackstoryQuestion> = client.all().unwrap();
+}
+
+#[test]
+fn all_answers() {
+    let client = setup::setup();
+    let _: Vec<BackstoryAnswer> = client.all().unwrap();
+}
+
+#[test]
+fn profession_gated_question() {
+    let client = setup::setup();
+    let questions: Vec<BackstoryQuestion> = client.all().unwrap();
+    let question = questions
+        .into_iter()
+        .find(|q| q.professions.is_some())
+        .expect("no profession specific question");
+    let answers: Vec<BackstoryAnswer> = client
+        .many::<BackstoryAnswer, BackstoryAnswerId>(question.answers.clone())
+        .unwrap();
+    assert_eq!(answers.len(), question.answers.len());
+    assert!(answers.iter().all(|a| a.question == question.id));
+}
@@ -1,8 +1,14 @@
-#![cfg(feature = "blocking")]
+#![cfg(all(feature = "blocking", feature = "characters"))]
 
 use gw2lib::{
-    model::authenticated::characters::{
-        Backstory, Character, CharacterId, Core, Crafting, Equipment, Inventory, Recipes, Training,
+    model::{
+        authenticated::characters::{
+            Backstory, Character, CharacterId, CharacterNames, Core, Crafting, Discipline,
+            Equipment, EquipmentPvp, HeroPoints, Inventory, Quests, Recipes, Skills,
+            Specializations, Training,
+        },
+        items::recipes::{Recipe, RecipeId},
+        shared::{CraftingDiscipline, Profession, Race},
     },
     Requester,
 };
@@ -29,6 +35,13 @@ fn ids() {
     let _: Vec<CharacterId> = client.ids::<Character, _>().unwrap();
 }
 
+#[test]
+fn names() {
+    let client = setup::setup();
+    let names: CharacterNames = client.get().unwrap();
+    assert!(names.contains(&character_name()));
+}
+
 #[test]
 fn core() {
     let client = setup::setup();
@@ -47,6 +60,67 @@ fn crafting() {
     let _: Crafting = client.single(character_name()).unwrap();
 }
 
+#[test]
+fn equipment_pvp_sigil_count() {
+    let json = r#"{ "amulet": 1, "rune": 2, "sigils": [3, null] }"#;
+    let pvp: EquipmentPvp = serde_json::from_str(json).unwrap();
+    assert_eq!(pvp.sigils, [Some(3), None]);
+
+    let json = r#"{ "amulet": 1, "rune": 2, "sigils": [3, 4, null, 5] }"#;
+    let pvp: EquipmentPvp = serde_json::from_str(json).unwrap();
+    assert_eq!(pvp.sigils.len(), 4);
+}
+
+#[test]
+fn crafting_maxed_disciplines() {
+    let json = r#"{
+        "crafting": [
+            { "discipline": "Armorsmith", "rating": 500, "active": true },
+            { "discipline": "Weaponsmith", "rating": 500, "active": true },
+            { "discipline": "Chef", "rating": 400, "active": false }
+        ]
+    }"#;
+    let crafting: Crafting = serde_json::from_str(json).unwrap();
+    let maxed: Vec<_> = crafting
+        .crafting
+        .iter()
+        .filter(|c| c.rating == 500)
+        .map(|c| c.discipline.clone())
+        .collect();
+    assert_eq!(maxed, [Discipline::Armorsmith, Discipline::Weaponsmith]);
+    assert_eq!(crafting.crafting.iter().filter(|c| c.active).count(), 2);
+}
+
+#[test]
+fn parse_unknown_profession() {
+    let json = r#"{
+        "name": "Char",
+        "race": "Tengu",
+        "gender": "Female",
+        "profession": "Warrior",
+        "level": 80,
+        "guild": null,
+        "age": 1,
+        "created": "2024-01-01T00:00:00Z",
+        "last_modified": "2024-01-01T00:00:00Z",
+        "deaths": 0,
+        "title": null
+    }"#;
+    let core: Core = serde_json::from_str(json).unwrap();
+    assert_eq!(core.profession, Profession::Warrior);
+    assert_eq!(core.race, Race::Unknown("Tengu".to_string()));
+    assert_eq!(serde_json::to_value(&core.race).unwrap(), "Tengu");
+}
+
+#[test]
+fn shared_enums_round_trip() {
+    assert_eq!("Scribe".parse(), Ok(CraftingDiscipline::Scribe));
+    assert_eq!(CraftingDiscipline::Scribe.to_string(), "Scribe");
+    assert_eq!(Profession::Revenant.as_str(), "Revenant");
+    let discipline: Discipline = "Chef".parse().unwrap();
+    assert_eq!(discipline, CraftingDiscipline::Chef);
+}
+
 #[test]
 fn equipment() {
     let client = setup::setup();
@@ -59,14 +133,62 @@ fn inventory() {
     let _: Inventory = client.single(character_name()).unwrap();
 }
 
+#[test]
+fn heropoints() {
+    let client = setup::setup();
+    let _: HeroPoints = client.single(character_name()).unwrap();
+}
+
+#[test]
+fn parse_heropoints() {
+    let json = r#"["0-4", "0-5", "1-12"]"#;
+    let heropoints: HeroPoints = serde_json::from_str(json).unwrap();
+    assert_eq!(heropoints.len(), 3);
+    assert!(heropoints.contains(&"1-12".to_string()));
+}
+
+#[test]
+fn quests() {
+    let client = setup::setup();
+    let _: Quests = client.single(character_name()).unwrap();
+}
+
+#[test]
+fn parse_quests() {
+    let quests: Quests = serde_json::from_str("[1, 2, 15]").unwrap();
+    assert_eq!(*quests, [1, 2, 15]);
+}
+
 #[test]
 fn recipes() {
     let client = setup::setup();
     let _: Recipes = client.single(character_name()).unwrap();
 }
 
+#[test]
+fn recipes_resolve() {
+    let client = setup::setup();
+    let unlocked: Recipes = client.single(character_name()).unwrap();
+    let ids: Vec<RecipeId> = unlocked.recipes.into_iter().take(10).collect();
+    let recipes: Vec<Recipe> = client.many(ids.clone()).unwrap();
+    assert_eq!(recipes.len(), ids.len());
+}
+
 #[test]
 fn training() {
     let client = setup::setup();
     let _: Training = client.single(character_name()).unwrap();
 }
+
+#[test]
+fn skills() {
+    let client = setup::setup();
+    let skills: Skills = client.single(character_name()).unwrap();
+    assert!(skills.skills.pve.heal.is_some());
+}
+
+#[test]
+fn specializations() {
+    let client = setup::setup();
+    let _: Specializations = client.single(character_name()).unwrap();
+}
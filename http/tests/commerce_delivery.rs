@@ -1,4 +1,4 @@
-#![cfg(feature = "blocking")]
+#![cfg(all(feature = "blocking", feature = "commerce"))]
 
 use gw2lib::{model::authenticated::commerce::delivery::Delivery, Requester};
 
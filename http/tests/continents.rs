@@ -1,4 +1,4 @@
-#![cfg(feature = "blocking")]
+#![cfg(all(feature = "blocking", feature = "misc"))]
 
 use gw2lib::{
     model::maps::continents::{Continent, Floor},
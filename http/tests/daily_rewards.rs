@@ -0,0 +1,16 @@
+#![cfg(all(feature = "blocking", feature = "achievements"))]
+
+use gw2lib::{
+    model::daily_rewards::{DailyCrafting, MapChest, WorldBoss},
+    Requester,
+};
+
+pub mod setup;
+
+#[test]
+fn parse_all() {
+    let client = setup::setup();
+    let _: Vec<WorldBoss> = client.all().unwrap();
+    let _: Vec<DailyCrafting> = client.all().unwrap();
+    let _: Vec<MapChest> = client.all().unwrap();
+}
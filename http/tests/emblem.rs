@@ -0,0 +1,45 @@
+#![cfg(all(feature = "blocking", feature = "guild"))]
+
+use gw2lib::{
+    model::guild::emblem::{EmblemBackground, EmblemForeground},
+    Requester,
+};
+
+pub mod setup;
+
+#[test]
+fn all_backgrounds() {
+    let client = setup::setup();
+    let _: Vec<EmblemBackground> = client.all().unwrap();
+}
+
+#[test]
+fn all_foregrounds() {
+    let client = setup::setup();
+    let _: Vec<EmblemForeground> = client.all().unwrap();
+}
+
+#[test]
+fn parse_background() {
+    let json = r#"{
+        "id": 1,
+        "layers": ["https://render.guildwars2.com/file/F4A5D42FC1E8C52AE5EB84B9C3DE61A5E0A5A4F0/59599.png"]
+    }"#;
+    let background: EmblemBackground = serde_json::from_str(json).unwrap();
+    assert_eq!(background.id, 1);
+    assert_eq!(background.layers.len(), 1);
+}
+
+#[test]
+fn parse_foreground() {
+    let json = r#"{
+        "id": 1,
+        "layers": [
+            "https://render.guildwars2.com/file/2F1E3A7A1E8C4F1D7B2A6B7A8E3B0C6F9D0E1A2B/59603.png",
+            "https://render.guildwars2.com/file/1F2E3A7A1E8C4F1D7B2A6B7A8E3B0C6F9D0E1A2B/59604.png",
+            "https://render.guildwars2.com/file/0F2E3A7A1E8C4F1D7B2A6B7A8E3B0C6F9D0E1A2B/59605.png"
+        ]
+    }"#;
+    let foreground: EmblemForeground = serde_json::from_str(json).unwrap();
+    assert_eq!(foreground.layers.len(), 3);
+}
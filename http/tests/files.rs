@@ -0,0 +1,19 @@
+#![cfg(all(feature = "blocking", feature = "misc"))]
+
+use gw2lib::{model::misc::files::File, Requester};
+
+pub mod setup;
+
+#[test]
+fn all() {
+    let client = setup::setup();
+    let _: Vec<File> = client.all().unwrap();
+}
+
+#[test]
+fn single() {
+    let client = setup::setup();
+    let file: File = client.single("map_complete".to_string()).unwrap();
+    assert_eq!(file.id, "map_complete");
+    assert!(file.icon.starts_with("https://"));
+}
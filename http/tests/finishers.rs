@@ -0,0 +1,11 @@
+#![cfg(all(feature = "blocking", feature = "items"))]
+
+use gw2lib::{model::items::finishers::Finisher, Requester};
+
+pub mod setup;
+
+#[test]
+fn all() {
+    let client = setup::setup();
+    let _: Vec<Finisher> = client.all().unwrap();
+}
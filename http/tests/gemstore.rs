@@ -0,0 +1,30 @@
+#![cfg(all(feature = "blocking", feature = "commerce"))]
+
+use gw2lib::{
+    model::gemstore::{CoinsToGems, GemsToCoins},
+    Requester,
+};
+
+pub mod setup;
+
+#[test]
+fn coins_to_gems() {
+    let client = setup::setup();
+    let rate: CoinsToGems = client.single(1_000_000).unwrap();
+    assert!(rate.quantity > 0);
+}
+
+#[test]
+fn gems_to_coins() {
+    let client = setup::setup();
+    let rate: GemsToCoins = client.single(100).unwrap();
+    assert!(rate.coins_per_gem > 0);
+}
+
+#[test]
+fn parse() {
+    let rate: GemsToCoins =
+        serde_json::from_str(r#"{ "coins_per_gem": 2935, "quantity": 293562 }"#).unwrap();
+    assert_eq!(rate.coins_per_gem, 2935);
+    assert_eq!(rate.quantity, 293562);
+}
@@ -1,4 +1,4 @@
-#![cfg(feature = "blocking")]
+#![cfg(all(feature = "blocking", feature = "misc"))]
 #![allow(dead_code)]
 
 use std::sync::Arc;
@@ -10,12 +10,51 @@ use gw2lib::{
 
 pub mod setup;
 
+#[test]
+fn endpoint_info() {
+    use gw2lib::{
+        model::{authenticated::account::Account, endpoint_info, misc::colors::Color},
+        Projected,
+    };
+
+    let info = endpoint_info::<Account>();
+    assert!(info.authenticated);
+    assert!(!info.supports_paging);
+
+    let info = endpoint_info::<Color>();
+    assert_eq!(info.url, "v2/colors");
+    assert!(info.locale);
+    assert!(info.supports_all);
+    assert!(info.supports_paging);
+
+    assert_eq!(endpoint_info::<Projected<Color, ColorId>>(), info);
+}
+
 #[test]
 fn get() {
     let client = setup::setup();
     let _: Build = client.get().unwrap();
 }
 
+#[test]
+fn raw_get() {
+    let client = setup::setup();
+    let build = client.raw_get("v2/build", &[]).unwrap();
+    assert!(build["id"].is_u64());
+}
+
+#[test]
+fn raw_typed() {
+    #[derive(serde::Deserialize)]
+    struct RawBuild {
+        id: u64,
+    }
+
+    let client = setup::setup();
+    let build: RawBuild = client.raw_typed("v2/build", &[]).unwrap();
+    assert!(build.id > 0);
+}
+
 //#[test]
 fn inflight() {
     let client = Arc::new(setup::setup());
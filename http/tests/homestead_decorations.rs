@@ -0,0 +1,35 @@
+#![cfg(all(feature = "blocking", feature = "misc"))]
+
+use gw2lib::{
+    model::home_instance::decorations::{DecorationCategory, HomesteadDecoration},
+    Requester,
+};
+
+pub mod setup;
+
+#[test]
+fn all() {
+    let client = setup::setup();
+    let _: Vec<HomesteadDecoration> = client.all().unwrap();
+}
+
+#[test]
+fn categories() {
+    let client = setup::setup();
+    let _: Vec<DecorationCategory> = client.all().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"{
+        "id": 11,
+        "name": "Rustic Bench",
+        "description": "A sturdy wooden bench.",
+        "max_count": 250,
+        "icon": "https://render.guildwars2.com/file/bench.png",
+        "categories": [3]
+    }"#;
+    let decoration: HomesteadDecoration = serde_json::from_str(json).unwrap();
+    assert_eq!(decoration.max_count, 250);
+    assert_eq!(decoration.categories, vec![3]);
+}
@@ -0,0 +1,21 @@
+#![cfg(all(feature = "blocking", feature = "misc"))]
+
+use gw2lib::{
+    model::home_instance::glyphs::{GlyphSlot, HomesteadGlyph},
+    Requester,
+};
+
+pub mod setup;
+
+#[test]
+fn all() {
+    let client = setup::setup();
+    let _: Vec<HomesteadGlyph> = client.all().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"{ "id": "harvesting_volatility", "item_id": 100916, "slot": "harvesting" }"#;
+    let glyph: HomesteadGlyph = serde_json::from_str(json).unwrap();
+    assert_eq!(glyph.slot, GlyphSlot::Harvesting);
+}
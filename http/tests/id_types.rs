@@ -0,0 +1,174 @@
+//! compile time check that every id type works with the whole requester
+#![cfg(all(
+    feature = "items",
+    feature = "commerce",
+    feature = "characters",
+    feature = "guild",
+    feature = "wvw",
+    feature = "achievements",
+    feature = "misc"
+))]
+
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use gw2lib::model::{
+    authenticated::characters::{
+        Backstory, Character, Core, Crafting, Equipment, HeroPoints, Inventory, QuestId, Quests,
+        Recipes, Skills, Specializations, Training,
+    },
+    daily_rewards::{DailyCrafting, MapChest, WorldBoss},
+    game_mechanics::{
+        legends::Legend,
+        outfits::{Outfit, OutfitId},
+        pets::{Pet, PetId},
+    },
+    gemstore::{CoinsToGems, GemsToCoins},
+    guild::emblem::{EmblemBackground, EmblemBackgroundId, EmblemForeground, EmblemForegroundId},
+    home_instance::{
+        cats::{Cat, CatId},
+        decorations::{
+            DecorationCategory, DecorationCategoryId, DecorationId, HomesteadDecoration,
+        },
+        glyphs::HomesteadGlyph,
+        nodes::Node,
+    },
+    items::{
+        finishers::{Finisher, FinisherId},
+        itemstats::ItemStat,
+        legendary_armory::LegendaryArmory,
+        recipes::{Recipe, RecipeId},
+        skins::{Skin, SkinId},
+        Item, ItemId,
+    },
+    maps::{
+        continents::{Continent, ContinentFloorId, ContinentId, Floor, FloorId},
+        Map, MapId,
+    },
+    misc::{
+        colors::{Color, ColorId},
+        currencies::{Currency, CurrencyId},
+        files::File,
+        quaggans::Quaggan,
+        raids::Raid,
+        worlds::{World, WorldId},
+    },
+    story::{
+        backstory::{BackstoryAnswer, BackstoryQuestion},
+        stories::{Story, StoryId, StorySeason},
+    },
+    tradingpost::{Listings, Prices},
+    wvw::{
+        abilities::{Ability, AbilityId},
+        upgrades::{Upgrade, UpgradeId},
+    },
+    EndpointWithId,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// the bounds `single`, `many` and `ids` put on the id type
+fn assert_id_bounds<T>()
+where
+    T: EndpointWithId,
+    T::IdType: Display + DeserializeOwned + Serialize + Hash + Clone + Eq + Send + Sync + 'static,
+{
+}
+
+macro_rules! assert_id_bounds {
+    ($($t:ty),* $(,)?) => {
+        $(assert_id_bounds::<$t>();)*
+    };
+}
+
+#[test]
+fn id_types_satisfy_requester_bounds() {
+    assert_id_bounds!(
+        Ability,
+        Backstory,
+        BackstoryAnswer,
+        BackstoryQuestion,
+        Cat,
+        Character,
+        CoinsToGems,
+        Color,
+        Continent,
+        Core,
+        Crafting,
+        Currency,
+        DailyCrafting,
+        DecorationCategory,
+        EmblemBackground,
+        EmblemForeground,
+        Equipment,
+        File,
+        Finisher,
+        Floor,
+        GemsToCoins,
+        HeroPoints,
+        HomesteadDecoration,
+        HomesteadGlyph,
+        Inventory,
+        Item,
+        ItemStat,
+        Legend,
+        LegendaryArmory,
+        Listings,
+        Map,
+        MapChest,
+        Node,
+        Outfit,
+        Pet,
+        Prices,
+        Quaggan,
+        Quests,
+        Raid,
+        Recipe,
+        Recipes,
+        Skills,
+        Skin,
+        Specializations,
+        Story,
+        StorySeason,
+        Training,
+        Upgrade,
+        World,
+        WorldBoss,
+    );
+}
+
+fn assert_sorts<I: Ord + From<u8> + Debug>() {
+    let mut ids: Vec<I> = vec![3.into(), 1.into(), 2.into()];
+    ids.sort();
+    assert_eq!(ids, [1.into(), 2.into(), 3.into()]);
+}
+
+#[test]
+fn numeric_ids_sort() {
+    assert_sorts::<AbilityId>();
+    assert_sorts::<CatId>();
+    assert_sorts::<ColorId>();
+    assert_sorts::<ContinentId>();
+    assert_sorts::<CurrencyId>();
+    assert_sorts::<DecorationCategoryId>();
+    assert_sorts::<DecorationId>();
+    assert_sorts::<EmblemBackgroundId>();
+    assert_sorts::<EmblemForegroundId>();
+    assert_sorts::<FinisherId>();
+    assert_sorts::<FloorId>();
+    assert_sorts::<ItemId>();
+    assert_sorts::<MapId>();
+    assert_sorts::<OutfitId>();
+    assert_sorts::<PetId>();
+    assert_sorts::<QuestId>();
+    assert_sorts::<RecipeId>();
+    assert_sorts::<SkinId>();
+    assert_sorts::<StoryId>();
+    assert_sorts::<UpgradeId>();
+    assert_sorts::<WorldId>();
+
+    let mut floors: Vec<ContinentFloorId> = vec![(2, 1).into(), (1, 3).into(), (1, -1).into()];
+    floors.sort();
+    assert_eq!(floors, [(1, -1).into(), (1, 3).into(), (2, 1).into()]);
+}
@@ -1,4 +1,4 @@
-#![cfg(feature = "blocking")]
+#![cfg(all(feature = "blocking", feature = "items"))]
 
 use gw2lib::{model::items::Item, Requester};
 
@@ -9,7 +9,7 @@ macro_rules! parse_single {
     ($name:ident, $id:expr, $validate:expr) => {
         #[test]
         fn $name() {
-            let client = crate::setup::setup();
+            let client = $crate::setup::setup();
             let x: gw2lib::model::items::Item = client.single($id).unwrap();
             #[allow(clippy::redundant_closure_call)]
             ($validate)(x);
@@ -31,6 +31,55 @@ fn parse_all() {
     let _: Vec<Item> = client.all().unwrap();
 }
 
+#[test]
+fn ids() {
+    let client = crate::setup::setup();
+    let ids = client.ids::<Item, _>().unwrap();
+    assert!(ids.len() > 10_000);
+}
+
+#[test]
+fn parse_upgrades() {
+    use gw2lib::model::items::{ItemUpgrade, ItemUpgradeType};
+
+    let json = r#"{
+        "id": 49371, "chat_link": "[&AgHbwAAA]", "name": "Mist Band (Infused)",
+        "rarity": "Ascended", "level": 80, "vendor_value": 0, "flags": [], "game_types": [],
+        "restrictions": [], "type": "Trophy",
+        "upgrades_into": [{ "upgrade": "Infusion", "item_id": 80058 }],
+        "upgrades_from": [{ "upgrade": "Attunement", "item_id": 37000 }]
+    }"#;
+    let item: Item = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        item.upgrades_into,
+        Some(vec![ItemUpgrade {
+            upgrade: ItemUpgradeType::Infusion,
+            item_id: 80058
+        }])
+    );
+    assert_eq!(
+        item.upgrades_from,
+        Some(vec![ItemUpgrade {
+            upgrade: ItemUpgradeType::Attunement,
+            item_id: 37000
+        }])
+    );
+}
+
+#[test]
+fn exists() {
+    let client = crate::setup::setup();
+    assert!(client.exists::<Item, _>(19721).unwrap());
+    assert!(!client.exists::<Item, _>(1).unwrap());
+}
+
+#[test]
+fn exists_many() {
+    let client = crate::setup::setup();
+    let exists = client.exists_many::<Item, _>(vec![19721, 1, 24]).unwrap();
+    assert_eq!(exists, [true, false, true]);
+}
+
 mod single {
     use gw2lib::{
         model::items::{
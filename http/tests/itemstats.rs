@@ -1,4 +1,4 @@
-#![cfg(feature = "blocking")]
+#![cfg(all(feature = "blocking", feature = "items"))]
 
 use gw2lib::{model::items::itemstats::ItemStat, Requester};
 
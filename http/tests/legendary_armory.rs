@@ -0,0 +1,19 @@
+#![cfg(all(feature = "blocking", feature = "items"))]
+
+use gw2lib::{model::items::legendary_armory::LegendaryArmory, Requester};
+
+pub mod setup;
+
+#[test]
+fn all() {
+    let client = setup::setup();
+    let _: Vec<LegendaryArmory> = client.all().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"[{ "id": 80111, "max_count": 1 }, { "id": 81908, "max_count": 4 }]"#;
+    let armory: Vec<LegendaryArmory> = serde_json::from_str(json).unwrap();
+    assert_eq!(armory[1].id, 81908);
+    assert_eq!(armory[1].max_count, 4);
+}
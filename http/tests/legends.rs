@@ -0,0 +1,42 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{
+    model::game_mechanics::legends::{Legend, LegendId},
+    Requester,
+};
+
+pub mod setup;
+
+#[test]
+fn all() {
+    let client = setup::setup();
+    let _: Vec<Legend> = client.all().unwrap();
+}
+
+#[test]
+fn ids() {
+    let client = setup::setup();
+    let _: Vec<LegendId> = client.ids::<Legend, LegendId>().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"{
+        "id": "Legend2", "code": 1, "swap": 28134, "heal": 26937, "elite": 28406,
+        "utilities": [27322, 27505, 27014]
+    }"#;
+    let legend: Legend = serde_json::from_str(json).unwrap();
+    assert_eq!(legend.utilities, [27322, 27505, 27014]);
+}
+
+#[test]
+fn parse_unusual_utility_count() {
+    let json = r#"[
+        { "id": "Legend9", "swap": 1, "heal": 2, "elite": 3, "utilities": [4, 5, 6, 7] },
+        { "id": "Legend10", "swap": 1, "heal": 2, "elite": 3, "utilities": [4, 5] }
+    ]"#;
+    let legends: Vec<Legend> = serde_json::from_str(json).unwrap();
+    assert_eq!(legends[0].utilities.len(), 4);
+    assert_eq!(legends[0].code, None);
+    assert_eq!(legends[1].utilities.len(), 2);
+}
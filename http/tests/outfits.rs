@@ -0,0 +1,11 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{model::game_mechanics::outfits::Outfit, Requester};
+
+pub mod setup;
+
+#[test]
+fn all() {
+    let client = setup::setup();
+    let _: Vec<Outfit> = client.all().unwrap();
+}
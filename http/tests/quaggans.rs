@@ -0,0 +1,20 @@
+#![cfg(all(feature = "blocking", feature = "misc"))]
+
+use gw2lib::{model::misc::quaggans::Quaggan, Requester};
+
+pub mod setup;
+
+#[test]
+fn all() {
+    let client = setup::setup();
+    let quaggans: Vec<Quaggan> = client.all().unwrap();
+    assert!(quaggans.iter().any(|q| q.id == "404"));
+}
+
+#[test]
+fn single() {
+    let client = setup::setup();
+    let quaggan: Quaggan = client.single("box".to_string()).unwrap();
+    assert_eq!(quaggan.id, "box");
+    assert!(quaggan.url.starts_with("https://"));
+}
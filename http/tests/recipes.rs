@@ -1,4 +1,4 @@
-#![cfg(feature = "blocking")]
+#![cfg(all(feature = "blocking", feature = "items"))]
 
 use gw2lib::{model::items::recipes::Recipe, Requester};
 
@@ -9,7 +9,7 @@ macro_rules! parse_single {
     ($name:ident, $id:expr, $validate:expr) => {
         #[test]
         fn $name() {
-            let client = crate::setup::setup();
+            let client = $crate::setup::setup();
             let x: gw2lib::model::items::recipes::Recipe = client.single($id).unwrap();
             #[allow(clippy::redundant_closure_call)]
             ($validate)(x);
@@ -0,0 +1,32 @@
+#![cfg(all(feature = "blocking", feature = "characters"))]
+
+use gw2lib::{
+    model::story::stories::{Story, StoryId, StorySeason},
+    Requester,
+};
+
+pub mod setup;
+
+#[test]
+fn all_stories() {
+    let client = setup::setup();
+    let _: Vec<Story> = client.all().unwrap();
+}
+
+#[test]
+fn all_seasons() {
+    let client = setup::setup();
+    let _: Vec<StorySeason> = client.all().unwrap();
+}
+
+#[test]
+fn season_stories() {
+    let client = setup::setup();
+    let seasons: Vec<StorySeason> = client.all().unwrap();
+    let season = seasons.first().expect("no seasons");
+    let stories: Vec<Story> = client
+        .many::<Story, StoryId>(season.stories.clone())
+        .unwrap();
+    assert_eq!(stories.len(), season.stories.len());
+    assert!(stories.iter().all(|s| s.season == season.id));
+}
@@ -0,0 +1,29 @@
+#![cfg(all(feature = "blocking", feature = "wvw"))]
+
+use gw2lib::{model::wvw::abilities::Ability, Requester};
+
+pub mod setup;
+
+#[test]
+fn all() {
+    let client = setup::setup();
+    let _: Vec<Ability> = client.all().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"{
+        "id": 2,
+        "name": "Guard Killer",
+        "description": "Increases damage to guards and lords.",
+        "icon": "https://render.guildwars2.com/file/guard_killer.png",
+        "ranks": [
+            { "cost": 1, "effect": "+2% damage to guards and lords" },
+            { "cost": 2, "effect": "+4% damage to guards and lords" },
+            { "cost": 3, "effect": "+6% damage to guards and lords" }
+        ]
+    }"#;
+    let ability: Ability = serde_json::from_str(json).unwrap();
+    assert_eq!(ability.ranks.len(), 3);
+    assert_eq!(ability.ranks[2].cost, 3);
+}
@@ -0,0 +1,34 @@
+#![cfg(all(feature = "blocking", feature = "wvw"))]
+
+use gw2lib::{model::wvw::upgrades::Upgrade, Requester};
+
+pub mod setup;
+
+#[test]
+fn all() {
+    let client = setup::setup();
+    let _: Vec<Upgrade> = client.all().unwrap();
+}
+
+#[test]
+fn parse() {
+    let json = r#"{
+        "id": 3,
+        "tiers": [
+            {
+                "name": "Secured",
+                "yaks_required": 20,
+                "upgrades": [
+                    {
+                        "name": "Reinforced Walls",
+                        "description": "Increases the health of walls.",
+                        "icon": "https://render.guildwars2.com/file/walls.png"
+                    }
+                ]
+            }
+        ]
+    }"#;
+    let upgrade: Upgrade = serde_json::from_str(json).unwrap();
+    assert_eq!(upgrade.tiers[0].yaks_required, 20);
+    assert_eq!(upgrade.tiers[0].upgrades[0].name, "Reinforced Walls");
+}
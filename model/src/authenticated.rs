@@ -4,7 +4,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::{Endpoint, FixedEndpoint, TimeStamp};
 pub mod account;
+#[cfg(feature = "characters")]
 pub mod characters;
+#[cfg(feature = "commerce")]
 pub mod commerce;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
@@ -21,6 +23,9 @@ pub enum Permissions {
     TradingPost,
     Unlocks,
     Wallet,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -28,6 +33,9 @@ pub enum Permissions {
 pub enum TokenType {
     APIKey,
     Subtoken,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -38,16 +46,18 @@ pub struct SubtokenDetails {
     pub urls: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Tokeninfo {
-    pub id: String,
-    pub name: String,
-    pub permissions: BTreeSet<Permissions>,
-    #[serde(rename = "type")]
-    pub _type: TokenType,
-    #[serde(flatten)]
-    details: Option<SubtokenDetails>,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Tokeninfo {
+        pub id: String,
+        pub name: String,
+        pub permissions: BTreeSet<Permissions>,
+        #[serde(rename = "type")]
+        pub _type: TokenType,
+        #[serde(flatten)]
+        details: Option<SubtokenDetails>,
+    }
 }
 
 impl Endpoint for Tokeninfo {
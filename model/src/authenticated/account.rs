@@ -1,8 +1,35 @@
+#[cfg(feature = "characters")]
 pub mod bank;
+#[cfg(feature = "characters")]
+pub mod buildstorage;
+#[cfg(feature = "achievements")]
+pub mod daily_rewards;
+#[cfg(feature = "characters")]
+pub mod dyes;
+#[cfg(feature = "characters")]
+pub mod finishers;
+#[cfg(feature = "misc")]
+pub mod homestead;
+#[cfg(feature = "characters")]
 pub mod inventory;
+#[cfg(feature = "characters")]
+pub mod legendary_armory;
+#[cfg(feature = "characters")]
+pub mod luck;
+#[cfg(feature = "characters")]
 pub mod materials;
+#[cfg(feature = "characters")]
+pub mod outfits;
+#[cfg(feature = "characters")]
+pub mod progression;
+#[cfg(feature = "characters")]
 pub mod raids;
+#[cfg(feature = "characters")]
+pub mod skins;
+#[cfg(feature = "characters")]
 pub mod wallet;
+#[cfg(feature = "wvw")]
+pub mod wvw;
 
 use std::collections::BTreeSet;
 
@@ -23,25 +50,30 @@ pub enum Access {
     EndOfDragons,
     SecretsOfTheObscure,
     JanthirWilds,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Account {
-    pub id: String,
-    pub age: u64,
-    pub name: String,
-    pub world: WorldId,
-    pub guilds: Vec<String>,
-    pub guild_leader: Option<Vec<String>>,
-    pub created: TimeStamp,
-    pub access: BTreeSet<Access>,
-    pub commander: bool,
-    pub fractal_level: Option<u8>,
-    pub daily_ap: Option<u16>,
-    pub monthly_ap: Option<u16>,
-    pub wvw_rank: Option<u16>,
-    pub last_modified: String,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Account {
+        pub id: String,
+        pub age: u64,
+        pub name: String,
+        pub world: WorldId,
+        pub guilds: Vec<String>,
+        pub guild_leader: Option<Vec<String>>,
+        pub created: TimeStamp,
+        pub access: BTreeSet<Access>,
+        pub commander: bool,
+        pub fractal_level: Option<u8>,
+        pub daily_ap: Option<u16>,
+        pub monthly_ap: Option<u16>,
+        pub wvw_rank: Option<u16>,
+        pub last_modified: String,
+    }
 }
 
 impl Endpoint for Account {
@@ -1,6 +1,6 @@
-use crate::{authenticated::characters::InventoryItem, Endpoint, FixedEndpoint};
+use crate::{authenticated::characters::InventorySlot, Endpoint, FixedEndpoint};
 
-pub type Bank = Vec<Option<InventoryItem>>;
+pub type Bank = Vec<InventorySlot>;
 
 impl Endpoint for Bank {
     const AUTHENTICATED: bool = true;
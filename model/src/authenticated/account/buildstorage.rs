@@ -0,0 +1,36 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{authenticated::characters::BuildTemplate, Endpoint, FixedEndpoint};
+
+/// builds saved in the account wide build storage, requires the `builds`
+/// scope
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountBuildStorage(pub Vec<BuildTemplate>);
+
+impl Endpoint for AccountBuildStorage {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    // without `ids=all` this only returns the indices of the stored builds
+    const QUERY: &'static [(&'static str, &'static str)] = &[("ids", "all")];
+    const URL: &'static str = "v2/account/buildstorage";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountBuildStorage {}
+
+impl Deref for AccountBuildStorage {
+    type Target = Vec<BuildTemplate>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountBuildStorage {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
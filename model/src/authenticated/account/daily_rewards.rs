@@ -0,0 +1,95 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    daily_rewards::{DailyCraftingId, MapChestId, WorldBossId},
+    Endpoint, FixedEndpoint,
+};
+
+/// [`WorldBoss`](crate::daily_rewards::WorldBoss)es looted since the last
+/// daily reset, requires the `progression` scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountWorldBosses(pub Vec<WorldBossId>);
+
+impl Endpoint for AccountWorldBosses {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/worldbosses";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountWorldBosses {}
+
+impl Deref for AccountWorldBosses {
+    type Target = Vec<WorldBossId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountWorldBosses {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// [`DailyCrafting`](crate::daily_rewards::DailyCrafting) recipes crafted
+/// since the last daily reset, requires the `progression` scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountDailyCrafting(pub Vec<DailyCraftingId>);
+
+impl Endpoint for AccountDailyCrafting {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/dailycrafting";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountDailyCrafting {}
+
+impl Deref for AccountDailyCrafting {
+    type Target = Vec<DailyCraftingId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountDailyCrafting {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// [`MapChest`](crate::daily_rewards::MapChest)s looted since the last daily
+/// reset, requires the `progression` scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountMapChests(pub Vec<MapChestId>);
+
+impl Endpoint for AccountMapChests {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/mapchests";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountMapChests {}
+
+impl Deref for AccountMapChests {
+    type Target = Vec<MapChestId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountMapChests {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
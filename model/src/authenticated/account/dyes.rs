@@ -0,0 +1,34 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{misc::colors::ColorId, Endpoint, FixedEndpoint};
+
+/// ids of unlocked [`Color`](crate::misc::colors::Color)s, requires the
+/// `unlocks` scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountDyes(pub Vec<ColorId>);
+
+impl Endpoint for AccountDyes {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/dyes";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountDyes {}
+
+impl Deref for AccountDyes {
+    type Target = Vec<ColorId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountDyes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,44 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{items::finishers::FinisherId, Endpoint, FixedEndpoint};
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct FinisherUnlock {
+    /// id of the [`Finisher`](crate::items::finishers::Finisher)
+    pub id: FinisherId,
+    /// whether the finisher is unlocked for good
+    pub permanent: bool,
+    /// uses left of a consumable finisher, absent for permanent ones
+    pub quantity: Option<u32>,
+}
+
+/// unlocked finishers, requires the `unlocks` scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountFinishers(pub Vec<FinisherUnlock>);
+
+impl Endpoint for AccountFinishers {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/finishers";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountFinishers {}
+
+impl Deref for AccountFinishers {
+    type Target = Vec<FinisherUnlock>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountFinishers {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,76 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    home_instance::{decorations::DecorationId, glyphs::GlyphId},
+    Endpoint, FixedEndpoint,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct DecorationCount {
+    /// id of the
+    /// [`HomesteadDecoration`](crate::home_instance::decorations::HomesteadDecoration)
+    pub id: DecorationId,
+    /// how many of the decoration the account stores
+    pub count: u32,
+}
+
+/// decorations stored in the homestead, requires the `unlocks` scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountHomesteadDecorations(pub Vec<DecorationCount>);
+
+impl Endpoint for AccountHomesteadDecorations {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/homestead/decorations";
+    const VERSION: &'static str = "2024-08-20T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountHomesteadDecorations {}
+
+impl Deref for AccountHomesteadDecorations {
+    type Target = Vec<DecorationCount>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountHomesteadDecorations {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// ids of unlocked
+/// [`HomesteadGlyph`](crate::home_instance::glyphs::HomesteadGlyph)s,
+/// requires the `unlocks` scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountHomesteadGlyphs(pub Vec<GlyphId>);
+
+impl Endpoint for AccountHomesteadGlyphs {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/homestead/glyphs";
+    const VERSION: &'static str = "2024-08-20T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountHomesteadGlyphs {}
+
+impl Deref for AccountHomesteadGlyphs {
+    type Target = Vec<GlyphId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountHomesteadGlyphs {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -1,24 +1,18 @@
+use std::ops::{Deref, DerefMut};
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    authenticated::characters::Binding,
-    items::{skins::SkinId, ItemId},
+    authenticated::characters::{InventoryItem, InventorySlot},
     Endpoint, FixedEndpoint,
 };
 
-pub type AccountInventory = Vec<Option<AccountInventoryItem>>;
-
-#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct AccountInventoryItem {
-    pub id: ItemId,
-    pub count: u8,
-    pub charges: Option<u8>,
-    pub upgrades: Option<Vec<ItemId>>,
-    pub skin: Option<SkinId>,
-    pub infusions: Option<Vec<ItemId>>,
-    pub binding: Option<Binding>,
-}
+pub type AccountInventoryItem = InventoryItem;
+
+/// shared inventory slots, empty slots keep their position as `None`
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountInventory(pub Vec<InventorySlot>);
 
 impl Endpoint for AccountInventory {
     const AUTHENTICATED: bool = true;
@@ -28,3 +22,17 @@ impl Endpoint for AccountInventory {
 }
 
 impl FixedEndpoint for AccountInventory {}
+
+impl Deref for AccountInventory {
+    type Target = Vec<InventorySlot>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountInventory {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
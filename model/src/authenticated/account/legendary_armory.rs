@@ -0,0 +1,41 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{items::ItemId, Endpoint, FixedEndpoint};
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct LegendaryArmoryUnlock {
+    /// id of the [`Item`](crate::items::Item)
+    pub id: ItemId,
+    pub count: u8,
+}
+
+/// unlocked legendaries, requires the `inventories` and `unlocks` scopes
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountLegendaryArmory(pub Vec<LegendaryArmoryUnlock>);
+
+impl Endpoint for AccountLegendaryArmory {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/legendaryarmory";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountLegendaryArmory {}
+
+impl Deref for AccountLegendaryArmory {
+    type Target = Vec<LegendaryArmoryUnlock>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountLegendaryArmory {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
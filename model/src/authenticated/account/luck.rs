@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{authenticated::account::progression::Progression, Endpoint, FixedEndpoint};
+
+/// consumed luck, requires the `progression` and `unlocks` scopes
+///
+/// The api returns `[{ "id": "luck", "value": 123 }]`, or an empty array
+/// for accounts that never consumed any luck.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "Vec<Progression>", into = "Vec<Progression>")]
+pub struct AccountLuck(pub i64);
+
+impl From<Vec<Progression>> for AccountLuck {
+    fn from(entries: Vec<Progression>) -> Self {
+        Self(
+            entries
+                .into_iter()
+                .filter(|p| p.id == "luck")
+                .map(|p| p.value)
+                .sum(),
+        )
+    }
+}
+
+impl From<AccountLuck> for Vec<Progression> {
+    fn from(luck: AccountLuck) -> Self {
+        vec![Progression {
+            id: "luck".to_string(),
+            value: luck.0,
+        }]
+    }
+}
+
+impl Endpoint for AccountLuck {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/luck";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountLuck {}
@@ -0,0 +1,34 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{game_mechanics::outfits::OutfitId, Endpoint, FixedEndpoint};
+
+/// ids of unlocked [`Outfit`](crate::game_mechanics::outfits::Outfit)s,
+/// requires the `unlocks` scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountOutfits(pub Vec<OutfitId>);
+
+impl Endpoint for AccountOutfits {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/outfits";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountOutfits {}
+
+impl Deref for AccountOutfits {
+    type Target = Vec<OutfitId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountOutfits {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
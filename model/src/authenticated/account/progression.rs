@@ -0,0 +1,50 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Endpoint, FixedEndpoint};
+
+pub type ProgressionId = String;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Progression {
+    pub id: ProgressionId,
+    pub value: i64,
+}
+
+/// account wide progression like fractal agony impedance or luck, requires
+/// the `progression` scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountProgression(pub Vec<Progression>);
+
+impl AccountProgression {
+    /// returns the value of the entry with the given id
+    pub fn value(&self, id: &str) -> Option<i64> {
+        self.iter().find(|p| p.id == id).map(|p| p.value)
+    }
+}
+
+impl Endpoint for AccountProgression {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/progression";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountProgression {}
+
+impl Deref for AccountProgression {
+    type Target = Vec<Progression>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountProgression {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,34 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{items::skins::SkinId, Endpoint, FixedEndpoint};
+
+/// ids of unlocked [`Skin`](crate::items::skins::Skin)s, requires the `unlocks`
+/// scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountSkins(pub Vec<SkinId>);
+
+impl Endpoint for AccountSkins {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/skins";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountSkins {}
+
+impl Deref for AccountSkins {
+    type Target = Vec<SkinId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AccountSkins {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Endpoint, FixedEndpoint};
+
+pub type TeamId = u32;
+
+with_unknown_fields! {
+    /// the wvw team of the account, requires the `account` scope
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct AccountWvw {
+        /// absent while the account isn't assigned to a team
+        pub team: Option<TeamId>,
+        pub rank: Option<u16>,
+    }
+}
+
+impl Endpoint for AccountWvw {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/account/wvw";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl FixedEndpoint for AccountWvw {}
@@ -1,36 +1,39 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
 
 use serde::{Deserialize, Serialize};
 
-// todo: remove this pub use on next breaking version
-pub use crate::game_mechanics::pets::PetId;
+// todo: remove the PetId and Discipline re-exports on next breaking version
+pub use crate::{
+    game_mechanics::pets::PetId,
+    items::recipes::Discipline,
+    shared::{CraftingDiscipline, Profession, Race},
+};
 use crate::{
     game_mechanics::{skills::SkillId, specializations::SpecializationId, traits::TraitId},
     items::{itemstats::StatsId, recipes::RecipeId, skins::SkinId, AttributeType, ItemId},
     misc::{colors::ColorId, titles::TitleId},
     pvp::amulets::AmuletId,
     wvw::abilities::AbilityId,
-    BulkEndpoint, Endpoint, EndpointWithId, TimeStamp,
+    BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId, FixedEndpoint, TimeStamp,
 };
 
 pub type Age = u64;
 pub type CharacterId = String;
 pub type BackStoryId = String;
-
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Backstory {
-    pub backstory: Vec<BackStoryId>,
-}
-
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub enum Race {
-    Asura,
-    Charr,
-    Human,
-    Norn,
-    Sylvari,
+/// the id of a hero challenge, see
+/// [`SkillChallenge::id`](crate::maps::continents::SkillChallenge::id)
+pub type HeroPointId = String;
+pub type QuestId = u32;
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Backstory {
+        pub backstory: Vec<BackStoryId>,
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -38,64 +41,46 @@ pub enum Race {
 pub enum Gender {
     Male,
     Female,
-}
-
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub enum Profession {
-    Elementalist,
-    Engineer,
-    Guardian,
-    Mesmer,
-    Necromancer,
-    Ranger,
-    Revenant,
-    Thief,
-    Warrior,
-}
-
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Core {
-    pub name: CharacterId,
-    pub race: Race,
-    pub gender: Gender,
-    pub profession: Profession,
-    pub level: u8,
-    pub guild: Option<String>,
-    pub age: Age,
-    pub created: TimeStamp,
-    pub last_modified: TimeStamp,
-    pub deaths: u32,
-    pub title: Option<TitleId>,
-}
-
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub enum Discipline {
-    Armorsmith,
-    Artificer,
-    Chef,
-    Huntsman,
-    Jeweler,
-    Leatherworker,
-    Scribe,
-    Tailor,
-    Weaponsmith,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Core {
+        pub name: CharacterId,
+        pub race: Race,
+        pub gender: Gender,
+        pub profession: Profession,
+        pub level: u8,
+        pub guild: Option<String>,
+        pub age: Age,
+        pub created: TimeStamp,
+        pub last_modified: TimeStamp,
+        pub deaths: u32,
+        pub title: Option<TitleId>,
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct Craft {
-    pub discipline: Discipline,
+    pub discipline: CraftingDiscipline,
+    /// crafting level, 500 is the maximum for most disciplines
     pub rating: u16,
+    /// whether this is one of the two disciplines currently in use
     pub active: bool,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Crafting {
-    pub crafting: Vec<Craft>,
+with_unknown_fields! {
+    /// `v2/characters/:id/crafting`, requires the `characters` scope
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Crafting {
+        pub crafting: Vec<Craft>,
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -130,6 +115,9 @@ pub enum Slot {
     SensoryArray,
     ServiceChip,
     Relic,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq)]
@@ -144,6 +132,9 @@ pub struct Stats {
 pub enum Binding {
     Character,
     Account,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -153,6 +144,9 @@ pub enum Location {
     Armory,
     EquippedFromLegendaryArmory,
     LegendaryArmory,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq)]
@@ -175,10 +169,12 @@ pub struct Equip {
     pub tabs: Option<Vec<usize>>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Equipment {
-    pub equipment: Vec<Equip>,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Equipment {
+        pub equipment: Vec<Equip>,
+    }
 }
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq)]
@@ -197,20 +193,32 @@ pub struct InventoryItem {
     pub dyes: Option<Vec<ColorId>>,
 }
 
+/// a single inventory slot, `None` if the slot is empty
+///
+/// Shared between bags, the bank and the shared inventory slots.
+pub type InventorySlot = Option<InventoryItem>;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct InventoryBag {
     pub id: ItemId,
     pub size: u8,
-    pub inventory: Vec<Option<InventoryItem>>,
+    pub inventory: Vec<InventorySlot>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Inventory {
-    pub bags: Vec<Option<InventoryBag>>,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Inventory {
+        pub bags: Vec<Option<InventoryBag>>,
+    }
 }
 
+/// the three utility slots
+///
+/// Like the other fixed size arrays of builds, the number of slots is part of
+/// the build template chat link format and can't change without breaking it.
+/// Lists without such a guarantee are `Vec`s.
 pub type Utilities = [Option<SkillId>; 3];
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -231,6 +239,7 @@ pub struct SkillDataSet {
     pub wvw: Skillset,
 }
 
+/// the adept, master and grandmaster trait, fixed like [`Utilities`]
 pub type TraitSet = [Option<TraitId>; 3];
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -240,6 +249,7 @@ pub struct TraitLine {
     pub traits: Option<TraitSet>,
 }
 
+/// the three trait lines, fixed like [`Utilities`]
 pub type Specialization = [Option<TraitLine>; 3];
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -250,6 +260,26 @@ pub struct SpecializationSet {
     pub wvw: Specialization,
 }
 
+with_unknown_fields! {
+    /// `v2/characters/:id/skills`, the equipped skills per game mode, requires
+    /// the `builds` and `characters` scopes
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Skills {
+        pub skills: SkillDataSet,
+    }
+}
+
+with_unknown_fields! {
+    /// `v2/characters/:id/specializations`, the selected specializations and
+    /// traits per game mode, requires the `builds` and `characters` scopes
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Specializations {
+        pub specializations: SpecializationSet,
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct TrainingSet {
@@ -259,18 +289,39 @@ pub struct TrainingSet {
     pub done: bool,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Training {
-    pub training: Vec<TrainingSet>,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Training {
+        pub training: Vec<TrainingSet>,
+    }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Recipes {
-    pub recipes: Vec<RecipeId>,
+with_unknown_fields! {
+    /// `v2/characters/:id/recipes`, requires the `characters` and `unlocks`
+    /// scopes
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Recipes {
+        /// ids of unlocked [`Recipe`](crate::items::recipes::Recipe)s
+        pub recipes: Vec<RecipeId>,
+    }
 }
 
+/// `v2/characters/:id/heropoints`, the completed hero challenges, requires
+/// the `characters` and `progression` scopes
+///
+/// The ids match the hero challenges of the map floors.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct HeroPoints(pub Vec<HeroPointId>);
+
+/// `v2/characters/:id/quests`, the completed story quests, requires the
+/// `characters` and `progression` scopes
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct Quests(pub Vec<QuestId>);
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct WvwAbility {
@@ -283,23 +334,23 @@ pub struct WvwAbility {
 pub struct EquipmentPvp {
     pub amulet: Option<AmuletId>,
     pub rune: Option<ItemId>,
-    pub sigils: (
-        Option<ItemId>,
-        Option<ItemId>,
-        Option<ItemId>,
-        Option<ItemId>,
-    ),
+    /// a `Vec`, as the number of sigil slots depends on the equipped weapons
+    pub sigils: Vec<Option<ItemId>>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum Flags {
     Beta,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct BuildPets {
+    /// fixed like [`Utilities`]
     pub terrestrial: [Option<PetId>; 2],
     pub aquatic: [Option<PetId>; 2],
 }
@@ -311,7 +362,8 @@ pub struct BuildLegends {
     pub aquatic_legends: LegendSlots,
 }
 
-pub type LegendId = String;
+pub use crate::game_mechanics::legends::LegendId;
+/// the two legend slots, fixed like [`Utilities`]
 pub type LegendSlots = [Option<LegendId>; 2];
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -381,6 +433,7 @@ impl EndpointWithId for Character {
 
 impl Endpoint for Character {
     const AUTHENTICATED: bool = true;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = false;
     const URL: &'static str = "v2/characters";
     const VERSION: &'static str = "2022-06-14T00:00:00.000Z";
@@ -394,6 +447,35 @@ impl BulkEndpoint for Character {
     }
 }
 
+/// `v2/characters`, the names of all characters of the account, requires
+/// the `characters` scope
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CharacterNames(pub Vec<CharacterId>);
+
+impl Endpoint for CharacterNames {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/characters";
+    const VERSION: &'static str = "2022-06-14T00:00:00.000Z";
+}
+
+impl FixedEndpoint for CharacterNames {}
+
+impl Deref for CharacterNames {
+    type Target = Vec<CharacterId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CharacterNames {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 impl EndpointWithId for Core {
     type IdType = CharacterId;
 
@@ -484,6 +566,36 @@ impl Endpoint for Recipes {
     const VERSION: &'static str = "2022-06-14T00:00:00.000Z";
 }
 
+impl EndpointWithId for Skills {
+    type IdType = CharacterId;
+
+    fn format_url(id: &str) -> String {
+        format!("{}/{}/skills", Self::URL, id)
+    }
+}
+
+impl Endpoint for Skills {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/characters";
+    const VERSION: &'static str = "2022-06-14T00:00:00.000Z";
+}
+
+impl EndpointWithId for Specializations {
+    type IdType = CharacterId;
+
+    fn format_url(id: &str) -> String {
+        format!("{}/{}/specializations", Self::URL, id)
+    }
+}
+
+impl Endpoint for Specializations {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/characters";
+    const VERSION: &'static str = "2022-06-14T00:00:00.000Z";
+}
+
 impl EndpointWithId for Training {
     type IdType = CharacterId;
 
@@ -498,3 +610,133 @@ impl Endpoint for Training {
     const URL: &'static str = "v2/characters";
     const VERSION: &'static str = "2022-06-14T00:00:00.000Z";
 }
+
+impl EndpointWithId for HeroPoints {
+    type IdType = CharacterId;
+
+    fn format_url(id: &str) -> String {
+        format!("{}/{}/heropoints", Self::URL, id)
+    }
+}
+
+impl Endpoint for HeroPoints {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/characters";
+    const VERSION: &'static str = "2022-06-14T00:00:00.000Z";
+}
+
+impl Deref for HeroPoints {
+    type Target = Vec<HeroPointId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for HeroPoints {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl EndpointWithId for Quests {
+    type IdType = CharacterId;
+
+    fn format_url(id: &str) -> String {
+        format!("{}/{}/quests", Self::URL, id)
+    }
+}
+
+impl Endpoint for Quests {
+    const AUTHENTICATED: bool = true;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/characters";
+    const VERSION: &'static str = "2022-06-14T00:00:00.000Z";
+}
+
+impl Deref for Quests {
+    type Target = Vec<QuestId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Quests {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_skills() {
+        let skills: Skills = serde_json::from_str(
+            r#"{
+                "skills": {
+                    "pve": { "heal": 5503, "utilities": [5542, 5570, 5571], "elite": 5516 },
+                    "pvp": { "heal": 5503, "utilities": [5539, null, 5535], "elite": null },
+                    "wvw": {
+                        "heal": 27220,
+                        "utilities": [27372, 27107, 26821],
+                        "elite": 27760,
+                        "legends": ["Legend3", "Legend2"]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            skills.skills.pve.utilities,
+            [Some(5542), Some(5570), Some(5571)]
+        );
+        assert_eq!(skills.skills.pve.legends, None);
+        assert_eq!(skills.skills.pvp.utilities[1], None);
+        assert_eq!(skills.skills.pvp.elite, None);
+        assert_eq!(
+            skills.skills.wvw.legends.as_deref(),
+            Some(&["Legend3".to_string(), "Legend2".to_string()][..])
+        );
+    }
+
+    #[test]
+    fn parse_specializations() {
+        let specializations: Specializations = serde_json::from_str(
+            r#"{
+                "specializations": {
+                    "pve": [
+                        { "id": 31, "traits": [296, 1510, 1675] },
+                        { "id": 41, "traits": [214, 1503, 1672] },
+                        { "id": 56, "traits": [2177, 2131, 2138] }
+                    ],
+                    "pvp": [
+                        { "id": 31, "traits": [296, null, 1675] },
+                        null,
+                        { "id": null, "traits": null }
+                    ],
+                    "wvw": [null, null, null]
+                }
+            }"#,
+        )
+        .unwrap();
+        let [fire, _, weaver] = &specializations.specializations.pve;
+        assert_eq!(fire.as_ref().unwrap().id, Some(31));
+        assert_eq!(
+            weaver.as_ref().unwrap().traits,
+            Some([Some(2177), Some(2131), Some(2138)])
+        );
+        let [fire, empty, unset] = &specializations.specializations.pvp;
+        assert_eq!(fire.as_ref().unwrap().traits.unwrap()[1], None);
+        assert!(empty.is_none());
+        assert_eq!(unset.as_ref().unwrap().id, None);
+        assert!(specializations
+            .specializations
+            .wvw
+            .iter()
+            .all(Option::is_none));
+    }
+}
@@ -8,10 +8,12 @@ pub struct DeliveryItem {
     pub count: u32,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
-pub struct Delivery {
-    pub coins: u32,
-    pub items: Vec<DeliveryItem>,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub struct Delivery {
+        pub coins: u32,
+        pub items: Vec<DeliveryItem>,
+    }
 }
 
 impl Endpoint for Delivery {
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    authenticated::account::daily_rewards::{
+        AccountDailyCrafting, AccountMapChests, AccountWorldBosses,
+    },
+    BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId,
+};
+
+pub type WorldBossId = String;
+pub type DailyCraftingId = String;
+pub type MapChestId = String;
+
+with_unknown_fields! {
+    /// a world boss whose chest can be looted once per day
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct WorldBoss {
+        pub id: WorldBossId,
+    }
+}
+
+impl Endpoint for WorldBoss {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/worldbosses";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for WorldBoss {
+    type IdType = WorldBossId;
+}
+
+impl BulkEndpoint for WorldBoss {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
+
+with_unknown_fields! {
+    /// a time gated recipe that can be crafted once per day
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct DailyCrafting {
+        pub id: DailyCraftingId,
+    }
+}
+
+impl Endpoint for DailyCrafting {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/dailycrafting";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for DailyCrafting {
+    type IdType = DailyCraftingId;
+}
+
+impl BulkEndpoint for DailyCrafting {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
+
+with_unknown_fields! {
+    /// a hero's choice chest of a map meta event, lootable once per day
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct MapChest {
+        pub id: MapChestId,
+    }
+}
+
+impl Endpoint for MapChest {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/mapchests";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for MapChest {
+    type IdType = MapChestId;
+}
+
+impl BulkEndpoint for MapChest {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
+
+/// whether a daily reward was already claimed since the last daily reset
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DailyEntry {
+    pub id: String,
+    pub completed: bool,
+}
+
+/// the daily rewards of an account, joined with the lists of all rewards
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DailyProgress {
+    pub world_bosses: Vec<DailyEntry>,
+    pub daily_crafting: Vec<DailyEntry>,
+    pub map_chests: Vec<DailyEntry>,
+}
+
+impl DailyProgress {
+    /// marks every reward as completed if the account claimed it
+    pub fn new(
+        world_bosses: &[WorldBoss],
+        daily_crafting: &[DailyCrafting],
+        map_chests: &[MapChest],
+        account_world_bosses: &AccountWorldBosses,
+        account_daily_crafting: &AccountDailyCrafting,
+        account_map_chests: &AccountMapChests,
+    ) -> Self {
+        fn join<'a>(
+            all: impl Iterator<Item = &'a String>,
+            completed: &[String],
+        ) -> Vec<DailyEntry> {
+            all.map(|id| DailyEntry {
+                id: id.clone(),
+                completed: completed.contains(id),
+            })
+            .collect()
+        }
+
+        Self {
+            world_bosses: join(world_bosses.iter().map(|x| &x.id), account_world_bosses),
+            daily_crafting: join(daily_crafting.iter().map(|x| &x.id), account_daily_crafting),
+            map_chests: join(map_chests.iter().map(|x| &x.id), account_map_chests),
+        }
+    }
+}
@@ -1,4 +1,11 @@
+#[cfg(feature = "characters")]
+pub mod legends;
+#[cfg(feature = "characters")]
+pub mod outfits;
+#[cfg(feature = "characters")]
 pub mod pets;
 pub mod skills;
+#[cfg(feature = "characters")]
 pub mod specializations;
+#[cfg(feature = "characters")]
 pub mod traits;
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::game_mechanics::skills::SkillId;
+use crate::*;
+
+pub type LegendId = String;
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Legend {
+        pub id: LegendId,
+        /// the id of the legend in build template chat links
+        pub code: Option<u8>,
+        /// the skill used to swap to this legend
+        pub swap: SkillId,
+        pub heal: SkillId,
+        pub elite: SkillId,
+        /// usually three, but not fixed to stay compatible with future legends
+        pub utilities: Vec<SkillId>,
+    }
+}
+
+impl Endpoint for Legend {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/legends";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for Legend {
+    type IdType = LegendId;
+}
+
+impl BulkEndpoint for Legend {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
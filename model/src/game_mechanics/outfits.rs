@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{items::ItemId, *};
+
+pub type OutfitId = u16;
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Outfit {
+        pub id: OutfitId,
+        pub name: String,
+        pub icon: String,
+        pub unlock_items: Vec<ItemId>,
+    }
+}
+
+impl Endpoint for Outfit {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = true;
+    const URL: &'static str = "v2/outfits";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for Outfit {
+    type IdType = OutfitId;
+}
+
+impl BulkEndpoint for Outfit {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
@@ -11,18 +11,21 @@ pub struct PetSkill {
     pub id: SkillId,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Pet {
-    pub id: PetId,
-    pub name: String,
-    pub description: String,
-    pub icon: Option<String>,
-    pub skills: Vec<PetSkill>,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Pet {
+        pub id: PetId,
+        pub name: String,
+        pub description: String,
+        pub icon: Option<String>,
+        pub skills: Vec<PetSkill>,
+    }
 }
 
 impl Endpoint for Pet {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = true;
     const URL: &'static str = "v2/pets";
     const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
@@ -1,10 +1,14 @@
+#[cfg(feature = "characters")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "characters")]
 use crate::game_mechanics::traits::TraitId;
-pub use crate::{authenticated::characters::Profession, items::WeaponType};
+#[cfg(feature = "characters")]
+pub use crate::{items::WeaponType, shared::Profession};
 
 pub type SkillId = u32;
 
+#[cfg(feature = "characters")]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum SkillType {
     Bundle,
@@ -13,8 +17,12 @@ pub enum SkillType {
     Profession,
     Utility,
     Weapon,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "characters")]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[allow(non_camel_case_types)]
 pub enum Slot {
@@ -34,8 +42,12 @@ pub enum Slot {
     Weapon_3,
     Weapon_4,
     Weapon_5,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "characters")]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum FactsType {
     AttributeAdjust,
@@ -56,8 +68,12 @@ pub enum FactsType {
     Recharge,
     Time,
     Unblockable,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "characters")]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Fact {
@@ -66,6 +82,7 @@ pub struct Fact {
     pub _type: FactsType,
 }
 
+#[cfg(feature = "characters")]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct TraitedFact {
@@ -0,0 +1,65 @@
+//! gem related data
+//!
+//! The api doesn't offer the gem store catalog, only the current exchange
+//! rates between coins and gems. Both are public and use the quantity to
+//! exchange as their id, so requesting a single [`GemsToCoins`] with id `100`
+//! calls `v2/commerce/exchange/gems?quantity=100`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Endpoint, EndpointWithId};
+
+/// number of coins or gems to exchange
+pub type ExchangeQuantity = u64;
+
+with_unknown_fields! {
+    /// result of exchanging coins for gems
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct CoinsToGems {
+        pub coins_per_gem: u64,
+        /// gems received for the requested amount of coins
+        pub quantity: u64,
+    }
+}
+
+impl Endpoint for CoinsToGems {
+    const AUTHENTICATED: bool = false;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/commerce/exchange/coins";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for CoinsToGems {
+    type IdType = ExchangeQuantity;
+
+    fn format_url(id: &str) -> String {
+        format!("{}?quantity={}", Self::URL, id)
+    }
+}
+
+with_unknown_fields! {
+    /// result of exchanging gems for coins
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct GemsToCoins {
+        pub coins_per_gem: u64,
+        /// coins received for the requested amount of gems
+        pub quantity: u64,
+    }
+}
+
+impl Endpoint for GemsToCoins {
+    const AUTHENTICATED: bool = false;
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/commerce/exchange/gems";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for GemsToCoins {
+    type IdType = ExchangeQuantity;
+
+    fn format_url(id: &str) -> String {
+        format!("{}?quantity={}", Self::URL, id)
+    }
+}
@@ -1 +1,3 @@
+#[cfg(feature = "guild")]
+pub mod emblem;
 pub mod upgrades;
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+pub type EmblemBackgroundId = u32;
+pub type EmblemForegroundId = u32;
+
+with_unknown_fields! {
+    /// background of a guild emblem, referenced by `emblem.background.id` of a
+    /// guild
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct EmblemBackground {
+        pub id: EmblemBackgroundId,
+        /// image urls of the layers, from bottom to top
+        pub layers: Vec<String>,
+    }
+}
+
+impl Endpoint for EmblemBackground {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/emblem/backgrounds";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for EmblemBackground {
+    type IdType = EmblemBackgroundId;
+}
+
+impl BulkEndpoint for EmblemBackground {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
+
+with_unknown_fields! {
+    /// foreground of a guild emblem, referenced by `emblem.foreground.id` of a
+    /// guild
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct EmblemForeground {
+        pub id: EmblemForegroundId,
+        /// image urls of the layers, from bottom to top
+        pub layers: Vec<String>,
+    }
+}
+
+impl Endpoint for EmblemForeground {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/emblem/foregrounds";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for EmblemForeground {
+    type IdType = EmblemForegroundId;
+}
+
+impl BulkEndpoint for EmblemForeground {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
@@ -1,2 +1,4 @@
 pub mod cats;
+pub mod decorations;
+pub mod glyphs;
 pub mod nodes;
@@ -1,14 +1,16 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{BulkEndpoint, Endpoint, EndpointWithId};
+use crate::{BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId};
 
 pub type CatId = u32;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Cat {
-    pub id: CatId,
-    pub hint: String,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Cat {
+        pub id: CatId,
+        pub hint: String,
+    }
 }
 
 impl EndpointWithId for Cat {
@@ -16,6 +18,7 @@ impl EndpointWithId for Cat {
 }
 impl Endpoint for Cat {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = false;
     const URL: &'static str = "v2/home/cats";
     const VERSION: &'static str = "2023-08-14T00:00:00.000Z";
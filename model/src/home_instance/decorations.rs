@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId};
+
+pub type DecorationId = u32;
+pub type DecorationCategoryId = u16;
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct HomesteadDecoration {
+        pub id: DecorationId,
+        pub name: String,
+        /// may contain html
+        pub description: String,
+        /// how many of this decoration an account can store
+        pub max_count: u32,
+        pub icon: String,
+        /// ids of [`DecorationCategory`]s
+        pub categories: Vec<DecorationCategoryId>,
+    }
+}
+
+impl EndpointWithId for HomesteadDecoration {
+    type IdType = DecorationId;
+}
+impl Endpoint for HomesteadDecoration {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = true;
+    const URL: &'static str = "v2/homestead/decorations";
+    const VERSION: &'static str = "2024-08-20T00:00:00.000Z";
+}
+
+impl BulkEndpoint for HomesteadDecoration {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct DecorationCategory {
+        pub id: DecorationCategoryId,
+        pub name: String,
+    }
+}
+
+impl EndpointWithId for DecorationCategory {
+    type IdType = DecorationCategoryId;
+}
+impl Endpoint for DecorationCategory {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = true;
+    const URL: &'static str = "v2/homestead/decorations/categories";
+    const VERSION: &'static str = "2024-08-20T00:00:00.000Z";
+}
+
+impl BulkEndpoint for DecorationCategory {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{items::ItemId, BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId};
+
+pub type GlyphId = String;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+#[serde(rename_all = "lowercase")]
+pub enum GlyphSlot {
+    Harvesting,
+    Logging,
+    Mining,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct HomesteadGlyph {
+        pub id: GlyphId,
+        /// the item that unlocks the glyph
+        pub item_id: ItemId,
+        /// the gathering tool slot the glyph applies to
+        pub slot: GlyphSlot,
+    }
+}
+
+impl EndpointWithId for HomesteadGlyph {
+    type IdType = GlyphId;
+}
+impl Endpoint for HomesteadGlyph {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/homestead/glyphs";
+    const VERSION: &'static str = "2024-08-20T00:00:00.000Z";
+}
+
+impl BulkEndpoint for HomesteadGlyph {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
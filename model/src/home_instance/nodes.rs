@@ -1,13 +1,15 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{BulkEndpoint, Endpoint, EndpointWithId};
+use crate::{BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId};
 
 pub type NodeId = String;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Node {
-    pub id: NodeId,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Node {
+        pub id: NodeId,
+    }
 }
 
 impl EndpointWithId for Node {
@@ -15,6 +17,7 @@ impl EndpointWithId for Node {
 }
 impl Endpoint for Node {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = false;
     const URL: &'static str = "v2/home/nodes";
     const VERSION: &'static str = "2023-08-14T00:00:00.000Z";
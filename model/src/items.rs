@@ -1,21 +1,32 @@
+#[cfg(feature = "items")]
+pub mod finishers;
+#[cfg(feature = "items")]
 pub mod itemstats;
+#[cfg(feature = "items")]
+pub mod legendary_armory;
+#[cfg(feature = "items")]
 pub mod recipes;
+#[cfg(feature = "items")]
 pub mod skins;
 
+#[cfg(feature = "items")]
 use std::collections::BTreeSet;
 
+#[cfg(feature = "items")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "items")]
 use crate::{
     game_mechanics::skills::SkillId,
     guild::upgrades::GuildUpgradeId,
     items::{itemstats::StatsId, recipes::RecipeId, skins::SkinId},
     misc::{colors::ColorId, minis::MiniPetId},
-    BulkEndpoint, Endpoint, EndpointWithId,
+    BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId,
 };
 
 pub type ItemId = u32;
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum ItemType {
@@ -38,9 +49,15 @@ pub enum ItemType {
     PowerCore,
     JadeTechModule,
     Relic,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
+/// ordered from `Junk` to `Legendary`, an `Unknown` rarity is neither
+/// above nor below the others
+#[cfg(feature = "items")]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum Rarity {
     Junk,
@@ -51,8 +68,39 @@ pub enum Rarity {
     Exotic,
     Ascended,
     Legendary,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[cfg(feature = "items")]
+impl Rarity {
+    fn rank(&self) -> Option<u8> {
+        Some(match self {
+            Rarity::Junk => 0,
+            Rarity::Basic => 1,
+            Rarity::Fine => 2,
+            Rarity::Masterwork => 3,
+            Rarity::Rare => 4,
+            Rarity::Exotic => 5,
+            Rarity::Ascended => 6,
+            Rarity::Legendary => 7,
+            Rarity::Unknown(_) => return None,
+        })
+    }
 }
 
+#[cfg(feature = "items")]
+impl PartialOrd for Rarity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self.rank(), other.rank()) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+            _ => (self == other).then_some(std::cmp::Ordering::Equal),
+        }
+    }
+}
+
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum Flags {
@@ -73,8 +121,12 @@ pub enum Flags {
     SoulBindOnUse,
     Tonic,
     Unique,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum GameTypes {
@@ -84,8 +136,12 @@ pub enum GameTypes {
     Pvp,
     PvpLobby,
     Wvw,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum Restrictions {
@@ -104,8 +160,12 @@ pub enum Restrictions {
     Warrior,
     Female,
     Revenant,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum ArmorSlot {
@@ -116,8 +176,12 @@ pub enum ArmorSlot {
     HelmAquatic,
     Leggings,
     Shoulders,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum WeightClass {
@@ -125,15 +189,23 @@ pub enum WeightClass {
     Medium,
     Light,
     Clothing,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum InfusionType {
     Enrichment,
     Infusion,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct InfusionSlot {
@@ -141,6 +213,7 @@ pub struct InfusionSlot {
     pub item_id: Option<ItemId>,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize, Ord, Eq, Hash)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum AttributeType {
@@ -154,8 +227,12 @@ pub enum AttributeType {
     Precision,
     Toughness,
     Vitality,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct Attribute {
@@ -163,6 +240,7 @@ pub struct Attribute {
     pub modifier: u16,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct Buff {
@@ -170,6 +248,7 @@ pub struct Buff {
     pub description: Option<String>,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct InfixUpgrade {
@@ -178,6 +257,7 @@ pub struct InfixUpgrade {
     pub buff: Option<Buff>,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct Upgrades {
@@ -189,6 +269,7 @@ pub struct Upgrades {
     pub stat_choices: Option<Vec<StatsId>>,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct ArmorDetails {
@@ -200,6 +281,7 @@ pub struct ArmorDetails {
     pub upgrades: Upgrades,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct BackItemDetails {
@@ -207,6 +289,7 @@ pub struct BackItemDetails {
     pub upgrades: Upgrades,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct BagDetails {
@@ -214,6 +297,7 @@ pub struct BagDetails {
     pub no_sell_or_sort: bool,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum ConsumableType {
@@ -232,8 +316,12 @@ pub enum ConsumableType {
     UpgradeRemoval,
     Utility,
     TeleportToFriend,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum UnlockType {
@@ -254,8 +342,12 @@ pub enum UnlockType {
     Outfit,
     RandomUnlock,
     SharedSlot,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct ConsumableDetails {
@@ -274,6 +366,7 @@ pub struct ConsumableDetails {
     pub skins: Option<Vec<u64>>,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum ContainerType {
@@ -281,8 +374,12 @@ pub enum ContainerType {
     GiftBox,
     Immediate,
     OpenUI,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct ContainerDetails {
@@ -290,6 +387,7 @@ pub struct ContainerDetails {
     pub _type: ContainerType,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum GatheringToolsType {
@@ -299,8 +397,12 @@ pub enum GatheringToolsType {
     Lure,
     Bait,
     Fishing,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct GatheringToolsDetails {
@@ -308,6 +410,7 @@ pub struct GatheringToolsDetails {
     pub _type: GatheringToolsType,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum GizmoType {
@@ -315,8 +418,12 @@ pub enum GizmoType {
     ContainerKey,
     RentableContractNpc,
     UnlimitedConsumable,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct GizmoDetails {
@@ -326,18 +433,24 @@ pub struct GizmoDetails {
     pub vendor_ids: Option<Vec<u64>>, // TODO: figure out if this is resolvable
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct MiniatureDetails {
     pub minipet_id: MiniPetId,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum SalvageKitType {
     Salvage,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct SalvageKitDetails {
@@ -346,14 +459,19 @@ pub struct SalvageKitDetails {
     pub charges: u8,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum TrinketType {
     Accessory,
     Amulet,
     Ring,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct TrinketDetails {
@@ -363,6 +481,7 @@ pub struct TrinketDetails {
     pub upgrades: Upgrades,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum UpgradeComponentType {
@@ -370,8 +489,12 @@ pub enum UpgradeComponentType {
     Gem,
     Rune,
     Sigil,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum UpgradeComponentFlags {
@@ -402,8 +525,12 @@ pub enum UpgradeComponentFlags {
     MediumArmor,
     LightArmor,
     Trinket,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum InfusionUpgradeFlags {
@@ -413,8 +540,12 @@ pub enum InfusionUpgradeFlags {
     Offense,
     Utility,
     Agony,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct UpgradeComponentDetails {
@@ -428,6 +559,7 @@ pub struct UpgradeComponentDetails {
     pub bonuses: Option<Vec<String>>,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum WeaponType {
@@ -459,8 +591,12 @@ pub enum WeaponType {
     Toy,
     ToyTwoHanded,
     None,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum DamageType {
@@ -469,8 +605,12 @@ pub enum DamageType {
     Lightning,
     Physical,
     Choking,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct WeaponDetails {
@@ -484,6 +624,7 @@ pub struct WeaponDetails {
     pub upgrades: Upgrades,
 }
 
+#[cfg(feature = "items")]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 #[serde(tag = "type", content = "details")]
 #[cfg_attr(test, serde(deny_unknown_fields))]
@@ -501,13 +642,22 @@ pub enum Details {
     UpgradeComponent(UpgradeComponentDetails),
     Weapon(WeaponDetails),
     CraftingMaterial,
+    /// trait guides, no longer obtainable
+    Trait,
     Trophy,
     Key,
     PowerCore,
     JadeTechModule,
+    /// relics come without details, their effect is only described in the
+    /// description
     Relic,
+    /// a type this version of the library doesn't know yet, only
+    /// deserialized as part of an [`Item`]
+    #[serde(skip_deserializing)]
+    Unknown(String),
 }
 
+#[cfg(feature = "items")]
 impl From<Details> for ItemType {
     fn from(d: Details) -> Self {
         match d {
@@ -524,45 +674,92 @@ impl From<Details> for ItemType {
             Details::UpgradeComponent(_) => ItemType::UpgradeComponent,
             Details::Weapon(_) => ItemType::Weapon,
             Details::CraftingMaterial => ItemType::CraftingMaterial,
+            Details::Trait => ItemType::Trait,
             Details::Trophy => ItemType::Trophy,
             Details::Key => ItemType::Key,
             Details::PowerCore => ItemType::PowerCore,
             Details::JadeTechModule => ItemType::JadeTechModule,
             Details::Relic => ItemType::Relic,
+            Details::Unknown(name) => ItemType::Unknown(name),
         }
     }
 }
 
-#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
+with_unknown_fields! {
+    #[cfg(feature = "items")]
+    #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
+    // serde can't deny unknown fields next to `flatten`, the flattened details
+    // deny them instead
+    pub struct Item {
+        pub id: ItemId,
+        pub chat_link: String,
+        pub name: String,
+        pub icon: Option<String>,
+        pub description: Option<String>,
+        pub rarity: Rarity,
+        pub level: u8,
+        pub vendor_value: u64,
+        pub default_skin: Option<SkinId>,
+        pub flags: BTreeSet<Flags>,
+        pub game_types: BTreeSet<GameTypes>,
+        pub restrictions: BTreeSet<Restrictions>,
+        /// items this item can be transformed into
+        pub upgrades_into: Option<Vec<ItemUpgrade>>,
+        /// items that can be transformed into this item
+        pub upgrades_from: Option<Vec<ItemUpgrade>>,
+        /// `Unknown` if the type is newer than this release
+        #[serde(flatten, deserialize_with = "deserialize_details")]
+        pub details: Details,
+    }
+}
+
+#[cfg(feature = "items")]
+fn deserialize_details<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Details, D::Error> {
+    use serde::de::Error;
+
+    crate::deserialize_known(crate::details_fields(d)?, |tag| match tag {
+        ItemType::Unknown(name) => Some(Details::Unknown(name)),
+        _ => None,
+    })
+    .map_err(D::Error::custom)
+}
+
+#[cfg(feature = "items")]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Item {
-    pub id: ItemId,
-    pub chat_link: String,
-    pub name: String,
-    pub icon: Option<String>,
-    pub description: Option<String>,
-    pub rarity: Rarity,
-    pub level: u8,
-    pub vendor_value: u64,
-    pub default_skin: Option<SkinId>,
-    pub flags: BTreeSet<Flags>,
-    pub game_types: BTreeSet<GameTypes>,
-    pub restrictions: BTreeSet<Restrictions>,
-    #[serde(flatten)]
-    pub details: Details,
+pub enum ItemUpgradeType {
+    Attunement,
+    Unattunement,
+    Infusion,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
+/// a transformation linking two items, like attuning a ring
+#[cfg(feature = "items")]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct ItemUpgrade {
+    pub upgrade: ItemUpgradeType,
+    pub item_id: ItemId,
+}
+
+#[cfg(feature = "items")]
 impl EndpointWithId for Item {
     type IdType = ItemId;
 }
 
+#[cfg(feature = "items")]
 impl Endpoint for Item {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = true;
     const URL: &'static str = "v2/items";
     const VERSION: &'static str = "2022-07-22T00:00:00.000Z";
 }
 
+#[cfg(feature = "items")]
 impl BulkEndpoint for Item {
     const ALL: bool = false;
 
@@ -570,3 +767,207 @@ impl BulkEndpoint for Item {
         &self.id
     }
 }
+
+#[cfg(all(test, feature = "items"))]
+mod tests {
+    use super::*;
+
+    /// parses with `deny_unknown_fields`, as the model is built for tests
+    fn item(json: &str) -> Item {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn parse_jade_bot_skin() {
+        let item = item(
+            r#"{
+                "name": "Jade Bot Skin: Tuned",
+                "description": "Double-click to unlock this jade bot skin.",
+                "type": "Consumable", "level": 0, "rarity": "Rare", "vendor_value": 0,
+                "game_types": ["Activity", "Wvw", "Dungeon", "Pve"],
+                "flags": ["AccountBound", "NoSalvage", "NoSell", "AccountBindOnUse"],
+                "restrictions": [], "id": 97013, "chat_link": "[&AgH1egEA]",
+                "details": { "type": "Unlock", "unlock_type": "JadeBotSkin", "skins": [3] }
+            }"#,
+        );
+        assert!(matches!(
+            item.details,
+            Details::Consumable(ConsumableDetails {
+                _type: ConsumableType::Unlock,
+                unlock_type: Some(UnlockType::JadeBotSkin),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_teleport_to_friend() {
+        let item = item(
+            r#"{
+                "name": "Teleport to Friend",
+                "description": "Double-click to teleport to a friend in the same map.",
+                "type": "Consumable", "level": 0, "rarity": "Rare", "vendor_value": 0,
+                "game_types": ["Activity", "Wvw", "Dungeon", "Pve"],
+                "flags": ["AccountBound", "NoMysticForge", "NoSalvage", "NoSell",
+                    "AccountBindOnUse"],
+                "restrictions": [], "id": 70593, "chat_link": "[&AgHBEwEA]",
+                "details": { "type": "TeleportToFriend" }
+            }"#,
+        );
+        assert!(matches!(
+            item.details,
+            Details::Consumable(ConsumableDetails {
+                _type: ConsumableType::TeleportToFriend,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_unlimited_consumable() {
+        let item = item(
+            r#"{
+                "name": "Endless Gift of Festive Cheer",
+                "description": "Double-click to use. Unlimited uses.",
+                "type": "Gizmo", "level": 0, "rarity": "Exotic", "vendor_value": 0,
+                "game_types": ["Activity", "Wvw", "Dungeon", "Pve"],
+                "flags": ["AccountBound", "NoSalvage", "NoSell", "AccountBindOnUse"],
+                "restrictions": [], "id": 72339, "chat_link": "[&AgGTGgEA]",
+                "details": { "type": "UnlimitedConsumable", "vendor_ids": [] }
+            }"#,
+        );
+        assert!(matches!(
+            item.details,
+            Details::Gizmo(GizmoDetails {
+                _type: GizmoType::UnlimitedConsumable,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_relic() {
+        let item = item(
+            r#"{
+                "name": "Relic of the Thief",
+                "description": "Gain damage for each weapon skill recharge you reduce.",
+                "type": "Relic", "level": 0, "rarity": "Exotic", "vendor_value": 0,
+                "game_types": ["Activity", "Wvw", "Dungeon", "Pve"],
+                "flags": ["AccountBound", "NoSalvage", "NoSell", "AccountBindOnUse",
+                    "DeleteWarning"],
+                "restrictions": [], "id": 100916, "chat_link": "[&AgE0igEA]"
+            }"#,
+        );
+        assert_eq!(item.details, Details::Relic);
+    }
+
+    #[test]
+    fn parse_trait_guide() {
+        let item = item(
+            r#"{
+                "name": "Guide to Elemental Attunement",
+                "description": "Double-click to learn a trait.",
+                "type": "Trait", "level": 0, "rarity": "Fine", "vendor_value": 0,
+                "game_types": ["Activity", "Wvw", "Dungeon", "Pve"],
+                "flags": ["AccountBound", "NoSell", "SoulBindOnUse"],
+                "restrictions": [], "id": 74006, "chat_link": "[&AgEWIQEA]"
+            }"#,
+        );
+        assert!(ItemType::from(item.details) == ItemType::Trait);
+    }
+
+    const WEAPON: &str = r#"{
+        "name": "Berserker's Pearl Broadsword", "type": "Weapon", "level": 80,
+        "rarity": "Exotic", "vendor_value": 330, "default_skin": 4679,
+        "game_types": ["Activity", "Wvw", "Dungeon", "Pve"], "flags": ["HideSuffix"],
+        "restrictions": [], "id": 46762, "chat_link": "[&AgGqtgAA]",
+        "details": {
+            "type": "Greatsword", "damage_type": "Physical", "min_power": 1045,
+            "max_power": 1155, "defense": 0, "infusion_slots": [],
+            "attribute_adjustment": 717.024, "suffix_item_id": 24599,
+            "infix_upgrade": {
+                "id": 161,
+                "attributes": [
+                    { "attribute": "Power", "modifier": 179 },
+                    { "attribute": "Precision", "modifier": 128 },
+                    { "attribute": "CritDamage", "modifier": 128 }
+                ]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn parse_weapon() {
+        let item = item(WEAPON);
+        assert!(matches!(
+            item.details,
+            Details::Weapon(WeaponDetails {
+                _type: WeaponType::Greatsword,
+                min_power: 1045,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_unknown_values() {
+        let item = item(
+            r#"{
+                "id": 1, "chat_link": "", "name": "", "rarity": "Mythic", "level": 0,
+                "vendor_value": 0, "flags": ["NoSell", "Glowing"], "game_types": [],
+                "restrictions": [], "type": "Hoverboard", "details": { "speed": 3 }
+            }"#,
+        );
+        assert_eq!(item.rarity, Rarity::Unknown("Mythic".into()));
+        assert!(item.flags.contains(&Flags::NoSell));
+        assert!(item.flags.contains(&Flags::Unknown("Glowing".into())));
+        assert_eq!(item.details, Details::Unknown("Hoverboard".into()));
+
+        let cached: Item = serde_json::from_str(&serde_json::to_string(&item).unwrap()).unwrap();
+        assert_eq!(cached, item);
+        assert_eq!(
+            ItemType::from(item.details),
+            ItemType::Unknown("Hoverboard".into())
+        );
+    }
+
+    #[test]
+    fn unknown_rarity_is_unordered() {
+        let mythic = Rarity::Unknown("Mythic".into());
+        assert!(Rarity::Legendary > Rarity::Exotic);
+        assert_eq!(mythic.partial_cmp(&Rarity::Exotic), None);
+        assert_eq!(mythic.partial_cmp(&Rarity::Junk), None);
+        assert!(mythic <= mythic.clone());
+    }
+
+    #[test]
+    fn unknown_type_falls_back() {
+        let json = WEAPON
+            .replace(r#""type": "Weapon""#, r#""type": "Hoverboard""#)
+            .replace(r#""min_power": 1045"#, r#""speed": 3"#);
+        assert_eq!(item(&json).details, Details::Unknown("Hoverboard".into()));
+    }
+
+    #[test]
+    fn known_type_is_strict() {
+        let invalid = WEAPON.replace(r#""min_power": 1045"#, r#""min_power": "oops""#);
+        assert!(serde_json::from_str::<Item>(&invalid).is_err());
+        let new_field = WEAPON.replace(r#""defense": 0"#, r#""defense": 0, "new_field": 1"#);
+        assert!(serde_json::from_str::<Item>(&new_field).is_err());
+    }
+
+    #[cfg(feature = "unknown-fields")]
+    #[test]
+    fn unknown_fields_next_to_details() {
+        let item = item(
+            r#"{
+                "name": "Teleport to Friend", "type": "Consumable", "level": 0,
+                "rarity": "Rare", "vendor_value": 0, "game_types": [], "flags": [],
+                "restrictions": [], "id": 70593, "chat_link": "[&AgHBEwEA]",
+                "details": { "type": "TeleportToFriend" }, "new_field": 1
+            }"#,
+        );
+        assert!(matches!(item.details, Details::Consumable(_)));
+        assert_eq!(item.extra.keys().collect::<Vec<_>>(), ["new_field"]);
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{items::ItemId, *};
+
+pub type FinisherId = u16;
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Finisher {
+        pub id: FinisherId,
+        /// how the finisher is unlocked, may contain html
+        pub unlock_details: String,
+        /// items that unlock the finisher
+        #[serde(default)]
+        pub unlock_items: Vec<ItemId>,
+        /// sort order in the hero panel
+        pub order: u16,
+        pub icon: String,
+        pub name: String,
+    }
+}
+
+impl Endpoint for Finisher {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = true;
+    const URL: &'static str = "v2/finishers";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for Finisher {
+    type IdType = FinisherId;
+}
+
+impl BulkEndpoint for Finisher {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{items::AttributeType, BulkEndpoint, Endpoint, EndpointWithId};
+use crate::{items::AttributeType, BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId};
 
 pub type StatsId = u32;
 
@@ -12,13 +12,15 @@ pub struct Attribute {
     pub value: u8,
 }
 
-#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct ItemStat {
-    pub id: StatsId,
-    /// The name of the set of stats. Can be empty.
-    pub name: String,
-    pub attributes: Vec<Attribute>,
+with_unknown_fields! {
+    #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct ItemStat {
+        pub id: StatsId,
+        /// The name of the set of stats. Can be empty.
+        pub name: String,
+        pub attributes: Vec<Attribute>,
+    }
 }
 
 impl EndpointWithId for ItemStat {
@@ -27,6 +29,7 @@ impl EndpointWithId for ItemStat {
 
 impl Endpoint for ItemStat {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = true;
     const URL: &'static str = "v2/itemstats";
     const VERSION: &'static str = "2023-03-20T19:00:00.000Z";
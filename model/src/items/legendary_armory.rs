@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{items::ItemId, *};
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct LegendaryArmory {
+        /// id of the [`Item`](crate::items::Item)
+        pub id: ItemId,
+        /// how many of this item can be stored
+        pub max_count: u8,
+    }
+}
+
+impl Endpoint for LegendaryArmory {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/legendaryarmory";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for LegendaryArmory {
+    type IdType = ItemId;
+}
+
+impl BulkEndpoint for LegendaryArmory {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
@@ -2,12 +2,13 @@ pub type RecipeId = u32;
 
 use std::collections::BTreeSet;
 
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
 
-pub use crate::authenticated::characters::Discipline;
+// todo: remove the Discipline re-export on next breaking version
+pub use crate::shared::CraftingDiscipline as Discipline;
 use crate::{
-    guild::upgrades::GuildUpgradeId, items::ItemId, misc::currencies::CurrencyId, BulkEndpoint,
-    Endpoint, EndpointWithId,
+    guild::upgrades::GuildUpgradeId, items::ItemId, misc::currencies::CurrencyId,
+    shared::CraftingDiscipline, BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId,
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -66,6 +67,9 @@ pub enum RecipeType {
     Dye,
     Potion,
     UpgradeComponent,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
@@ -73,32 +77,84 @@ pub enum RecipeType {
 pub enum RecipeFlag {
     AutoLearned,
     LearnedFromItem,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub enum Ingredient {
-    Currency { id: CurrencyId, count: u16 },
-    Item { id: ItemId, count: u16 },
-    GuildUpgrade { id: GuildUpgradeId, count: u16 },
+    Currency {
+        id: CurrencyId,
+        count: u16,
+    },
+    Item {
+        id: ItemId,
+        count: u16,
+    },
+    GuildUpgrade {
+        id: GuildUpgradeId,
+        count: u16,
+    },
+    /// a type this version of the library doesn't know yet, only
+    /// deserialized as part of a [`Recipe`]
+    #[serde(skip_deserializing, serialize_with = "serialize_unknown")]
+    Unknown(String),
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Recipe {
-    pub id: RecipeId,
-    #[serde(rename = "type")]
-    pub _type: RecipeType,
-    pub output_item_id: ItemId,
-    pub output_item_count: u16,
-    pub time_to_craft_ms: u16,
-    pub disciplines: BTreeSet<Discipline>,
-    pub min_rating: u16,
-    pub flags: BTreeSet<RecipeFlag>,
-    pub ingredients: Vec<Ingredient>,
-    pub output_upgrade_id: Option<u32>,
-    pub chat_link: String,
+/// the `type` of an [`Ingredient`]
+#[derive(Deserialize)]
+enum IngredientType {
+    Currency,
+    Item,
+    GuildUpgrade,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// writes the type like the `Unknown` details of items, so that it's read
+/// back by [`crate::deserialize_known`]
+fn serialize_unknown<S: Serializer>(name: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry("details", name)?;
+    map.end()
+}
+
+fn deserialize_ingredients<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<Ingredient>, D::Error> {
+    use serde::de::Error;
+
+    Vec::<serde_json::Value>::deserialize(d)?
+        .into_iter()
+        .map(|ingredient| {
+            crate::deserialize_known(ingredient, |tag| match tag {
+                IngredientType::Unknown(name) => Some(Ingredient::Unknown(name)),
+                _ => None,
+            })
+            .map_err(D::Error::custom)
+        })
+        .collect()
+}
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Recipe {
+        pub id: RecipeId,
+        #[serde(rename = "type")]
+        pub _type: RecipeType,
+        pub output_item_id: ItemId,
+        pub output_item_count: u16,
+        pub time_to_craft_ms: u16,
+        pub disciplines: BTreeSet<CraftingDiscipline>,
+        pub min_rating: u16,
+        pub flags: BTreeSet<RecipeFlag>,
+        #[serde(deserialize_with = "deserialize_ingredients")]
+        pub ingredients: Vec<Ingredient>,
+        pub output_upgrade_id: Option<u32>,
+        pub chat_link: String,
+    }
 }
 
 impl EndpointWithId for Recipe {
@@ -106,6 +162,7 @@ impl EndpointWithId for Recipe {
 }
 impl Endpoint for Recipe {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = false;
     const URL: &'static str = "v2/recipes";
     const VERSION: &'static str = "2023-03-20T13:00:00.000Z";
@@ -118,3 +175,42 @@ impl BulkEndpoint for Recipe {
         &self.id
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECIPE: &str = r#"{
+        "type": "Refinement", "output_item_id": 19713, "output_item_count": 1,
+        "time_to_craft_ms": 1000, "disciplines": ["Armorsmith", "Weaponsmith"],
+        "min_rating": 0, "flags": ["AutoLearned"],
+        "ingredients": [
+            { "type": "Item", "id": 19723, "count": 3 },
+            { "type": "Essence", "id": 4, "count": 1 }
+        ],
+        "id": 11, "chat_link": "[&CQsAAAA=]"
+    }"#;
+
+    #[test]
+    fn parse_unknown_ingredient() {
+        let recipe: Recipe = serde_json::from_str(RECIPE).unwrap();
+        assert!(matches!(
+            recipe.ingredients[0],
+            Ingredient::Item {
+                id: 19723,
+                count: 3
+            }
+        ));
+        assert!(matches!(&recipe.ingredients[1], Ingredient::Unknown(name) if name == "Essence"));
+
+        let json = serde_json::to_string(&recipe).unwrap();
+        let cached: Recipe = serde_json::from_str(&json).unwrap();
+        assert!(matches!(&cached.ingredients[1], Ingredient::Unknown(name) if name == "Essence"));
+    }
+
+    #[test]
+    fn known_ingredient_is_strict() {
+        let invalid = RECIPE.replace(r#""id": 19723"#, r#""id": "oops""#);
+        assert!(serde_json::from_str::<Recipe>(&invalid).is_err());
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
         ArmorSlot, DamageType, GatheringToolsType, Rarity, Restrictions, WeaponType, WeightClass,
     },
     misc::colors::ColorId,
-    BulkEndpoint, Endpoint, EndpointWithId,
+    BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId,
 };
 
 pub type SkinId = u32;
@@ -19,6 +19,9 @@ pub enum SkinType {
     Back,
     Gathering,
     Weapon,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
@@ -32,6 +35,9 @@ pub enum Flags {
     HideIfLocked,
     /// The skin overrides item rarity when applied.
     OverrideRarity,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
@@ -41,6 +47,9 @@ pub enum Material {
     Cloth,
     Leather,
     Metal,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
@@ -63,6 +72,9 @@ pub enum RaceGender {
     NornFemale,
     SylvariMale,
     SylvariFemale,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
@@ -104,6 +116,10 @@ pub enum Details {
     Back,
     Gathering(GatheringToolsDetails),
     Weapon(WeaponDetails),
+    /// a type this version of the library doesn't know yet, only
+    /// deserialized as part of a [`Skin`]
+    #[serde(skip_deserializing)]
+    Unknown(String),
 }
 
 impl From<Details> for SkinType {
@@ -113,22 +129,36 @@ impl From<Details> for SkinType {
             Details::Back => SkinType::Back,
             Details::Gathering(_) => SkinType::Gathering,
             Details::Weapon(_) => SkinType::Weapon,
+            Details::Unknown(name) => SkinType::Unknown(name),
         }
     }
 }
 
-#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Skin {
-    pub id: SkinId,
-    pub name: String,
-    pub icon: Option<String>,
-    pub description: Option<String>,
-    pub rarity: Rarity,
-    pub flags: BTreeSet<Flags>,
-    pub restrictions: BTreeSet<Restrictions>,
-    #[serde(flatten)]
-    pub details: Details,
+with_unknown_fields! {
+    #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Skin {
+        pub id: SkinId,
+        pub name: String,
+        pub icon: Option<String>,
+        pub description: Option<String>,
+        pub rarity: Rarity,
+        pub flags: BTreeSet<Flags>,
+        pub restrictions: BTreeSet<Restrictions>,
+        /// `Unknown` if the type is newer than this release
+        #[serde(flatten, deserialize_with = "deserialize_details")]
+        pub details: Details,
+    }
+}
+
+fn deserialize_details<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Details, D::Error> {
+    use serde::de::Error;
+
+    crate::deserialize_known(crate::details_fields(d)?, |tag| match tag {
+        SkinType::Unknown(name) => Some(Details::Unknown(name)),
+        _ => None,
+    })
+    .map_err(D::Error::custom)
 }
 
 impl EndpointWithId for Skin {
@@ -137,6 +167,7 @@ impl EndpointWithId for Skin {
 
 impl Endpoint for Skin {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = true;
     const URL: &'static str = "v2/skins";
     const VERSION: &'static str = "2023-03-20T19:00:00.000Z";
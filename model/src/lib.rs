@@ -1,15 +1,30 @@
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "unknown-fields")]
+pub use unknown_fields::{capture_unknown_fields, UnknownFields};
+
+#[macro_use]
+mod unknown_fields;
 
 pub mod authenticated;
+#[cfg(feature = "achievements")]
+pub mod daily_rewards;
 pub mod game_mechanics;
+#[cfg(feature = "commerce")]
+pub mod gemstore;
 pub mod guild;
+#[cfg(feature = "misc")]
 pub mod home_instance;
 pub mod items;
+#[cfg(feature = "misc")]
 pub mod maps;
 pub mod misc;
 pub mod pvp;
+pub mod shared;
+#[cfg(feature = "characters")]
+pub mod story;
+#[cfg(feature = "commerce")]
 pub mod tradingpost;
 pub mod wvw;
 
@@ -77,6 +92,25 @@ pub trait Endpoint: Sized {
 
     /// version of the endpoint to request
     const VERSION: &'static str;
+
+    /// http method used to request this endpoint
+    const METHOD: Method = Method::Get;
+
+    /// query parameters sent with every request of this endpoint
+    const QUERY: &'static [(&'static str, &'static str)] = &[];
+
+    /// capabilities of this endpoint, see [`endpoint_info`]
+    ///
+    /// Implementors of [`BulkEndpoint`] set this to [`EndpointInfo::bulk`].
+    const INFO: EndpointInfo = EndpointInfo::new::<Self>();
+}
+
+/// http method of an [`Endpoint`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Method {
+    #[default]
+    Get,
+    Post,
 }
 
 pub trait EndpointWithId: Endpoint {
@@ -103,3 +137,135 @@ pub trait BulkEndpoint: EndpointWithId {
 pub trait PagedEndpoint: Endpoint {}
 
 impl<T: BulkEndpoint> PagedEndpoint for T {}
+
+/// capabilities of an endpoint, assembled from its associated constants
+///
+/// ## Example
+/// ```
+/// use gw2lib_model::{endpoint_info, items::Item};
+///
+/// let info = endpoint_info::<Item>();
+/// assert_eq!(info.url, "v2/items");
+/// assert!(!info.supports_all);
+/// assert!(info.supports_paging);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EndpointInfo {
+    pub url: &'static str,
+    pub authenticated: bool,
+    pub locale: bool,
+    pub version: &'static str,
+    pub method: Method,
+    /// whether `ids=all` is supported
+    pub supports_all: bool,
+    /// whether `page=` and `page_size=` are supported
+    pub supports_paging: bool,
+}
+
+impl EndpointInfo {
+    /// the capabilities of an endpoint without bulk queries
+    pub const fn new<T: Endpoint>() -> Self {
+        Self {
+            url: T::URL,
+            authenticated: T::AUTHENTICATED,
+            locale: T::LOCALE,
+            version: T::VERSION,
+            method: T::METHOD,
+            supports_all: false,
+            supports_paging: false,
+        }
+    }
+
+    /// the capabilities of a [`BulkEndpoint`]
+    pub const fn bulk<T: BulkEndpoint>() -> Self {
+        Self {
+            supports_all: T::ALL,
+            supports_paging: true,
+            ..Self::new::<T>()
+        }
+    }
+}
+
+/// returns the capabilities of any endpoint, including bulk endpoints
+pub const fn endpoint_info<T: Endpoint>() -> EndpointInfo {
+    T::INFO
+}
+
+/// reads the `type` and `details` fields of a model with flattened details,
+/// e.g. an [`items::Item`]
+///
+/// With `unknown-fields`, the other fields are left to the `extra` field of
+/// the model. Otherwise all remaining fields are read, so that the details
+/// deny unknown fields in tests.
+#[cfg(feature = "items")]
+pub(crate) fn details_fields<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<serde_json::Value, D::Error> {
+    #[cfg(not(feature = "unknown-fields"))]
+    return serde_json::Value::deserialize(deserializer);
+
+    #[cfg(feature = "unknown-fields")]
+    {
+        struct DetailsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DetailsVisitor {
+            type Value = serde_json::Value;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a type with details")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut fields = serde_json::Map::new();
+                while let Some(name) = map.next_key::<String>()? {
+                    if DETAILS_FIELDS.contains(&name.as_str()) {
+                        fields.insert(name, map.next_value()?);
+                    } else {
+                        map.next_value::<serde::de::IgnoredAny>()?;
+                    }
+                }
+                Ok(fields.into())
+            }
+        }
+
+        const DETAILS_FIELDS: &[&str] = &["type", "details"];
+        deserializer.deserialize_struct("Details", DETAILS_FIELDS, DetailsVisitor)
+    }
+}
+
+/// deserializes a tagged `T` strictly, or returns what `unknown` makes of an
+/// unknown `type` tag
+///
+/// The tag is read as `Tag`, e.g. [`items::ItemType`], whose `Unknown`
+/// variant `unknown` maps to the one of `T`. serde can't fall back to a
+/// variant with data, so the `Unknown` variant of `T` is skipped when
+/// deserializing. It's written as `{"type": "Unknown", "details": tag}`, which
+/// is read back here. Used by `deserialize_with` functions, so that types
+/// added to the api don't fail the response while known types still have to
+/// match.
+#[cfg(feature = "items")]
+pub(crate) fn deserialize_known<'de, D, Tag, T>(
+    deserializer: D,
+    unknown: fn(Tag) -> Option<T>,
+) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    Tag: serde::de::DeserializeOwned,
+    T: serde::de::DeserializeOwned,
+{
+    use serde::de::Error;
+
+    let mut value = serde_json::Value::deserialize(deserializer)?;
+    if value["type"] == "Unknown" && value["details"].is_string() {
+        value["type"] = value["details"].take();
+    }
+    if let Some(Ok(tag)) = value.get("type").map(Tag::deserialize) {
+        if let Some(unknown) = unknown(tag) {
+            return Ok(unknown);
+        }
+    }
+    T::deserialize(value).map_err(D::Error::custom)
+}
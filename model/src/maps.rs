@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     maps::continents::{ContinentId, ContinentRectangle, FloorId, MapRectangle, RegionId},
-    BulkEndpoint, Endpoint, EndpointWithId,
+    BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId,
 };
 
 pub mod continents;
@@ -35,39 +35,43 @@ pub enum MapType {
     RedHome,
     /// The tutorial missions for newly created characters.
     Tutorial,
-    Unknown,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Map {
-    /// The map id.
-    pub id: MapId,
-    /// The map name.
-    pub name: String,
-    /// The minimum level on this map.
-    pub min_level: u8,
-    /// The maximum level on this map.
-    pub max_level: u8,
-    /// The default floor of the map.
-    pub default_floor: FloorId,
-    /// The map type.
-    #[serde(rename = "type")]
-    pub _type: MapType,
-    /// The list of available floors for the map.
-    pub floors: BTreeSet<FloorId>,
-    /// The id of the region this map belongs to, if any.
-    pub region_id: Option<RegionId>,
-    /// The name of the region this map belongs to, if any.
-    pub region_name: Option<String>,
-    /// The id of the continent this map belongs to, if any.
-    pub continent_id: Option<ContinentId>,
-    /// The name of the continent this map belongs to, if any.
-    pub continent_name: Option<String>,
-    /// The dimensions of the map.
-    pub map_rect: MapRectangle,
-    /// The dimensions of the map within the continent coordinate system.
-    pub continent_rect: ContinentRectangle,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Map {
+        /// The map id.
+        pub id: MapId,
+        /// The map name.
+        pub name: String,
+        /// The minimum level on this map.
+        pub min_level: u8,
+        /// The maximum level on this map.
+        pub max_level: u8,
+        /// The default floor of the map.
+        pub default_floor: FloorId,
+        /// The map type.
+        #[serde(rename = "type")]
+        pub _type: MapType,
+        /// The list of available floors for the map.
+        pub floors: BTreeSet<FloorId>,
+        /// The id of the region this map belongs to, if any.
+        pub region_id: Option<RegionId>,
+        /// The name of the region this map belongs to, if any.
+        pub region_name: Option<String>,
+        /// The id of the continent this map belongs to, if any.
+        pub continent_id: Option<ContinentId>,
+        /// The name of the continent this map belongs to, if any.
+        pub continent_name: Option<String>,
+        /// The dimensions of the map.
+        pub map_rect: MapRectangle,
+        /// The dimensions of the map within the continent coordinate system.
+        pub continent_rect: ContinentRectangle,
+    }
 }
 
 impl EndpointWithId for Map {
@@ -75,6 +79,7 @@ impl EndpointWithId for Map {
 }
 impl Endpoint for Map {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = true;
     const URL: &'static str = "v2/maps";
     const VERSION: &'static str = "2023-04-02T00:00:00.000Z";
@@ -6,7 +6,7 @@ use serde::{ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer}
 use serde_tuple::{Deserialize_tuple, Serialize_tuple};
 
 pub use crate::maps::continents::floors::*;
-use crate::{BulkEndpoint, Endpoint, EndpointWithId};
+use crate::{BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId};
 
 pub type ContinentId = u32;
 
@@ -87,6 +87,7 @@ impl EndpointWithId for Continent {
 }
 impl Endpoint for Continent {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = true;
     const URL: &'static str = "v2/continents";
     const VERSION: &'static str = "2023-03-31T00:00:00.000Z";
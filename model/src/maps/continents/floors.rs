@@ -72,6 +72,9 @@ pub enum PointOfInterestType {
     Waypoint,
     Vista,
     Unlock,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -197,6 +200,10 @@ pub enum MasteryPointRegion {
     /// Janthir Wilds
     #[serde(alias = "Unknown")]
     Janthir,
+    /// a region added after this release, the api reports Janthir Wilds as
+    /// `Unknown`
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -260,18 +267,20 @@ pub struct Region {
     pub maps: HashMap<MapId, Map>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Floor {
-    pub id: FloorId,
-    /// The dimensions of the texture.
-    pub texture_dims: Dimensions,
-    /// If present, it represents a rectangle of downloadable textures. Every
-    /// tile coordinate outside this rectangle is not available on the tile
-    /// server.
-    pub clamped_view: Option<ContinentRectangle>,
-    /// The list of regions in on this floor.
-    pub regions: HashMap<RegionId, Region>,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Floor {
+        pub id: FloorId,
+        /// The dimensions of the texture.
+        pub texture_dims: Dimensions,
+        /// If present, it represents a rectangle of downloadable textures. Every
+        /// tile coordinate outside this rectangle is not available on the tile
+        /// server.
+        pub clamped_view: Option<ContinentRectangle>,
+        /// The list of regions in on this floor.
+        pub regions: HashMap<RegionId, Region>,
+    }
 }
 
 impl EndpointWithId for Floor {
@@ -1,7 +1,12 @@
+#[cfg(feature = "misc")]
 pub mod build;
 pub mod colors;
 pub mod currencies;
+#[cfg(feature = "misc")]
+pub mod files;
 pub mod minis;
+#[cfg(feature = "misc")]
+pub mod quaggans;
 pub mod raids;
 pub mod titles;
 pub mod worlds;
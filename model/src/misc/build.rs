@@ -2,10 +2,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::{Endpoint, FixedEndpoint};
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Build {
-    pub id: u64,
+with_unknown_fields! {
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Build {
+        pub id: u64,
+    }
 }
 
 impl Endpoint for Build {
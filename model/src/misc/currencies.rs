@@ -1,33 +1,43 @@
-use serde::{Deserialize, Serialize};
+pub type CurrencyId = u16;
 
-use crate::{BulkEndpoint, Endpoint, EndpointWithId};
+#[cfg(feature = "misc")]
+pub use self::endpoint::*;
 
-pub type CurrencyId = u16;
+#[cfg(feature = "misc")]
+mod endpoint {
+    use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Currency {
-    pub id: CurrencyId,
-    pub name: String,
-    pub description: String,
-    pub icon: String,
-    pub order: u16,
-}
+    use super::CurrencyId;
+    use crate::{BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId};
 
-impl EndpointWithId for Currency {
-    type IdType = CurrencyId;
-}
-impl Endpoint for Currency {
-    const AUTHENTICATED: bool = false;
-    const LOCALE: bool = true;
-    const URL: &'static str = "v2/currencies";
-    const VERSION: &'static str = "2021-01-11T00:00:00.000Z";
-}
+    with_unknown_fields! {
+        #[derive(Clone, PartialEq, Eq, PartialOrd, Debug, Serialize, Deserialize)]
+        #[cfg_attr(test, serde(deny_unknown_fields))]
+        pub struct Currency {
+            pub id: CurrencyId,
+            pub name: String,
+            pub description: String,
+            pub icon: String,
+            pub order: u16,
+        }
+    }
+
+    impl EndpointWithId for Currency {
+        type IdType = CurrencyId;
+    }
+    impl Endpoint for Currency {
+        const AUTHENTICATED: bool = false;
+        const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+        const LOCALE: bool = true;
+        const URL: &'static str = "v2/currencies";
+        const VERSION: &'static str = "2021-01-11T00:00:00.000Z";
+    }
 
-impl BulkEndpoint for Currency {
-    const ALL: bool = true;
+    impl BulkEndpoint for Currency {
+        const ALL: bool = true;
 
-    fn id(&self) -> &Self::IdType {
-        &self.id
+        fn id(&self) -> &Self::IdType {
+            &self.id
+        }
     }
 }
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+pub type FileId = String;
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct File {
+        pub id: FileId,
+        pub icon: String,
+    }
+}
+
+impl Endpoint for File {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/files";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for File {
+    type IdType = FileId;
+}
+
+impl BulkEndpoint for File {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
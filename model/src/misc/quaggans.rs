@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+pub type QuagganId = String;
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Quaggan {
+        pub id: QuagganId,
+        pub url: String,
+    }
+}
+
+impl Endpoint for Quaggan {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = false;
+    const URL: &'static str = "v2/quaggans";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for Quaggan {
+    type IdType = QuagganId;
+}
+
+impl BulkEndpoint for Quaggan {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
@@ -1,41 +1,72 @@
-use serde::{Deserialize, Serialize};
+pub type WorldId = u16;
 
-use crate::*;
+#[cfg(feature = "misc")]
+pub use self::endpoint::*;
 
-pub type WorldId = u16;
+#[cfg(feature = "misc")]
+mod endpoint {
+    use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub enum PopulationLevel {
-    Medium,
-    High,
-    VeryHigh,
-    Full,
-}
+    use super::WorldId;
+    use crate::*;
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct World {
-    pub id: WorldId,
-    pub name: String,
-    pub population: PopulationLevel,
-}
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub enum PopulationLevel {
+        Medium,
+        High,
+        VeryHigh,
+        Full,
+        /// a value this version of the library doesn't know yet
+        #[serde(untagged)]
+        Unknown(String),
+    }
 
-impl Endpoint for World {
-    const AUTHENTICATED: bool = false;
-    const LOCALE: bool = true;
-    const URL: &'static str = "v2/worlds";
-    const VERSION: &'static str = "2022-07-22T00:00:00.000Z";
-}
+    with_unknown_fields! {
+        #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+        #[cfg_attr(test, serde(deny_unknown_fields))]
+        pub struct World {
+            pub id: WorldId,
+            pub name: String,
+            pub population: PopulationLevel,
+        }
+    }
 
-impl EndpointWithId for World {
-    type IdType = WorldId;
-}
+    /// a change of a world's population, as `(id, old, new)`
+    pub type PopulationChange = (WorldId, PopulationLevel, PopulationLevel);
+
+    /// compares two snapshots of all worlds and returns the worlds whose
+    /// population changed
+    ///
+    /// Worlds only present in one of the snapshots are skipped.
+    pub fn population_changes(previous: &[World], current: &[World]) -> Vec<PopulationChange> {
+        current
+            .iter()
+            .filter_map(|world| {
+                let old = previous.iter().find(|x| x.id == world.id)?;
+                (old.population != world.population)
+                    .then(|| (world.id, old.population.clone(), world.population.clone()))
+            })
+            .collect()
+    }
+
+    impl Endpoint for World {
+        const AUTHENTICATED: bool = false;
+        const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+        const LOCALE: bool = true;
+        const URL: &'static str = "v2/worlds";
+        const VERSION: &'static str = "2022-07-22T00:00:00.000Z";
+    }
+
+    impl EndpointWithId for World {
+        type IdType = WorldId;
+    }
 
-impl BulkEndpoint for World {
-    const ALL: bool = true;
+    impl BulkEndpoint for World {
+        const ALL: bool = true;
 
-    fn id(&self) -> &Self::IdType {
-        &self.id
+        fn id(&self) -> &Self::IdType {
+            &self.id
+        }
     }
 }
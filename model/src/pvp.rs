@@ -1 +1,2 @@
+#[cfg(feature = "pvp")]
 pub mod amulets;
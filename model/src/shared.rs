@@ -0,0 +1,145 @@
+//! types referenced by several endpoints
+
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// adds `as_str`, `Display` and `FromStr` to an enum of api values, named
+/// like its variants, with an untagged `Unknown(String)` variant last
+///
+/// Serde is derived as for every other model enum, the `Unknown` variant
+/// keeps values added to the api after this release.
+macro_rules! string_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($variant:ident,)*
+            $(#[$unknown_attr:meta])*
+            Unknown(String),
+        }
+    ) => {
+        $(#[$attr])*
+        pub enum $name {
+            $($variant,)*
+            $(#[$unknown_attr])*
+            Unknown(String),
+        }
+
+        impl $name {
+            /// the name as used by the api
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => stringify!($variant),)*
+                    Self::Unknown(name) => name,
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $(stringify!($variant) => Self::$variant,)*
+                    _ => Self::Unknown(s.to_owned()),
+                })
+            }
+        }
+    };
+}
+
+string_enum! {
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+    pub enum Profession {
+        Elementalist,
+        Engineer,
+        Guardian,
+        Mesmer,
+        Necromancer,
+        Ranger,
+        Revenant,
+        Thief,
+        Warrior,
+        /// a value this version of the library doesn't know yet
+        #[serde(untagged)]
+        Unknown(String),
+    }
+}
+
+string_enum! {
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+    pub enum Race {
+        Asura,
+        Charr,
+        Human,
+        Norn,
+        Sylvari,
+        /// a value this version of the library doesn't know yet
+        #[serde(untagged)]
+        Unknown(String),
+    }
+}
+
+string_enum! {
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+    pub enum CraftingDiscipline {
+        Armorsmith,
+        Artificer,
+        Chef,
+        Huntsman,
+        Jeweler,
+        Leatherworker,
+        Scribe,
+        Tailor,
+        Weaponsmith,
+        /// a value this version of the library doesn't know yet
+        #[serde(untagged)]
+        Unknown(String),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_round_trip() {
+        for name in ["Revenant", "Tengu"] {
+            let profession: Profession = name.parse().unwrap();
+            assert_eq!(profession.to_string(), name);
+            assert_eq!(profession.as_str(), name);
+        }
+        assert_eq!("Revenant".parse(), Ok(Profession::Revenant));
+        assert_eq!("Tengu".parse(), Ok(Race::Unknown("Tengu".to_owned())));
+    }
+
+    #[test]
+    fn serde_uses_api_names() {
+        let json = serde_json::to_string(&CraftingDiscipline::Leatherworker).unwrap();
+        assert_eq!(json, r#""Leatherworker""#);
+        let discipline: CraftingDiscipline = serde_json::from_str(r#""Leatherworker""#).unwrap();
+        assert_eq!(discipline, CraftingDiscipline::Leatherworker);
+
+        // names are case sensitive, like everywhere else in the api
+        let discipline: CraftingDiscipline = serde_json::from_str(r#""leatherworker""#).unwrap();
+        assert_eq!(
+            discipline,
+            CraftingDiscipline::Unknown("leatherworker".to_owned())
+        );
+    }
+
+    #[test]
+    fn unknown_round_trip() {
+        let race: Race = serde_json::from_str(r#""Tengu""#).unwrap();
+        assert_eq!(race, Race::Unknown("Tengu".to_owned()));
+        assert_eq!(serde_json::to_string(&race).unwrap(), r#""Tengu""#);
+        assert_eq!(race.to_string(), "Tengu");
+        assert_eq!(race.to_string().parse(), Ok(race));
+    }
+}
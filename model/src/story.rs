@@ -0,0 +1,2 @@
+pub mod backstory;
+pub mod stories;
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    authenticated::characters::BackStoryId,
+    shared::{Profession, Race},
+    BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId,
+};
+
+pub type BackstoryQuestionId = u32;
+/// answer ids as returned by
+/// [`Backstory`](crate::authenticated::characters::Backstory)
+pub type BackstoryAnswerId = BackStoryId;
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct BackstoryQuestion {
+        pub id: BackstoryQuestionId,
+        pub title: String,
+        pub description: String,
+        pub answers: Vec<BackstoryAnswerId>,
+        pub order: u32,
+        /// only available to these races, available to all if `None`
+        pub races: Option<Vec<Race>>,
+        /// only available to these professions, available to all if `None`
+        pub professions: Option<Vec<Profession>>,
+    }
+}
+
+impl Endpoint for BackstoryQuestion {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = true;
+    const URL: &'static str = "v2/backstory/questions";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for BackstoryQuestion {
+    type IdType = BackstoryQuestionId;
+}
+
+impl BulkEndpoint for BackstoryQuestion {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct BackstoryAnswer {
+        pub id: BackstoryAnswerId,
+        pub title: String,
+        pub description: String,
+        pub journal: String,
+        pub question: BackstoryQuestionId,
+        /// only available to these professions, available to all if `None`
+        pub professions: Option<Vec<Profession>>,
+        /// only available to these races, available to all if `None`
+        pub races: Option<Vec<Race>>,
+    }
+}
+
+impl Endpoint for BackstoryAnswer {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = true;
+    const URL: &'static str = "v2/backstory/answers";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for BackstoryAnswer {
+    type IdType = BackstoryAnswerId;
+}
+
+impl BulkEndpoint for BackstoryAnswer {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
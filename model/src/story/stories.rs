@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{shared::Race, BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId};
+
+pub type StoryId = u32;
+pub type StorySeasonId = String;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Chapter {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub enum StoryFlag {
+    RequiresUnlock,
+    /// a value this version of the library doesn't know yet
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Story {
+        pub id: StoryId,
+        pub season: StorySeasonId,
+        pub name: String,
+        pub description: String,
+        pub timeline: String,
+        pub level: u8,
+        pub order: u32,
+        pub chapters: Vec<Chapter>,
+        /// only available to these races, available to all if `None`
+        pub races: Option<Vec<Race>>,
+        pub flags: Option<Vec<StoryFlag>>,
+    }
+}
+
+impl Endpoint for Story {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = true;
+    const URL: &'static str = "v2/stories";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for Story {
+    type IdType = StoryId;
+}
+
+impl BulkEndpoint for Story {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
+
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct StorySeason {
+        pub id: StorySeasonId,
+        pub name: String,
+        pub order: u32,
+        pub stories: Vec<StoryId>,
+    }
+}
+
+impl Endpoint for StorySeason {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = true;
+    const URL: &'static str = "v2/stories/seasons";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for StorySeason {
+    type IdType = StorySeasonId;
+}
+
+impl BulkEndpoint for StorySeason {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{items::ItemId, BulkEndpoint, Endpoint, EndpointWithId};
+use crate::{items::ItemId, BulkEndpoint, Endpoint, EndpointInfo, EndpointWithId};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
@@ -10,12 +10,14 @@ pub struct ListingDetails {
     pub quantity: u64,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Listings {
-    pub id: ItemId,
-    pub buys: Vec<ListingDetails>,
-    pub sells: Vec<ListingDetails>,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Listings {
+        pub id: ItemId,
+        pub buys: Vec<ListingDetails>,
+        pub sells: Vec<ListingDetails>,
+    }
 }
 
 impl EndpointWithId for Listings {
@@ -23,6 +25,7 @@ impl EndpointWithId for Listings {
 }
 impl Endpoint for Listings {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = false;
     const URL: &'static str = "v2/commerce/listings";
     const VERSION: &'static str = "2021-01-11T00:00:00.000Z";
@@ -43,13 +46,15 @@ pub struct PriceDetails {
     pub quantity: u64,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-pub struct Prices {
-    pub id: ItemId,
-    pub whitelisted: bool,
-    pub buys: PriceDetails,
-    pub sells: PriceDetails,
+with_unknown_fields! {
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Prices {
+        pub id: ItemId,
+        pub whitelisted: bool,
+        pub buys: PriceDetails,
+        pub sells: PriceDetails,
+    }
 }
 
 impl EndpointWithId for Prices {
@@ -57,6 +62,7 @@ impl EndpointWithId for Prices {
 }
 impl Endpoint for Prices {
     const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
     const LOCALE: bool = false;
     const URL: &'static str = "v2/commerce/prices";
     const VERSION: &'static str = "2021-01-11T00:00:00.000Z";
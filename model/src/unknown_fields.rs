@@ -0,0 +1,178 @@
+#[cfg(feature = "unknown-fields")]
+use std::{cell::Cell, cmp::Ordering, collections::HashMap, ops::Deref};
+
+#[cfg(feature = "unknown-fields")]
+use serde::{
+    de::{IgnoredAny, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+#[cfg(feature = "unknown-fields")]
+use serde_json::Value;
+
+/// adds the `extra` field to a model, which keeps the top level fields of
+/// the response the model doesn't know
+///
+/// The field only exists with the `unknown-fields` feature. Wrap the whole
+/// struct, including its attributes:
+/// ```ignore
+/// with_unknown_fields! {
+///     #[derive(Clone, Debug, Serialize, Deserialize)]
+///     pub struct Model {
+///         pub id: u32,
+///     }
+/// }
+/// ```
+/// Structs that are flattened into another model don't get the field, the
+/// `extra` field of the outer model collects their unknown fields.
+macro_rules! with_unknown_fields {
+    ($(#[$attr:meta])* pub struct $name:ident { $($fields:tt)* }) => {
+        $(#[$attr])*
+        pub struct $name {
+            $($fields)*
+            /// top level fields of the response this model doesn't know, see
+            /// [`UnknownFields`](crate::UnknownFields)
+            #[cfg(feature = "unknown-fields")]
+            #[serde(flatten, default, skip_serializing_if = "crate::UnknownFields::is_empty")]
+            pub extra: crate::UnknownFields,
+        }
+    };
+}
+
+#[cfg(feature = "unknown-fields")]
+thread_local! {
+    static CAPTURE: Cell<bool> = const { Cell::new(true) };
+}
+
+/// fields of a response that the model doesn't know, by name
+///
+/// Models carry them in their `extra` field. They are kept when serializing
+/// the model, so they survive caches that store serialized values.
+#[cfg(feature = "unknown-fields")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnknownFields(pub HashMap<String, Value>);
+
+#[cfg(feature = "unknown-fields")]
+impl UnknownFields {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(feature = "unknown-fields")]
+impl Deref for UnknownFields {
+    type Target = HashMap<String, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// unknown fields don't order models, only equal fields compare as equal
+#[cfg(feature = "unknown-fields")]
+impl PartialOrd for UnknownFields {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self == other).then_some(Ordering::Equal)
+    }
+}
+
+#[cfg(feature = "unknown-fields")]
+impl Serialize for UnknownFields {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// skips the fields if capturing is turned off, see
+/// [`capture_unknown_fields`]
+#[cfg(feature = "unknown-fields")]
+impl<'de> Deserialize<'de> for UnknownFields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldsVisitor;
+
+        impl<'de> Visitor<'de> for FieldsVisitor {
+            type Value = UnknownFields;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a map of fields")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut fields = HashMap::new();
+                if CAPTURE.with(Cell::get) {
+                    while let Some((name, value)) = map.next_entry()? {
+                        fields.insert(name, value);
+                    }
+                } else {
+                    while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+                }
+                Ok(UnknownFields(fields))
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
+
+/// runs `f` with capturing unknown fields turned on or off for models
+/// deserialized on the current thread
+///
+/// Capturing is on by default.
+#[cfg(feature = "unknown-fields")]
+pub fn capture_unknown_fields<R>(capture: bool, f: impl FnOnce() -> R) -> R {
+    struct Reset(bool);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            CAPTURE.with(|c| c.set(self.0));
+        }
+    }
+
+    let _reset = Reset(CAPTURE.with(|c| c.replace(capture)));
+    f()
+}
+
+#[cfg(all(test, feature = "unknown-fields"))]
+mod tests {
+    use super::*;
+
+    with_unknown_fields! {
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        pub struct Model {
+            pub id: u32,
+            pub name: Option<String>,
+        }
+    }
+
+    #[test]
+    fn captures_unknown_fields() {
+        let body = r#"{"id": 1, "name": null, "new": [2], "other": {"a": true}}"#;
+        let model: Model = serde_json::from_str(body).unwrap();
+        assert_eq!((model.id, model.name.as_deref()), (1, None));
+        assert_eq!(model.extra.len(), 2);
+        assert_eq!(model.extra["new"], serde_json::json!([2]));
+
+        let roundtrip: Model =
+            serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+        assert_eq!(roundtrip, model);
+    }
+
+    #[test]
+    fn no_unknown_fields() {
+        let model: Vec<Model> = serde_json::from_str(r#"[{"id": 1, "name": "a"}]"#).unwrap();
+        assert!(model[0].extra.is_empty());
+        assert_eq!(
+            serde_json::to_string(&model[0]).unwrap(),
+            r#"{"id":1,"name":"a"}"#
+        );
+    }
+
+    #[test]
+    fn capture_turned_off() {
+        let body = r#"{"id": 1, "name": null, "new": [2]}"#;
+        let model: Model = capture_unknown_fields(false, || serde_json::from_str(body).unwrap());
+        assert!(model.extra.is_empty());
+
+        let model: Model = serde_json::from_str(body).unwrap();
+        assert_eq!(model.extra.len(), 1);
+    }
+}
@@ -1 +1,3 @@
 pub mod abilities;
+#[cfg(feature = "wvw")]
+pub mod upgrades;
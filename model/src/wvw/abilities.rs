@@ -1 +1,53 @@
+/// also used by characters, so it's available without the `wvw` feature
 pub type AbilityId = u32;
+
+#[cfg(feature = "wvw")]
+pub use self::endpoint::*;
+
+#[cfg(feature = "wvw")]
+mod endpoint {
+    use serde::{Deserialize, Serialize};
+
+    use super::AbilityId;
+    use crate::*;
+
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct AbilityRank {
+        /// wvw ability points required for this rank
+        pub cost: u16,
+        pub effect: String,
+    }
+
+    with_unknown_fields! {
+        #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+        #[cfg_attr(test, serde(deny_unknown_fields))]
+        pub struct Ability {
+            pub id: AbilityId,
+            pub name: String,
+            pub description: String,
+            pub icon: String,
+            pub ranks: Vec<AbilityRank>,
+        }
+    }
+
+    impl Endpoint for Ability {
+        const AUTHENTICATED: bool = false;
+        const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+        const LOCALE: bool = true;
+        const URL: &'static str = "v2/wvw/abilities";
+        const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+    }
+
+    impl EndpointWithId for Ability {
+        type IdType = AbilityId;
+    }
+
+    impl BulkEndpoint for Ability {
+        const ALL: bool = true;
+
+        fn id(&self) -> &Self::IdType {
+            &self.id
+        }
+    }
+}
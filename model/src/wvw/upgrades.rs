@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+pub type UpgradeId = u16;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct TierUpgrade {
+    pub name: String,
+    pub description: String,
+    pub icon: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct UpgradeTier {
+    pub name: String,
+    /// dolyaks that need to arrive at the objective to reach this tier
+    pub yaks_required: u16,
+    pub upgrades: Vec<TierUpgrade>,
+}
+
+with_unknown_fields! {
+    /// the tiers an objective progresses through, referenced by the
+    /// `upgrade_id` of an objective
+    #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[cfg_attr(test, serde(deny_unknown_fields))]
+    pub struct Upgrade {
+        pub id: UpgradeId,
+        pub tiers: Vec<UpgradeTier>,
+    }
+}
+
+impl Endpoint for Upgrade {
+    const AUTHENTICATED: bool = false;
+    const INFO: EndpointInfo = EndpointInfo::bulk::<Self>();
+    const LOCALE: bool = true;
+    const URL: &'static str = "v2/wvw/upgrades";
+    const VERSION: &'static str = "2024-03-09T00:00:00.000Z";
+}
+
+impl EndpointWithId for Upgrade {
+    type IdType = UpgradeId;
+}
+
+impl BulkEndpoint for Upgrade {
+    const ALL: bool = true;
+
+    fn id(&self) -> &Self::IdType {
+        &self.id
+    }
+}